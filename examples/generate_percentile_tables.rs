@@ -0,0 +1,55 @@
+//! Regenerates `src/percentile_tables.rs`:
+//!
+//!     cargo run --release --example generate_percentile_tables > src/percentile_tables.rs
+//!
+//! Every 5 and 7 card hand is evaluated, so the output is exact and only ever
+//! changes with the evaluator. The 7 card pass takes a few minutes.
+
+use terminal_poker::card::Card;
+use terminal_poker::deck::Deck;
+use terminal_poker::hand_rank::HandRank;
+
+const DISTINCT_VALUES: usize = 7462;
+
+fn main() {
+    let five = worse_counts(5);
+    eprintln!("5 cards: done");
+    let seven = worse_counts(7);
+    eprintln!("7 cards: done");
+
+    println!("// Generated by examples/generate_percentile_tables.rs, do not edit.");
+    println!("// Entry `i` counts the hands that lose to a `NumericHandRank` of `i + 1`.");
+    println!();
+    print_table("FIVE_CARD", &five);
+    println!();
+    print_table("SEVEN_CARD", &seven);
+}
+
+/// Number of `size` card hands that are beaten by each hand value
+fn worse_counts(size: usize) -> Vec<u32> {
+    let mut counts = vec![0_u32; DISTINCT_VALUES];
+    let mut cards: Vec<Card> = Vec::with_capacity(size);
+    for hand in Deck::new().combinations(size) {
+        cards.clear();
+        cards.extend(hand);
+        counts[HandRank::best_of(&cards).numeric() as usize - 1] += 1;
+    }
+
+    // Walk up from seven high so each entry sums everything below it
+    let mut worse = vec![0; DISTINCT_VALUES];
+    let mut below = 0;
+    for i in (0..DISTINCT_VALUES).rev() {
+        worse[i] = below;
+        below += counts[i];
+    }
+    worse
+}
+
+fn print_table(name: &str, values: &[u32]) {
+    println!("pub const {}: &[u32; {}] = &[", name, values.len());
+    for line in values.chunks(10) {
+        let line: Vec<String> = line.iter().map(|value| value.to_string()).collect();
+        println!("{},", line.join(", "));
+    }
+    println!("];");
+}
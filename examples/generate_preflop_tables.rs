@@ -0,0 +1,298 @@
+//! Regenerates `src/preflop_tables.rs`:
+//!
+//!     cargo run --release --example generate_preflop_tables > src/preflop_tables.rs
+//!
+//! Heads-up equities are exact. Boards that only differ by a renaming of
+//! suits give every starting hand class the same results, so each of the
+//! ~134 thousand suit classes of 5 card boards is dealt once, weighted by its
+//! size. On each board every holding is sorted by strength, which settles all
+//! 169x169 matchups at once.
+//!
+//! Equities against two or more random hands cannot be enumerated in any
+//! reasonable time and are Monte Carlo estimates from a fixed seed, so that
+//! part of the output only changes when the number of trials does. An
+//! optional argument overrides the trials per entry.
+
+use std::collections::HashMap;
+
+use terminal_poker::card::{Card, Rank};
+use terminal_poker::card_set::CardSet;
+use terminal_poker::deck::Deck;
+use terminal_poker::eval_cache::canonical;
+use terminal_poker::hand_rank::HandRank;
+use terminal_poker::rng::{Rng, SplitMix64};
+use terminal_poker::starting_hand::StartingHand;
+
+const SEED: u64 = 0x005E_ED0F_F10B;
+const DEFAULT_TRIALS: u64 = 20_000;
+const MAX_OPPONENTS: usize = 9;
+const CLASSES: usize = StartingHand::COUNT;
+
+fn main() {
+    let trials = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("trials must be a number"))
+        .unwrap_or(DEFAULT_TRIALS);
+    let mut rng = SplitMix64::new(SEED);
+    let hands: Vec<StartingHand> = StartingHand::all().collect();
+
+    let outcomes = Outcomes::count();
+    let mut heads_up = Vec::new();
+    for i in 0..CLASSES {
+        for j in i..CLASSES {
+            heads_up.push(basis_points(outcomes.equity(i, j)));
+        }
+    }
+
+    let mut vs_random = Vec::new();
+    for (i, hand) in hands.iter().enumerate() {
+        vs_random.push(basis_points(outcomes.equity_vs_any(i)));
+        for opponents in 2..=MAX_OPPONENTS {
+            let equity = random_equity(*hand, opponents, trials, &mut rng);
+            vs_random.push(basis_points(equity));
+        }
+        eprintln!("vs random: {}/{}", i + 1, hands.len());
+    }
+
+    println!("// Generated by examples/generate_preflop_tables.rs, do not edit.");
+    println!("// All-in equities in basis points. Heads-up equities are exact,");
+    println!(
+        "// equities against two or more random hands come from {} trials per entry.",
+        trials
+    );
+    println!();
+    print_table("HEADS_UP", &heads_up);
+    println!();
+    print_table("VS_RANDOM", &vs_random);
+}
+
+/// One of the 1326 pairs of hole cards
+struct Holding {
+    mask: u64,
+    class: usize,
+    cards: [Card; 2],
+}
+
+/// Boards won and tied by every class against every other, counting each
+/// pair of holdings that can be dealt together once per board.
+struct Outcomes {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+}
+
+impl Outcomes {
+    fn count() -> Self {
+        let mut holdings = Vec::with_capacity(1326);
+        for a in 0..52 {
+            for b in a + 1..52 {
+                let cards = [Card::from_index(a), Card::from_index(b)];
+                holdings.push(Holding {
+                    mask: 1 << a | 1 << b,
+                    class: StartingHand::of(cards).index(),
+                    cards,
+                });
+            }
+        }
+
+        // Holdings that share exactly one card with each holding. They are
+        // counted as opponents on every board and have to be taken back out.
+        let clashes: Vec<Vec<usize>> = holdings
+            .iter()
+            .map(|holding| {
+                let (a, b) = (holding.cards[0].index(), holding.cards[1].index());
+                (0..52)
+                    .filter(|other| *other != a && *other != b)
+                    .flat_map(|other| vec![holding_index(a, other), holding_index(b, other)])
+                    .collect()
+            })
+            .collect();
+
+        let mut boards: HashMap<CardSet, u64> = HashMap::new();
+        for board in Deck::new().combinations(5) {
+            *boards.entry(canonical(board)).or_insert(0) += 1;
+        }
+        eprintln!("heads up: {} boards", boards.len());
+
+        let mut outcomes = Outcomes {
+            wins: vec![0; CLASSES * CLASSES],
+            ties: vec![0; CLASSES * CLASSES],
+        };
+        let mut strengths = vec![0_u16; holdings.len()];
+        let mut order = Vec::with_capacity(holdings.len());
+        for (done, (board, weight)) in boards.into_iter().enumerate() {
+            order.clear();
+            for (i, holding) in holdings.iter().enumerate() {
+                strengths[i] = if holding.mask & board.mask() == 0 {
+                    order.push(i);
+                    strength(board, holding.cards)
+                } else {
+                    0
+                };
+            }
+            order.sort_unstable_by_key(|i| strengths[*i]);
+
+            outcomes.settle(&holdings, &strengths, &order, weight);
+            for &i in &order {
+                let class = holdings[i].class;
+                outcomes.ties[class * CLASSES + class] -= weight;
+                for &other in &clashes[i] {
+                    let cell = class * CLASSES + holdings[other].class;
+                    if strengths[other] == 0 {
+                        continue;
+                    } else if strengths[other] < strengths[i] {
+                        outcomes.wins[cell] -= weight;
+                    } else if strengths[other] == strengths[i] {
+                        outcomes.ties[cell] -= weight;
+                    }
+                }
+            }
+
+            if done % 10_000 == 0 {
+                eprintln!("heads up: {} boards dealt", done);
+            }
+        }
+        outcomes
+    }
+
+    /// Adds the result of every holding against every other on one board,
+    /// clashing pairs included, given the holdings in order of strength.
+    fn settle(&mut self, holdings: &[Holding], strengths: &[u16], order: &[usize], weight: u64) {
+        let mut weaker = [0_u64; CLASSES];
+        let mut level = [0_u64; CLASSES];
+        let mut classes = Vec::new();
+
+        for tied in order.chunk_by(|a, b| strengths[*a] == strengths[*b]) {
+            classes.clear();
+            for &i in tied {
+                let class = holdings[i].class;
+                if level[class] == 0 {
+                    classes.push(class);
+                }
+                level[class] += 1;
+            }
+
+            for &hero in &classes {
+                let times = weight * level[hero];
+                let row = hero * CLASSES;
+                for (wins, weaker) in self.wins[row..row + CLASSES].iter_mut().zip(&weaker) {
+                    *wins += times * weaker;
+                }
+                for &villain in &classes {
+                    self.ties[row + villain] += times * level[villain];
+                }
+            }
+            for &class in &classes {
+                weaker[class] += level[class];
+                level[class] = 0;
+            }
+        }
+    }
+
+    /// Share of the pot class `hero` wins against class `villain`
+    fn equity(&self, hero: usize, villain: usize) -> f64 {
+        let won = self.wins[hero * CLASSES + villain];
+        let lost = self.wins[villain * CLASSES + hero];
+        let tied = self.ties[hero * CLASSES + villain];
+        (won as f64 + tied as f64 / 2.0) / (won + lost + tied) as f64
+    }
+
+    /// Share of the pot class `hero` wins against any two cards
+    fn equity_vs_any(&self, hero: usize) -> f64 {
+        let (mut share, mut total) = (0.0, 0);
+        for villain in 0..CLASSES {
+            let won = self.wins[hero * CLASSES + villain];
+            let lost = self.wins[villain * CLASSES + hero];
+            let tied = self.ties[hero * CLASSES + villain];
+            share += won as f64 + tied as f64 / 2.0;
+            total += won + lost + tied;
+        }
+        share / total as f64
+    }
+}
+
+/// Position of the holding of cards `a` and `b` in `Card::index` order
+fn holding_index(a: usize, b: usize) -> usize {
+    let (low, high) = (a.min(b), a.max(b));
+    low * (103 - low) / 2 + high - low - 1
+}
+
+/// Strength of `hole` with `board`, skipping the full evaluation whenever a
+/// flush is out of reach
+fn strength(board: CardSet, hole: [Card; 2]) -> u16 {
+    let mut suits = [0; 4];
+    let mut ranks = [0; 13];
+    for card in board.iter().chain(hole.iter().copied()) {
+        suits[card.index() % 4] += 1;
+        ranks[rank_slot(card.rank())] += 1;
+    }
+
+    let rank = if suits.iter().any(|count| *count >= 5) {
+        let mut cards: Vec<Card> = board.iter().collect();
+        cards.extend_from_slice(&hole);
+        HandRank::best_of(&cards)
+    } else {
+        HandRank::from_ranks(&ranks).expect("Seven cards hold no more than four of a rank")
+    };
+    rank.strength().value()
+}
+
+/// Position of `rank` in `Rank::ALL`, the order `HandRank::from_ranks` counts in
+fn rank_slot(rank: Rank) -> usize {
+    Rank::ALL.iter().position(|r| *r == rank).unwrap()
+}
+
+/// Hero's share of the pot against `opponents` players holding any two cards.
+fn random_equity<R: Rng>(hand: StartingHand, opponents: usize, trials: u64, rng: &mut R) -> f64 {
+    let combos = hand.combos();
+    let mut players = vec![combos[0]; opponents + 1];
+
+    let mut share = 0.0;
+    for trial in 0..trials {
+        let hero = combos[trial as usize % combos.len()];
+        let mut deck = Deck::new();
+        deck.remove(hero[0]);
+        deck.remove(hero[1]);
+
+        players[0] = hero;
+        for player in players[1..].iter_mut() {
+            for card in player.iter_mut() {
+                *card = deck.draw(rng).unwrap();
+            }
+        }
+        share += deal_from(&players, deck, rng);
+    }
+    share / trials as f64
+}
+
+/// Deals a random board and returns the first player's share of the pot.
+fn deal_from<R: Rng>(players: &[[Card; 2]], mut deck: Deck, rng: &mut R) -> f64 {
+    let mut cards = [players[0][0]; 7];
+    for slot in cards[2..].iter_mut() {
+        *slot = deck.draw(rng).unwrap();
+    }
+
+    let mut strengths = Vec::with_capacity(players.len());
+    for hole in players {
+        cards[..2].copy_from_slice(hole);
+        strengths.push(HandRank::best_of(&cards).strength());
+    }
+
+    let best = *strengths.iter().max().unwrap();
+    if strengths[0] < best {
+        return 0.0;
+    }
+    1.0 / strengths.iter().filter(|s| **s == best).count() as f64
+}
+
+fn basis_points(equity: f64) -> u16 {
+    (equity * 10_000.0).round() as u16
+}
+
+fn print_table(name: &str, values: &[u16]) {
+    println!("pub const {}: &[u16; {}] = &[", name, values.len());
+    for line in values.chunks(12) {
+        let line: Vec<String> = line.iter().map(|value| value.to_string()).collect();
+        println!("{},", line.join(", "));
+    }
+    println!("];");
+}
@@ -0,0 +1,362 @@
+//! Command line front end to the evaluator and equity calculator.
+//!
+//!     poker-calc eval AhKhQhJhTh
+//!     poker-calc compare AhKhQhJhTh 2c2d2h7s7d
+//!     poker-calc equity AhKh QsQd --board 2h7h9c --dead 3s
+//!
+//! Add `--json` for machine readable output. Without a command, every line of
+//! standard input is run as one, which makes batches cheap to script.
+
+use std::io::{self, BufRead};
+use std::process;
+
+use terminal_poker::card::{parse_cards, Card};
+use terminal_poker::equity::exact_equity;
+use terminal_poker::hand_rank::HandRank;
+use terminal_poker::showdown::tiers;
+
+const USAGE: &str = "usage: poker-calc [--json] <command>
+
+commands:
+  eval <cards>                          rank the best hand in 5 to 7 cards
+  compare <cards> <cards>...            rank several hands and pick the winner
+  equity <hole> <hole>... [--board <cards>] [--dead <cards>]
+                                        all-in equity by full enumeration
+
+With no command, commands are read from standard input, one per line.";
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let json = take_flag(&mut args, "--json");
+
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    if !args.is_empty() {
+        match run(&args, json) {
+            Ok(output) => println!("{}", output),
+            Err(message) => {
+                eprintln!("poker-calc: {}", message);
+                if json {
+                    println!("{}", json_error(&message));
+                }
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Batch mode keeps going past bad lines so one typo does not lose the
+    // rest of the results
+    let mut failed = false;
+    for line in io::stdin().lock().lines() {
+        let line = line.unwrap_or_else(|err| {
+            eprintln!("poker-calc: {}", err);
+            process::exit(1);
+        });
+        let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+        if args.is_empty() {
+            continue;
+        }
+
+        match run(&args, json) {
+            Ok(output) => println!("{}", output),
+            Err(message) if json => {
+                failed = true;
+                println!("{}", json_error(&message));
+            }
+            Err(message) => {
+                failed = true;
+                println!("error: {}", message);
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String], json: bool) -> Result<String, String> {
+    let (command, rest) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    match command.as_str() {
+        "eval" => eval(rest, json),
+        "compare" => compare(rest, json),
+        "equity" => equity(rest, json),
+        other => Err(format!("unknown command `{}`\n\n{}", other, USAGE)),
+    }
+}
+
+fn eval(args: &[String], json: bool) -> Result<String, String> {
+    let cards = cards(&args.concat())?;
+    let rank = best_hand(&cards)?;
+
+    if json {
+        Ok(hand_json(&cards, rank))
+    } else {
+        Ok(describe(rank))
+    }
+}
+
+fn compare(args: &[String], json: bool) -> Result<String, String> {
+    if args.len() < 2 {
+        return Err("compare needs at least two hands".to_string());
+    }
+
+    let hands = args
+        .iter()
+        .map(|arg| cards(arg))
+        .collect::<Result<Vec<Vec<Card>>, String>>()?;
+    let ranks = hands
+        .iter()
+        .map(|hand| best_hand(hand))
+        .collect::<Result<Vec<HandRank>, String>>()?;
+    let winners = &tiers(&ranks)[0];
+
+    if json {
+        let hands: Vec<String> = hands
+            .iter()
+            .zip(&ranks)
+            .map(|(cards, rank)| hand_json(cards, *rank))
+            .collect();
+        let winners: Vec<String> = winners.iter().map(|i| i.to_string()).collect();
+        return Ok(format!(
+            "{{\"hands\":[{}],\"winners\":[{}]}}",
+            hands.join(","),
+            winners.join(",")
+        ));
+    }
+
+    let mut lines: Vec<String> = args
+        .iter()
+        .zip(&ranks)
+        .map(|(arg, rank)| format!("{}: {}", arg, describe(*rank)))
+        .collect();
+    if winners.len() == 1 {
+        lines.push(format!("winner: {}", args[winners[0]]));
+    } else {
+        let split: Vec<&str> = winners.iter().map(|i| args[*i].as_str()).collect();
+        lines.push(format!("split: {}", split.join(", ")));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn equity(args: &[String], json: bool) -> Result<String, String> {
+    let mut args = args.to_vec();
+    let board = take_option(&mut args, "--board")?.unwrap_or_default();
+    let dead = take_option(&mut args, "--dead")?.unwrap_or_default();
+
+    let players = args
+        .iter()
+        .map(|arg| match cards(arg)?.as_slice() {
+            [first, second] => Ok([*first, *second]),
+            _ => Err(format!("`{}` is not two hole cards", arg)),
+        })
+        .collect::<Result<Vec<[Card; 2]>, String>>()?;
+
+    let result = exact_equity(&players, &board, &dead).map_err(|err| err.to_string())?;
+
+    if json {
+        let players: Vec<String> = args
+            .iter()
+            .zip(&result.players)
+            .map(|(hole, player)| {
+                format!(
+                    "{{\"hole\":{},\"win\":{},\"tie\":{},\"equity\":{}}}",
+                    json_string(hole),
+                    player.win,
+                    player.tie,
+                    player.equity
+                )
+            })
+            .collect();
+        return Ok(format!(
+            "{{\"runouts\":{},\"players\":[{}]}}",
+            result.runouts,
+            players.join(",")
+        ));
+    }
+
+    let mut lines: Vec<String> = args
+        .iter()
+        .zip(&result.players)
+        .map(|(hole, player)| {
+            format!(
+                "{}: {:6.2}% equity ({:.2}% win, {:.2}% tie)",
+                hole,
+                player.equity * 100.0,
+                player.win * 100.0,
+                player.tie * 100.0
+            )
+        })
+        .collect();
+    lines.push(format!("{} runouts", result.runouts));
+    Ok(lines.join("\n"))
+}
+
+fn cards(notation: &str) -> Result<Vec<Card>, String> {
+    parse_cards(notation).map_err(|_| format!("cannot read cards from `{}`", notation))
+}
+
+fn best_hand(cards: &[Card]) -> Result<HandRank, String> {
+    if cards.len() < 5 || cards.len() > 7 {
+        return Err(format!("a hand needs 5 to 7 cards, got {}", cards.len()));
+    }
+    for (i, card) in cards.iter().enumerate() {
+        if cards[..i].contains(card) {
+            return Err(format!("{} appears more than once", card));
+        }
+    }
+    Ok(HandRank::best_of(cards))
+}
+
+fn describe(rank: HandRank) -> String {
+    format!(
+        "{} (rank {} of 7462, 1 is best)",
+        rank.category(),
+        rank.numeric()
+    )
+}
+
+/// Removes `flag` from `args`, reporting whether it was there
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != before
+}
+
+/// Removes `name` and the cards that follow it from `args`
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<Vec<Card>>, String> {
+    let position = match args.iter().position(|arg| arg == name) {
+        Some(position) => position,
+        None => return Ok(None),
+    };
+    if position + 1 >= args.len() {
+        return Err(format!("{} needs a value", name));
+    }
+    let value = args.remove(position + 1);
+    args.remove(position);
+    cards(&value).map(Some)
+}
+
+fn hand_json(cards: &[Card], rank: HandRank) -> String {
+    let cards: Vec<String> = cards.iter().map(|c| json_string(&c.to_string())).collect();
+    format!(
+        "{{\"cards\":[{}],\"category\":{},\"rank\":{},\"strength\":{}}}",
+        cards.join(","),
+        json_string(&format!("{:?}", rank.category())),
+        rank.numeric(),
+        rank.strength().value()
+    )
+}
+
+fn json_error(message: &str) -> String {
+    format!("{{\"error\":{}}}", json_string(message))
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn reads_cards_and_options_from_arguments() {
+        let mut rest = args("AhKh --board 2h7h9c QsQd --dead 3s");
+        let board = take_option(&mut rest, "--board").unwrap().unwrap();
+        assert_eq!(board, parse_cards("2h7h9c").unwrap());
+        assert_eq!(
+            take_option(&mut rest, "--dead").unwrap(),
+            Some(parse_cards("3s").unwrap())
+        );
+        assert_eq!(take_option(&mut rest, "--dead").unwrap(), None);
+        assert_eq!(rest, args("AhKh QsQd"));
+
+        let mut flags = args("--json eval AhKhQhJhTh");
+        assert!(take_flag(&mut flags, "--json"));
+        assert!(!take_flag(&mut flags, "--json"));
+        assert_eq!(flags, args("eval AhKhQhJhTh"));
+
+        // Cards may be split across arguments
+        assert_eq!(
+            run(&args("eval Ah Kh QhJhTh"), false).unwrap(),
+            "Straight flush (rank 1 of 7462, 1 is best)"
+        );
+    }
+
+    #[test]
+    fn runs_every_command() {
+        let output = run(&args("equity AhKh QsQd --dead 3s --board 2h7h9cTs"), false).unwrap();
+        assert_eq!(
+            output,
+            "AhKh:  34.88% equity (34.88% win, 0.00% tie)\n\
+             QsQd:  65.12% equity (65.12% win, 0.00% tie)\n\
+             43 runouts"
+        );
+
+        let output = run(&args("compare AhKhQhJhTh AsKsQsJsTs 2c2d2h7s7d"), false).unwrap();
+        assert!(output.ends_with("split: AhKhQhJhTh, AsKsQsJsTs"));
+    }
+
+    #[test]
+    fn reports_bad_input() {
+        let error = |line: &str| run(&args(line), false).unwrap_err();
+
+        assert!(error("deal AhKh").starts_with("unknown command `deal`"));
+        assert_eq!(error("eval AhKh"), "a hand needs 5 to 7 cards, got 2");
+        assert_eq!(error("eval AhKhQhJhAh"), "Ah appears more than once");
+        assert_eq!(
+            error("eval AhKhQhJhXx"),
+            "cannot read cards from `AhKhQhJhXx`"
+        );
+        assert_eq!(
+            error("compare AhKhQhJhTh"),
+            "compare needs at least two hands"
+        );
+        assert_eq!(error("equity AhKh QsQd --board"), "--board needs a value");
+        assert_eq!(
+            error("equity AhKhQd QsQd"),
+            "`AhKhQd` is not two hole cards"
+        );
+    }
+
+    #[test]
+    fn writes_json() {
+        assert_eq!(
+            run(&args("eval AhKhQhJhTh"), true).unwrap(),
+            r#"{"cards":["Ah","Kh","Qh","Jh","Th"],"category":"StraightFlush","rank":1,"strength":7462}"#
+        );
+        assert_eq!(
+            run(&args("compare 2c2d2h7s7d AhKhQhJhTh"), true).unwrap(),
+            r#"{"hands":[{"cards":["2c","2d","2h","7s","7d"],"category":"FullHouse","rank":318,"strength":7145},{"cards":["Ah","Kh","Qh","Jh","Th"],"category":"StraightFlush","rank":1,"strength":7462}],"winners":[1]}"#
+        );
+
+        let output = run(&args("equity AhKh QsQd --board 2h7h9cTs --dead 3s"), true).unwrap();
+        assert!(output.starts_with(r#"{"runouts":43,"players":[{"hole":"AhKh","win":0.348"#));
+
+        assert_eq!(
+            json_error("unknown command `\"x\\`\n"),
+            r#"{"error":"unknown command `\"x\\`\n"}"#
+        );
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::board::{self, BoardError};
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::hand_rank::{Category, HandRank};
+use crate::nuts::BoardRanking;
+use crate::range::Range;
+
+/// Weighted combos of some part of a range before and after taking out the
+/// ones that need our hole cards.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Removal {
+    pub before: f64,
+    pub after: f64,
+}
+
+impl Removal {
+    pub fn removed(&self) -> f64 {
+        self.before - self.after
+    }
+
+    /// Share of the combos our cards take away, 0 when there were none
+    pub fn removed_share(&self) -> f64 {
+        if self.before > 0.0 {
+            self.removed() / self.before
+        } else {
+            0.0
+        }
+    }
+
+    fn add(&mut self, weight: f64, blocked: bool) {
+        self.before += weight;
+        if !blocked {
+            self.after += weight;
+        }
+    }
+}
+
+/// How our hole cards change an opponent's range on a board.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blockers {
+    /// Combos per category the opponent makes, best category first. Only
+    /// categories the range can make are listed.
+    pub categories: Vec<(Category, Removal)>,
+    /// Combos that make the best hand possible on the board
+    pub nuts: Removal,
+    /// Combos that beat us
+    pub value: Removal,
+    /// Combos that we beat
+    pub bluffs: Removal,
+}
+
+impl Blockers {
+    pub fn category(&self, category: Category) -> Removal {
+        self.categories
+            .iter()
+            .find(|(c, _)| *c == category)
+            .map(|(_, removal)| *removal)
+            .unwrap_or_default()
+    }
+}
+
+/// Measures the card removal effect of `hole` on `range` given the `board`.
+///
+/// Combos that clash with the board are never counted. What is left is split
+/// by the category each combo makes, and by whether it beats our hand, before
+/// and after taking out the combos that need one of our hole cards.
+pub fn blockers(hole: [Card; 2], board: &[Card], range: &Range) -> Result<Blockers, BoardError> {
+    board::validate(board, 3..=5, &hole)?;
+
+    let on_board = CardSet::from(board);
+    let ours = CardSet::from(&hole[..]);
+    let made = |cards: [Card; 2]| {
+        let mut all = cards.to_vec();
+        all.extend_from_slice(board);
+        HandRank::best_of(&all)
+    };
+
+    let our_rank = made(hole);
+    let nuts = BoardRanking::new(board)
+        .expect("board was checked above")
+        .nuts()
+        .rank;
+
+    let mut categories = [Removal::default(); 9];
+    let mut report = Blockers {
+        categories: Vec::new(),
+        nuts: Removal::default(),
+        value: Removal::default(),
+        bluffs: Removal::default(),
+    };
+
+    for (combo, weight) in range.without(on_board).combos() {
+        let blocked = !combo.card_set().is_disjoint(ours);
+        let rank = made(combo.cards());
+
+        categories[rank.category() as usize].add(weight, blocked);
+        if rank == nuts {
+            report.nuts.add(weight, blocked);
+        }
+        if rank > our_rank {
+            report.value.add(weight, blocked);
+        } else if rank < our_rank {
+            report.bluffs.add(weight, blocked);
+        }
+    }
+
+    report.categories = Category::ALL
+        .iter()
+        .rev()
+        .map(|category| (*category, categories[*category as usize]))
+        .filter(|(_, removal)| removal.before > 0.0)
+        .collect();
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, parse_hole};
+
+    #[test]
+    fn counts_flush_combos_we_block() {
+        let board = parse_cards("Kh7h3h2c9d").unwrap();
+        let report = blockers(parse_hole("QhJc"), &board, &Range::full()).unwrap();
+
+        // Any two of the ten hearts left, and we hold one of them
+        let flushes = report.category(Category::Flush);
+        assert_eq!(flushes.before, 45.0);
+        assert_eq!(flushes.after, 36.0);
+        assert!((flushes.removed_share() - 0.2).abs() < 1e-12);
+
+        // Only ace-queen of hearts makes the best flush, and we hold the queen
+        assert_eq!(report.nuts.before, 1.0);
+        assert_eq!(report.nuts.removed_share(), 1.0);
+
+        let total: f64 = report.categories.iter().map(|(_, r)| r.before).sum();
+        assert_eq!(total, 1081.0);
+        assert_eq!(report.categories[0].0, Category::Flush);
+    }
+
+    #[test]
+    fn splits_value_from_bluffs() {
+        let board = parse_cards("Kh7h3h2c9d").unwrap();
+        let range: Range = "AhJh, 8h6h, T8o, QJo".parse().unwrap();
+        let report = blockers(parse_hole("KcQh"), &board, &range).unwrap();
+
+        assert_eq!(report.value.before, 2.0);
+        assert_eq!(report.value.removed(), 0.0);
+        // Our queen of hearts blocks three of the missed queen-jacks
+        assert_eq!(report.bluffs.before, 24.0);
+        assert_eq!(report.bluffs.after, 21.0);
+        assert!((report.bluffs.removed_share() - 0.125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_bad_boards() {
+        let range = Range::full();
+        let board = parse_cards("Kh7h").unwrap();
+        assert_eq!(
+            blockers(parse_hole("QhJc"), &board, &range),
+            Err(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 5
+            })
+        );
+        let board = parse_cards("Kh7h3hQh").unwrap();
+        assert_eq!(
+            blockers(parse_hole("QhJc"), &board, &range),
+            Err(BoardError::DuplicateCard(board[3]))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use core::ops::RangeInclusive;
+
+use crate::card::Card;
+use crate::card_set::CardSet;
+
+/// Why the cards given for an analysis of a flop, turn or river were
+/// rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// The board has a number of cards the analysis does not cover, e.g. a
+    /// river for something that needs cards to come
+    BoardSize {
+        cards: usize,
+        min: usize,
+        max: usize,
+    },
+    /// A card turned up twice, on the board or among the other known cards
+    DuplicateCard(Card),
+}
+
+impl core::fmt::Display for BoardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            BoardError::BoardSize { cards, min, max } => write!(
+                f,
+                "boards need {} to {} cards here, got {}",
+                min, max, cards
+            ),
+            BoardError::DuplicateCard(card) => write!(f, "{} appears more than once", card),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoardError {}
+
+/// Checks that `board` has as many cards as `sizes` allows and that no card
+/// repeats across it and the `others` known, such as a player's hole cards.
+/// Returns every card given.
+pub(crate) fn validate(
+    board: &[Card],
+    sizes: RangeInclusive<usize>,
+    others: &[Card],
+) -> Result<CardSet, BoardError> {
+    if !sizes.contains(&board.len()) {
+        return Err(BoardError::BoardSize {
+            cards: board.len(),
+            min: *sizes.start(),
+            max: *sizes.end(),
+        });
+    }
+    CardSet::from_distinct(board.iter().chain(others).copied()).map_err(BoardError::DuplicateCard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::parse_cards;
+
+    #[test]
+    fn checks_size_and_duplicates() {
+        let board = parse_cards("Kh7h3c").unwrap();
+        let hole = parse_cards("AsAd").unwrap();
+
+        let known = validate(&board, 3..=5, &hole).unwrap();
+        assert_eq!(known.len(), 5);
+        assert_eq!(
+            validate(&board[..2], 3..=5, &hole),
+            Err(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 5
+            })
+        );
+        assert_eq!(
+            validate(&board, 3..=4, &board[..1]),
+            Err(BoardError::DuplicateCard(board[0]))
+        );
+        assert_eq!(
+            BoardError::BoardSize {
+                cards: 5,
+                min: 3,
+                max: 4
+            }
+            .to_string(),
+            "boards need 3 to 4 cards here, got 5"
+        );
+    }
+}
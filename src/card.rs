@@ -1,5 +1,5 @@
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
-pub(crate) struct Card(u32);
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct Card(u32);
 
 impl Card {
     /// Packs a rank and suite pair into a packed bit representation
@@ -18,7 +18,7 @@ impl Card {
     /// 00000000 00001000 00010011 00000111    Five of Spades
     /// 00000010 00000000 10001001 00011101    Jack of Clubs
     ///
-    pub(crate) fn new(rank: Rank, suit: Suit) -> Card {
+    pub const fn new(rank: Rank, suit: Suit) -> Card {
         let prime = rank.prime_encoding();
         let order = rank.order_encoding();
         let base = 1 << (16 + order);
@@ -33,26 +33,26 @@ impl Card {
         Card(base | order << 8 | suit_bits | prime)
     }
 
-    pub(crate) fn as_int(&self) -> u32 {
+    pub const fn as_int(&self) -> u32 {
         self.0
     }
 
-    pub(crate) fn suit(&self) -> Suit {
+    pub const fn suit(&self) -> Suit {
         // Apply a mask to extract the suite
-        let bits = self.0 & (0b00000000_00000000_11110000_00000000 as u32);
+        let bits = self.0 & 0b00000000_00000000_11110000_00000000_u32;
 
         match bits {
             0x1000 => Suit::Spades,
             0x2000 => Suit::Hearts,
             0x4000 => Suit::Diamonds,
             0x8000 => Suit::Clubs,
-            _ => unreachable!("Bad suite encoding"),
+            _ => panic!("Bad suite encoding"),
         }
     }
 
-    pub(crate) fn rank(&self) -> Rank {
+    pub const fn rank(&self) -> Rank {
         // Apply a mask and shift to extract the rank
-        let bits = (self.0 & (0b00000000_00000000_00001111_00000000 as u32)) >> 8;
+        let bits = (self.0 & 0b00000000_00000000_00001111_00000000_u32) >> 8;
 
         match bits {
             0 => Rank::Two,
@@ -68,9 +68,33 @@ impl Card {
             10 => Rank::Queen,
             11 => Rank::King,
             12 => Rank::Ace,
-            _ => unreachable!("Bad rank encoding"),
+            _ => panic!("Bad rank encoding"),
         }
     }
+
+    /// Position of the card in a 52 card deck, ordered by rank from deuce to
+    /// ace and within a rank by suit (spades, hearts, diamonds, clubs).
+    pub const fn index(&self) -> usize {
+        let order = (self.0 >> 8) & 0xF;
+        let suit = ((self.0 >> 12) & 0xF).trailing_zeros();
+
+        (order * 4 + suit) as usize
+    }
+
+    /// Inverse of `Card::index`
+    pub const fn from_index(index: usize) -> Card {
+        assert!(index < 52, "Card index out of range");
+
+        let rank = Rank::from_order(index as u32 / 4);
+        let suit = match index % 4 {
+            0 => Suit::Spades,
+            1 => Suit::Hearts,
+            2 => Suit::Diamonds,
+            _ => Suit::Clubs,
+        };
+
+        Card::new(rank, suit)
+    }
 }
 
 impl From<u32> for Card {
@@ -79,9 +103,80 @@ impl From<u32> for Card {
     }
 }
 
-#[derive(Copy, Clone, PartialEq)]
-#[allow(dead_code)]
-pub(crate) enum Rank {
+/// Formats a card the way hand histories write it, e.g. `Ah` or `Td`
+impl core::fmt::Display for Card {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}{}", self.rank().to_char(), self.suit().to_char())
+    }
+}
+
+impl core::str::FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses cards such as `Ah`, `td` or `10c`
+    fn from_str(s: &str) -> Result<Card, ParseCardError> {
+        let mut chars = s.chars();
+        let suit = chars.next_back().and_then(Suit::from_char);
+        let rank = match chars.as_str() {
+            "10" => Some(Rank::Ten),
+            rank if rank.chars().count() == 1 => rank.chars().next().and_then(Rank::from_char),
+            _ => None,
+        };
+
+        match (rank, suit) {
+            (Some(rank), Some(suit)) => Ok(Card::new(rank, suit)),
+            _ => Err(ParseCardError),
+        }
+    }
+}
+
+/// Parses a run of cards such as `AhKh`, `2h 7h 9c` or `Ts,9s`
+#[cfg(any(test, feature = "std"))]
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    let chars: Vec<char> = s
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect();
+
+    let mut cards = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        // A ten may be written as `10`, which takes an extra character
+        let len = if chars[start] == '1' { 3 } else { 2 };
+        let end = start + len;
+        if end > chars.len() {
+            return Err(ParseCardError);
+        }
+
+        let card: String = chars[start..end].iter().collect();
+        cards.push(card.parse()?);
+        start = end;
+    }
+    Ok(cards)
+}
+
+/// Two hole cards written like `AhKh`, for tests
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn parse_hole(notation: &str) -> [Card; 2] {
+    let cards = parse_cards(notation).unwrap();
+    assert_eq!(cards.len(), 2, "{} is not two hole cards", notation);
+    [cards[0], cards[1]]
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseCardError;
+
+impl core::fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "expected a card such as Ah or Td")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCardError {}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rank {
     Ace,
     King,
     Queen,
@@ -98,7 +193,67 @@ pub(crate) enum Rank {
 }
 
 impl Rank {
-    fn prime_encoding(&self) -> u32 {
+    /// Every rank, from the highest to the lowest
+    pub const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::King,
+        Rank::Queen,
+        Rank::Jack,
+        Rank::Ten,
+        Rank::Nine,
+        Rank::Eight,
+        Rank::Seven,
+        Rank::Six,
+        Rank::Five,
+        Rank::Four,
+        Rank::Three,
+        Rank::Two,
+    ];
+
+    /// Single character used for the rank in hand notation
+    pub const fn to_char(&self) -> char {
+        match *self {
+            Rank::Ace => 'A',
+            Rank::King => 'K',
+            Rank::Queen => 'Q',
+            Rank::Jack => 'J',
+            Rank::Ten => 'T',
+            Rank::Nine => '9',
+            Rank::Eight => '8',
+            Rank::Seven => '7',
+            Rank::Six => '6',
+            Rank::Five => '5',
+            Rank::Four => '4',
+            Rank::Three => '3',
+            Rank::Two => '2',
+        }
+    }
+
+    pub const fn from_char(c: char) -> Option<Rank> {
+        match c {
+            'A' | 'a' => Some(Rank::Ace),
+            'K' | 'k' => Some(Rank::King),
+            'Q' | 'q' => Some(Rank::Queen),
+            'J' | 'j' => Some(Rank::Jack),
+            'T' | 't' => Some(Rank::Ten),
+            '9' => Some(Rank::Nine),
+            '8' => Some(Rank::Eight),
+            '7' => Some(Rank::Seven),
+            '6' => Some(Rank::Six),
+            '5' => Some(Rank::Five),
+            '4' => Some(Rank::Four),
+            '3' => Some(Rank::Three),
+            '2' => Some(Rank::Two),
+            _ => None,
+        }
+    }
+
+    /// Inverse of the order encoding, deuce=0 up to ace=12
+    pub(crate) const fn from_order(order: u32) -> Rank {
+        Rank::ALL[12 - order as usize]
+    }
+
+    pub(crate) const fn prime_encoding(&self) -> u32 {
         match *self {
             Rank::Ace => 41,
             Rank::King => 37,
@@ -116,7 +271,7 @@ impl Rank {
         }
     }
 
-    fn order_encoding(&self) -> u32 {
+    pub(crate) const fn order_encoding(&self) -> u32 {
         match *self {
             Rank::Ace => 12,
             Rank::King => 11,
@@ -135,8 +290,8 @@ impl Rank {
     }
 }
 
-impl std::fmt::Debug for Rank {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Debug for Rank {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
             Rank::Ace => write!(f, "A"),
             Rank::King => write!(f, "K"),
@@ -155,17 +310,40 @@ impl std::fmt::Debug for Rank {
     }
 }
 
-#[derive(Copy, Clone, PartialEq)]
-#[allow(dead_code)]
-pub(crate) enum Suit {
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Suit {
     Hearts,
     Diamonds,
     Spades,
     Clubs,
 }
 
-impl std::fmt::Debug for Suit {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Spades, Suit::Clubs];
+
+    /// Single lower case character used for the suit in hand notation
+    pub const fn to_char(&self) -> char {
+        match *self {
+            Suit::Hearts => 'h',
+            Suit::Diamonds => 'd',
+            Suit::Spades => 's',
+            Suit::Clubs => 'c',
+        }
+    }
+
+    pub const fn from_char(c: char) -> Option<Suit> {
+        match c {
+            'h' | 'H' => Some(Suit::Hearts),
+            'd' | 'D' => Some(Suit::Diamonds),
+            's' | 'S' => Some(Suit::Spades),
+            'c' | 'C' => Some(Suit::Clubs),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Debug for Suit {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
             Suit::Hearts => write!(f, "♥"),
             Suit::Clubs => write!(f, "♣"),
@@ -183,17 +361,17 @@ mod tests {
     fn packs_cards() {
         assert_eq!(
             Card::new(Rank::King, Suit::Diamonds),
-            (0b00001000_00000000_01001011_00100101 as u32).into(),
+            0b00001000_00000000_01001011_00100101_u32.into(),
             "Should be a King of Diamonds: "
         );
         assert_eq!(
             Card::new(Rank::Five, Suit::Spades),
-            (0b00000000_00001000_00010011_00000111 as u32).into(),
+            0b00000000_00001000_00010011_00000111_u32.into(),
             "Should be a Five of Spades"
         );
         assert_eq!(
             Card::new(Rank::Jack, Suit::Clubs),
-            (0b00000010_00000000_10001001_00011101 as u32).into(),
+            0b00000010_00000000_10001001_00011101_u32.into(),
             "Should be a Jack of Clubs"
         );
     }
@@ -210,4 +388,46 @@ mod tests {
         assert_eq!(card.rank(), Rank::Three);
         assert_eq!(card.suit(), Suit::Spades);
     }
+
+    #[test]
+    fn indexes_every_card_in_the_deck() {
+        assert_eq!(Card::new(Rank::Two, Suit::Spades).index(), 0);
+        assert_eq!(Card::new(Rank::Ace, Suit::Clubs).index(), 51);
+
+        for index in 0..52 {
+            assert_eq!(Card::from_index(index).index(), index);
+        }
+    }
+
+    #[test]
+    fn parses_and_formats_cards() {
+        for index in 0..52 {
+            let card = Card::from_index(index);
+            assert_eq!(card.to_string().parse(), Ok(card));
+        }
+
+        assert_eq!("Ah".parse(), Ok(Card::new(Rank::Ace, Suit::Hearts)));
+        assert_eq!("td".parse(), Ok(Card::new(Rank::Ten, Suit::Diamonds)));
+        assert_eq!("10c".parse(), Ok(Card::new(Rank::Ten, Suit::Clubs)));
+        assert_eq!(Card::new(Rank::Nine, Suit::Spades).to_string(), "9s");
+        assert_eq!("Ax".parse::<Card>(), Err(ParseCardError));
+        assert_eq!("1h".parse::<Card>(), Err(ParseCardError));
+        assert_eq!("AKh".parse::<Card>(), Err(ParseCardError));
+        assert_eq!("".parse::<Card>(), Err(ParseCardError));
+    }
+
+    #[test]
+    fn parses_runs_of_cards() {
+        let expected = vec![
+            Card::new(Rank::Ten, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        assert_eq!(parse_cards("Ts9s2h"), Ok(expected.clone()));
+        assert_eq!(parse_cards("10s 9s, 2h"), Ok(expected));
+        assert_eq!(parse_cards(""), Ok(vec![]));
+        assert_eq!(parse_cards("Ts9"), Err(ParseCardError));
+        assert_eq!(parse_cards("TsXs"), Err(ParseCardError));
+    }
 }
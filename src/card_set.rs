@@ -0,0 +1,175 @@
+use crate::card::Card;
+
+/// A set of distinct cards packed into a 64 bit mask, one bit per
+/// `Card::index`.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    const FULL_MASK: u64 = (1 << 52) - 1;
+
+    pub const fn empty() -> Self {
+        CardSet(0)
+    }
+
+    /// Every card of a standard 52 card deck
+    pub const fn full() -> Self {
+        CardSet(Self::FULL_MASK)
+    }
+
+    pub const fn from_mask(mask: u64) -> Self {
+        CardSet(mask & Self::FULL_MASK)
+    }
+
+    pub const fn mask(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    /// Adds a card, returning `false` if it was already in the set
+    pub fn insert(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 |= 1 << card.index();
+        !present
+    }
+
+    /// Removes a card, returning `false` if it was not in the set
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !(1 << card.index());
+        present
+    }
+
+    pub const fn union(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub const fn intersection(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    pub const fn difference(&self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    pub const fn is_disjoint(&self, other: CardSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Iterates the cards in `Card::index` order
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    /// Collects cards that are meant to all be different, or returns the
+    /// first one that turns up again
+    pub fn from_distinct<I: IntoIterator<Item = Card>>(cards: I) -> Result<Self, Card> {
+        let mut set = CardSet::empty();
+        for card in cards {
+            if !set.insert(card) {
+                return Err(card);
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl<'a> From<&'a [Card]> for CardSet {
+    fn from(cards: &'a [Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+}
+
+impl core::iter::FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut set = CardSet::empty();
+        for card in cards {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1; // Clear the lowest set bit
+        Some(Card::from_index(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{Rank, Suit};
+
+    #[test]
+    fn inserts_and_removes_cards() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let deuce = Card::new(Rank::Two, Suit::Hearts);
+
+        let mut set = CardSet::empty();
+        assert!(set.insert(ace));
+        assert!(!set.insert(ace));
+        assert!(set.insert(deuce));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(ace));
+        assert!(set.iter().eq([deuce, ace].iter().copied()));
+
+        assert!(set.remove(ace));
+        assert!(!set.remove(ace));
+        assert_eq!(set.len(), 1);
+        assert!(!set.contains(ace));
+    }
+
+    #[test]
+    fn full_deck_holds_every_card_once() {
+        let deck = CardSet::full();
+
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.iter().len(), 52);
+        assert_eq!(deck.iter().collect::<CardSet>(), deck);
+        assert_eq!(
+            deck.difference(CardSet::from(&[Card::new(Rank::King, Suit::Clubs)][..]))
+                .len(),
+            51
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use crate::card::{Card, Suit};
+use crate::card_set::CardSet;
+use crate::hand_rank::Category;
+
+/// An exact probability, kept in lowest terms.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Ratio {
+    pub fn new(numerator: u64, denominator: u64) -> Self {
+        assert!(denominator > 0, "A ratio needs a non-zero denominator");
+        let divisor = gcd(numerator, denominator);
+        Ratio {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl core::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Number of ways each category can be made, out of every possible deal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CategoryCounts {
+    /// Indexed by category, weakest first like `Category::ALL`
+    pub counts: [u64; 9],
+    pub total: u64,
+}
+
+impl CategoryCounts {
+    pub fn count(&self, category: Category) -> u64 {
+        self.counts[category as usize]
+    }
+
+    /// Chance of ending up with exactly `category`
+    pub fn probability(&self, category: Category) -> Ratio {
+        Ratio::new(self.count(category), self.total)
+    }
+
+    /// Chance of ending up with `category` or anything better
+    pub fn at_least(&self, category: Category) -> Ratio {
+        let better = self.counts[category as usize..].iter().sum();
+        Ratio::new(better, self.total)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CombinatoricsError {
+    /// Known and unknown cards together have to make a 5, 6 or 7 card hand
+    HandSize(usize),
+    /// A known card was listed more than once
+    DuplicateCard(Card),
+}
+
+impl core::fmt::Display for CombinatoricsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            CombinatoricsError::HandSize(size) => {
+                write!(f, "hands have 5 to 7 cards, got {}", size)
+            }
+            CombinatoricsError::DuplicateCard(card) => {
+                write!(f, "{} appears more than once", card)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CombinatoricsError {}
+
+/// Exact category counts over every 5, 6 or 7 card hand from a full deck.
+pub fn hand_counts(cards: usize) -> Result<CategoryCounts, CombinatoricsError> {
+    category_counts(&[], cards)
+}
+
+/// Exact category counts for the best hand made from `known` plus every way
+/// of dealing `to_come` more cards from the rest of the deck.
+///
+/// Rather than evaluate every deal, this walks the multisets of ranks that
+/// can come, weighting each by the number of ways to pick its suits, and
+/// counts flushes separately one suit at a time. That only works because a
+/// hand of at most seven cards with a flush can never also hold a full house
+/// or quads, so a flush always decides the category.
+pub fn category_counts(
+    known: &[Card],
+    to_come: usize,
+) -> Result<CategoryCounts, CombinatoricsError> {
+    let size = known.len() + to_come;
+    if !(5..=7).contains(&size) {
+        return Err(CombinatoricsError::HandSize(size));
+    }
+
+    CardSet::from_distinct(known.iter().copied()).map_err(CombinatoricsError::DuplicateCard)?;
+    let mut ranks = [0_u8; 13];
+    for &card in known {
+        ranks[card.rank().order_encoding() as usize] += 1;
+    }
+
+    let mut counts = [0_u64; 9];
+
+    // Every deal by the category its ranks make, ignoring suits
+    let mut available = [0_u8; 13];
+    for (available, known) in available.iter_mut().zip(ranks.iter()) {
+        *available = 4 - known;
+    }
+    let mut coming = [0_u8; 13];
+    rank_multisets(
+        &available,
+        to_come,
+        0,
+        &mut coming,
+        1,
+        &mut |coming, ways| {
+            counts[rank_category(&add(&ranks, coming)) as usize] += ways;
+        },
+    );
+
+    // Then move the deals with a flush over to the flush categories
+    for suit in Suit::ALL.iter() {
+        let suited = known.iter().filter(|c| c.suit() == *suit);
+        let known_suited = suited
+            .clone()
+            .fold(0, |mask, card| mask | 1 << card.rank().order_encoding());
+        let needed = 5_usize.saturating_sub(suited.count());
+
+        // Other suits' cards still in the deck, by rank
+        let mut offsuit = [0_u8; 13];
+        for (order, count) in offsuit.iter_mut().enumerate() {
+            let known_here = known
+                .iter()
+                .filter(|c| c.suit() != *suit && c.rank().order_encoding() as usize == order)
+                .count();
+            *count = 3 - known_here as u8;
+        }
+
+        for mask in 0..1_u32 << 13 {
+            let drawn = mask.count_ones() as usize;
+            if mask & known_suited != 0 || drawn < needed || drawn > to_come {
+                continue;
+            }
+
+            let flush_ranks = known_suited | mask;
+            let category = if has_straight(flush_ranks) {
+                Category::StraightFlush
+            } else {
+                Category::Flush
+            };
+
+            let mut with_flush = ranks;
+            for (order, count) in with_flush.iter_mut().enumerate() {
+                *count += (mask >> order & 1) as u8;
+            }
+
+            let mut others = [0_u8; 13];
+            rank_multisets(
+                &offsuit,
+                to_come - drawn,
+                0,
+                &mut others,
+                1,
+                &mut |others, ways| {
+                    let unflushed = rank_category(&add(&with_flush, others));
+                    counts[unflushed as usize] -= ways;
+                    counts[category as usize] += ways;
+                },
+            );
+        }
+    }
+
+    Ok(CategoryCounts {
+        counts,
+        total: choose(52 - known.len() as u64, to_come as u64),
+    })
+}
+
+/// Calls `visit` with every way of drawing `remaining` ranks, at most
+/// `available[r]` of rank `r`, and the number of card combinations behind it.
+fn rank_multisets<F>(
+    available: &[u8; 13],
+    remaining: usize,
+    order: usize,
+    drawn: &mut [u8; 13],
+    ways: u64,
+    visit: &mut F,
+) where
+    F: FnMut(&[u8; 13], u64),
+{
+    if remaining == 0 {
+        visit(drawn, ways);
+        return;
+    }
+    if order == 13 {
+        return;
+    }
+
+    let most = (available[order] as usize).min(remaining);
+    for count in 0..=most {
+        drawn[order] = count as u8;
+        let ways = ways * choose(u64::from(available[order]), count as u64);
+        rank_multisets(available, remaining - count, order + 1, drawn, ways, visit);
+    }
+    drawn[order] = 0;
+}
+
+/// Best category that can be made from these rank counts if suits are
+/// ignored, i.e. anything but a flush
+fn rank_category(counts: &[u8; 13]) -> Category {
+    let (mut trips, mut pairs, mut present) = (0, 0, 0_u32);
+    for (order, count) in counts.iter().enumerate() {
+        match count {
+            0 => continue,
+            1 => {}
+            2 => pairs += 1,
+            3 => trips += 1,
+            _ => return Category::FourOfAKind,
+        }
+        present |= 1 << order;
+    }
+
+    if trips >= 2 || (trips == 1 && pairs >= 1) {
+        Category::FullHouse
+    } else if has_straight(present) {
+        Category::Straight
+    } else if trips == 1 {
+        Category::ThreeOfAKind
+    } else if pairs >= 2 {
+        Category::TwoPair
+    } else if pairs == 1 {
+        Category::OnePair
+    } else {
+        Category::HighCard
+    }
+}
+
+fn has_straight(ranks: u32) -> bool {
+    // Repeat the ace below the deuce for the wheel
+    let ranks = ranks << 1 | ranks >> 12;
+    (0..10).any(|low| ranks >> low & 0x1F == 0x1F)
+}
+
+fn add(a: &[u8; 13], b: &[u8; 13]) -> [u8; 13] {
+    let mut sum = *a;
+    for (sum, b) in sum.iter_mut().zip(b.iter()) {
+        *sum += b;
+    }
+    sum
+}
+
+/// Number of ways to pick `k` of `n` things, or 0 when `k > n`
+pub const fn choose(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let mut result = 1;
+    let mut i = 0;
+    while i < k {
+        result = result * (n - i) / (i + 1);
+        i += 1;
+    }
+    result
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::parse_cards;
+    use crate::card_set::CardSet;
+    use crate::deck::Deck;
+    use crate::hand_rank::HandRank;
+
+    /// Counts by evaluating every single deal
+    fn enumerate(known: &[Card], to_come: usize) -> [u64; 9] {
+        let mut counts = [0; 9];
+        let mut cards = known.to_vec();
+        for coming in Deck::without(CardSet::from(known)).combinations(to_come) {
+            cards.truncate(known.len());
+            cards.extend(coming);
+            counts[HandRank::best_of(&cards).category() as usize] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn matches_the_published_five_card_counts() {
+        let five = hand_counts(5).unwrap();
+        assert_eq!(five.total, 2_598_960);
+        assert_eq!(
+            five.counts,
+            [1_302_540, 1_098_240, 123_552, 54_912, 10_200, 5_108, 3_744, 624, 40]
+        );
+        assert_eq!(five.counts, enumerate(&[], 5));
+    }
+
+    #[test]
+    fn matches_the_published_six_and_seven_card_counts() {
+        let six = hand_counts(6).unwrap();
+        assert_eq!(six.total, 20_358_520);
+        assert_eq!(
+            six.counts,
+            [6_612_900, 9_730_740, 2_532_816, 732_160, 361_620, 205_792, 165_984, 14_664, 1_844]
+        );
+
+        let seven = hand_counts(7).unwrap();
+        assert_eq!(seven.total, 133_784_560);
+        assert_eq!(
+            seven.counts,
+            [
+                23_294_460, 58_627_800, 31_433_400, 6_461_620, 6_180_020, 4_047_644, 3_473_184,
+                224_848, 41_584
+            ]
+        );
+        assert_eq!(seven.counts.iter().sum::<u64>(), seven.total);
+    }
+
+    #[test]
+    fn agrees_with_enumeration_given_known_cards() {
+        for (known, to_come) in &[
+            ("AhKhQh7h", 2),
+            ("AhKhQh7h2c", 2),
+            ("9s8s7d", 3),
+            ("2c2d2h", 2),
+            ("JhTh9h8h7h", 0),
+        ] {
+            let known = parse_cards(known).unwrap();
+            let counts = category_counts(&known, *to_come).unwrap();
+            assert_eq!(counts.counts, enumerate(&known, *to_come));
+            assert_eq!(counts.counts.iter().sum::<u64>(), counts.total);
+        }
+    }
+
+    #[test]
+    fn answers_conditional_questions() {
+        // Four hearts with two cards to come: miss with both of the 38
+        // other cards, else make the flush
+        let known = parse_cards("AhKhQh7h2c").unwrap();
+        let counts = category_counts(&known, 2).unwrap();
+        let miss = Ratio::new(38 * 37 / 2, 47 * 46 / 2);
+        assert_eq!(
+            counts.at_least(Category::Flush),
+            Ratio::new(miss.denominator - miss.numerator, miss.denominator)
+        );
+        assert_eq!(counts.at_least(Category::HighCard), Ratio::new(1, 1));
+        assert_eq!(Ratio::new(6, 8).to_string(), "3/4");
+        assert!((Ratio::new(1, 4).to_f64() - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_bad_hands() {
+        assert_eq!(hand_counts(4), Err(CombinatoricsError::HandSize(4)));
+        let known = parse_cards("AhAh").unwrap();
+        assert_eq!(
+            category_counts(&known, 3),
+            Err(CombinatoricsError::DuplicateCard(known[0]))
+        );
+    }
+}
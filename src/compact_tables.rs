@@ -0,0 +1,172 @@
+//! Drop-in replacements for the tables in `lookup_tables.rs`, computed on the
+//! fly instead of stored, for builds where binary size matters more than
+//! speed.
+//!
+//! Every hand value follows from counting the hands that beat it. For sets
+//! of distinct ranks, comparing hands card by card from the top is the same
+//! as comparing their 13 bit rank masks as integers, so the colexicographic
+//! rank of the mask counts the weaker sets and the rest are stronger. Paired
+//! hands are ordered by the paired ranks first and then by their kickers,
+//! which are counted the same way among the ranks left over.
+
+use crate::card::Rank;
+use crate::combinatorics::choose;
+
+/// Number of 5 rank sets
+const FIVE_RANK_SETS: u32 = 1287;
+
+/// Rank masks of the straights from ace high down to the wheel
+const STRAIGHTS: [u32; 10] = [
+    0x1F00, 0x0F80, 0x07C0, 0x03E0, 0x01F0, 0x00F8, 0x007C, 0x003E, 0x001F, 0x100F,
+];
+
+/// Counterpart of `FLUSHES[bits]`, value of five cards of one suit
+pub const fn flushes(bits: u32) -> u16 {
+    match straight(bits) {
+        Some(i) => 1 + i,
+        None if bits.count_ones() == 5 => 323 + non_straights_above(bits),
+        None => 0,
+    }
+}
+
+/// Counterpart of `UNIQUES[bits]`, value of five distinct ranks in more than
+/// one suit, or 0 when `bits` does not hold five ranks
+pub const fn uniques(bits: u32) -> u16 {
+    match straight(bits) {
+        Some(i) => 1600 + i,
+        None if bits.count_ones() == 5 => 6186 + non_straights_above(bits),
+        None => 0,
+    }
+}
+
+/// Counterpart of `VALUES[i]` where `PRODUCTS[i] == q`: value of a hand with
+/// at least one pair, from the product of its ranks' primes
+pub const fn values(q: u32) -> u16 {
+    // Factor the product back into how many of each rank there are
+    let mut counts = [0_u32; 13];
+    let mut rest = q;
+    let mut order = 0;
+    while order < 13 {
+        let prime = Rank::from_order(order as u32).prime_encoding();
+        #[allow(clippy::manual_is_multiple_of)]
+        while rest % prime == 0 {
+            counts[order] += 1;
+            rest /= prime;
+        }
+        order += 1;
+    }
+
+    // Ranks held once, twice, three times and four times
+    let mut held = [0_u32; 5];
+    let mut order = 0;
+    while order < 13 {
+        held[counts[order] as usize] |= 1 << order;
+        order += 1;
+    }
+    let (singles, pairs, trips, quads) = (held[1], held[2], held[3], held[4]);
+
+    if quads != 0 {
+        11 + 12 * above(quads, 13) + kickers_above(singles, quads, 12)
+    } else if trips != 0 && pairs != 0 {
+        167 + 12 * above(trips, 13) + kickers_above(pairs, trips, 12)
+    } else if trips != 0 {
+        1610 + 66 * above(trips, 13) + kickers_above(singles, trips, 12)
+    } else if pairs.count_ones() == 2 {
+        2468 + 11 * above(pairs, 13) + kickers_above(singles, pairs, 11)
+    } else {
+        3326 + 220 * above(pairs, 13) + kickers_above(singles, pairs, 12)
+    }
+}
+
+/// Position of a straight from ace high down to the wheel
+const fn straight(bits: u32) -> Option<u16> {
+    let mut i = 0;
+    while i < STRAIGHTS.len() {
+        if STRAIGHTS[i] == bits {
+            return Some(i as u16);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Number of 5 rank sets, other than straights, that beat `bits`
+const fn non_straights_above(bits: u32) -> u16 {
+    let mut straights_above = 0;
+    let mut i = 0;
+    while i < STRAIGHTS.len() {
+        if STRAIGHTS[i] > bits {
+            straights_above += 1;
+        }
+        i += 1;
+    }
+    (FIVE_RANK_SETS - 1 - colex(bits)) as u16 - straights_above
+}
+
+/// Number of kicker sets that beat `kickers`, drawn from the `ranks` left
+/// once the ranks in `taken` are set aside
+const fn kickers_above(kickers: u32, taken: u32, ranks: u32) -> u16 {
+    // Close the gaps the taken ranks leave so the kickers count among the
+    // remaining ranks alone
+    let mut packed = 0;
+    let mut next = 0;
+    let mut order = 0;
+    while order < 13 {
+        if taken & 1 << order == 0 {
+            packed |= (kickers >> order & 1) << next;
+            next += 1;
+        }
+        order += 1;
+    }
+    above(packed, ranks)
+}
+
+/// Number of sets of as many ranks as `bits`, out of `ranks`, that beat it
+const fn above(bits: u32, ranks: u32) -> u16 {
+    (choose(ranks as u64, bits.count_ones() as u64) as u32 - 1 - colex(bits)) as u16
+}
+
+/// Number of sets of as many ranks as `bits` with a smaller mask
+const fn colex(bits: u32) -> u32 {
+    let mut rank = 0;
+    let mut nth = 0;
+    let mut order = 0;
+    while order < 13 {
+        if bits & 1 << order != 0 {
+            nth += 1;
+            rank += choose(order as u64, nth as u64) as u32;
+        }
+        order += 1;
+    }
+    rank
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::lookup_tables::{FLUSHES, PRODUCTS, UNIQUES, VALUES};
+
+    #[test]
+    fn matches_the_flush_and_unique_tables() {
+        for bits in 0..FLUSHES.len() {
+            assert_eq!(flushes(bits as u32), FLUSHES[bits], "flush {:#x}", bits);
+            assert_eq!(uniques(bits as u32), UNIQUES[bits], "unique {:#x}", bits);
+        }
+    }
+
+    #[test]
+    fn matches_the_paired_values_table() {
+        for (q, value) in PRODUCTS.iter().zip(VALUES.iter()) {
+            assert_eq!(values(*q), *value, "product {}", q);
+        }
+    }
+
+    #[test]
+    fn counts_smaller_sets() {
+        assert_eq!(colex(0x001F), 0);
+        assert_eq!(colex(0x1F00), FIVE_RANK_SETS - 1);
+        assert_eq!(above(1 << 12, 13), 0);
+        assert_eq!(above(1, 13), 12);
+    }
+}
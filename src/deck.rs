@@ -0,0 +1,187 @@
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::rng::Rng;
+
+/// The cards that are still left to be dealt.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Deck(CardSet);
+
+impl Deck {
+    /// A fresh 52 card deck
+    pub const fn new() -> Self {
+        Deck(CardSet::full())
+    }
+
+    /// A fresh deck with the `known` cards already taken out of it
+    pub const fn without(known: CardSet) -> Self {
+        Deck(CardSet::full().difference(known))
+    }
+
+    pub const fn cards(&self) -> CardSet {
+        self.0
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub const fn contains(&self, card: Card) -> bool {
+        self.0.contains(card)
+    }
+
+    /// Takes a card out of the deck, returning `false` if it was already gone
+    pub fn remove(&mut self, card: Card) -> bool {
+        self.0.remove(card)
+    }
+
+    /// Deals a uniformly random card out of the deck
+    pub fn draw<R: Rng>(&mut self, rng: &mut R) -> Option<Card> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut mask = self.0.mask();
+        for _ in 0..rng.below(self.len() as u64) {
+            mask &= mask - 1; // Skip past the lowest remaining card
+        }
+
+        let card = Card::from_index(mask.trailing_zeros() as usize);
+        self.remove(card);
+        Some(card)
+    }
+
+    /// Every way of dealing `count` more cards from the deck, ignoring order
+    pub fn combinations(&self, count: usize) -> Combinations {
+        Combinations::new(self, count)
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
+
+/// Iterator over the `k` card subsets of a deck in lexicographic order of
+/// `Card::index`.
+pub struct Combinations {
+    cards: [Card; 52],
+    len: usize,
+    positions: [usize; 52],
+    count: usize,
+    done: bool,
+}
+
+impl Combinations {
+    fn new(deck: &Deck, count: usize) -> Self {
+        let mut cards = [Card::from_index(0); 52];
+        let mut len = 0;
+        for card in deck.cards() {
+            cards[len] = card;
+            len += 1;
+        }
+
+        let mut positions = [0; 52];
+        for (i, position) in positions.iter_mut().enumerate().take(count) {
+            *position = i;
+        }
+
+        Combinations {
+            cards,
+            len,
+            positions,
+            count,
+            done: count > len,
+        }
+    }
+
+    fn advance(&mut self) {
+        // Find the rightmost position that can still move to the right
+        let mut i = self.count;
+        while i > 0 {
+            i -= 1;
+            if self.positions[i] != i + self.len - self.count {
+                self.positions[i] += 1;
+                for j in i + 1..self.count {
+                    self.positions[j] = self.positions[j - 1] + 1;
+                }
+                return;
+            }
+        }
+        self.done = true;
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = CardSet;
+
+    fn next(&mut self) -> Option<CardSet> {
+        if self.done {
+            return None;
+        }
+
+        let mut dealt = CardSet::empty();
+        for &position in &self.positions[..self.count] {
+            dealt.insert(self.cards[position]);
+        }
+        self.advance();
+
+        Some(dealt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{Rank, Suit};
+
+    #[test]
+    fn removes_known_cards() {
+        let ace = Card::new(Rank::Ace, Suit::Hearts);
+        let mut deck = Deck::new();
+
+        assert_eq!(deck.len(), 52);
+        assert!(deck.remove(ace));
+        assert!(!deck.remove(ace));
+        assert!(!deck.contains(ace));
+        assert_eq!(deck, Deck::without(CardSet::from(&[ace][..])));
+    }
+
+    #[test]
+    fn draws_every_card_exactly_once() {
+        let mut rng = crate::rng::SplitMix64::new(1);
+        let mut deck = Deck::new();
+        let mut drawn = CardSet::empty();
+
+        while let Some(card) = deck.draw(&mut rng) {
+            assert!(drawn.insert(card));
+        }
+        assert_eq!(drawn, CardSet::full());
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn enumerates_every_combination_once() {
+        let deck = Deck::new();
+
+        assert_eq!(deck.combinations(0).count(), 1);
+        assert_eq!(deck.combinations(1).count(), 52);
+        assert_eq!(deck.combinations(2).count(), 1326);
+        assert_eq!(deck.combinations(3).count(), 22100);
+
+        let mut seen = std::collections::HashSet::new();
+        for combination in deck.combinations(2) {
+            assert_eq!(combination.len(), 2);
+            assert!(seen.insert(combination.mask()));
+        }
+
+        let tiny = Deck::without(CardSet::from_mask(!0b111));
+        assert_eq!(tiny.combinations(3).count(), 1);
+        assert_eq!(tiny.combinations(4).count(), 0);
+    }
+}
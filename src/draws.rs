@@ -0,0 +1,308 @@
+use crate::board::{self, BoardError};
+use crate::card::{Card, Rank, Suit};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DrawKind {
+    /// Four to the best flush still possible
+    NutFlushDraw,
+    /// Four to a flush that is not the nuts
+    FlushDraw,
+    /// Four in a row, open at both ends
+    OpenEnder,
+    /// Two different ranks each fill an inside straight, e.g. 9-7-6-5-3
+    DoubleGutshot,
+    /// A single rank fills the straight
+    Gutshot,
+    /// Three to a flush on the flop, needing both the turn and the river
+    BackdoorFlushDraw,
+    /// Three to a straight on the flop, needing both the turn and the river
+    BackdoorStraightDraw,
+}
+
+impl DrawKind {
+    pub fn is_flush_draw(&self) -> bool {
+        matches!(self, DrawKind::NutFlushDraw | DrawKind::FlushDraw)
+    }
+
+    pub fn is_straight_draw(&self) -> bool {
+        matches!(
+            self,
+            DrawKind::OpenEnder | DrawKind::DoubleGutshot | DrawKind::Gutshot
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draw {
+    pub kind: DrawKind,
+    /// The hole cards that take part in the draw
+    pub hole_cards: Vec<Card>,
+}
+
+/// Every draw a holding has on a flop or turn. Made hands are not draws, so a
+/// player who already has a flush has no flush draw.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draws {
+    pub draws: Vec<Draw>,
+}
+
+impl Draws {
+    pub fn has(&self, kind: DrawKind) -> bool {
+        self.draws.iter().any(|draw| draw.kind == kind)
+    }
+
+    pub fn has_flush_draw(&self) -> bool {
+        self.draws.iter().any(|draw| draw.kind.is_flush_draw())
+    }
+
+    pub fn has_straight_draw(&self) -> bool {
+        self.draws.iter().any(|draw| draw.kind.is_straight_draw())
+    }
+
+    /// A flush draw and a straight draw at the same time
+    pub fn is_combo_draw(&self) -> bool {
+        self.has_flush_draw() && self.has_straight_draw()
+    }
+}
+
+/// Finds the flush and straight draws `hole` has on a flop or turn `board`.
+///
+/// Only draws that use at least one hole card count: four to a straight on
+/// the board is everybody's draw, not ours.
+pub fn draws(hole: [Card; 2], board: &[Card]) -> Result<Draws, BoardError> {
+    board::validate(board, 3..=4, &hole)?;
+
+    let mut draws = Vec::new();
+    flush_draws(hole, board, &mut draws);
+    straight_draws(hole, board, &mut draws);
+    Ok(Draws { draws })
+}
+
+fn flush_draws(hole: [Card; 2], board: &[Card], draws: &mut Vec<Draw>) {
+    for suit in Suit::ALL.iter() {
+        let hole_cards: Vec<Card> = hole.iter().copied().filter(|c| c.suit() == *suit).collect();
+        if hole_cards.is_empty() {
+            continue;
+        }
+        let on_board = board.iter().filter(|card| card.suit() == *suit).count();
+
+        let kind = match hole_cards.len() + on_board {
+            4 => {
+                // The nut flush belongs to whoever holds the highest card of
+                // the suit that is not already on the board
+                let nut = Rank::ALL
+                    .iter()
+                    .map(|rank| Card::new(*rank, *suit))
+                    .find(|card| !board.contains(card))
+                    .unwrap();
+                if hole_cards.contains(&nut) {
+                    DrawKind::NutFlushDraw
+                } else {
+                    DrawKind::FlushDraw
+                }
+            }
+            3 if board.len() == 3 => DrawKind::BackdoorFlushDraw,
+            _ => continue,
+        };
+        draws.push(Draw { kind, hole_cards });
+    }
+}
+
+fn straight_draws(hole: [Card; 2], board: &[Card], draws: &mut Vec<Draw>) {
+    let board_ranks = rank_mask(board);
+    let ranks = board_ranks | rank_mask(&hole);
+    if straight_top(ranks) > straight_top(board_ranks) {
+        // Already holding a straight of our own
+        return;
+    }
+
+    // Ranks that give us a straight better than anything the board makes
+    // with that card on its own, and the straights they make
+    let mut filling = 0_u32;
+    let mut straights = 0_u32;
+    for order in 0..13 {
+        let card = 1 << order;
+        if ranks & card != 0 {
+            continue;
+        }
+        let top = straight_top(ranks | card);
+        if top > straight_top(board_ranks | card) {
+            filling |= card;
+            straights |= window(top.unwrap());
+        }
+    }
+
+    let kind = match filling.count_ones() {
+        0 => None,
+        1 => Some(DrawKind::Gutshot),
+        _ if open_ended(with_low_ace(ranks), with_low_ace(filling)) => Some(DrawKind::OpenEnder),
+        _ => Some(DrawKind::DoubleGutshot),
+    };
+
+    if let Some(kind) = kind {
+        draws.push(Draw {
+            kind,
+            hole_cards: in_window(hole, straights),
+        });
+    } else if board.len() == 3 {
+        // Three of the five ranks of some straight, at least one of them ours
+        let hole_ranks = with_low_ace(rank_mask(&hole));
+        let ranks = with_low_ace(ranks);
+        let mut backdoors = 0;
+        for low in 0..10 {
+            let window = 0x1F << low;
+            if (ranks & window).count_ones() == 3 && hole_ranks & window != 0 {
+                backdoors |= window;
+            }
+        }
+        if backdoors != 0 {
+            draws.push(Draw {
+                kind: DrawKind::BackdoorStraightDraw,
+                hole_cards: in_window(hole, backdoors),
+            });
+        }
+    }
+}
+
+/// Bit per rank, by order encoding
+fn rank_mask(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .fold(0, |mask, card| mask | 1 << card.rank().order_encoding())
+}
+
+/// Moves every rank up a bit and repeats the ace below the deuce, so that
+/// every straight is five consecutive bits
+fn with_low_ace(ranks: u32) -> u32 {
+    (ranks << 1) | (ranks >> 12)
+}
+
+/// Order encoding of the top card of the best straight in `ranks`
+fn straight_top(ranks: u32) -> Option<u32> {
+    let ranks = with_low_ace(ranks);
+    (0..10)
+        .rev()
+        .find(|low| ranks >> low & 0x1F == 0x1F)
+        .map(|low| low + 3)
+}
+
+/// The five ranks of the straight topped by `top`, in the shifted layout of
+/// `with_low_ace`
+fn window(top: u32) -> u32 {
+    0x1F << (top - 3)
+}
+
+/// Four ranks in a row with a filling rank at both ends
+fn open_ended(ranks: u32, filling: u32) -> bool {
+    (1..10).any(|low| ranks >> low & 0xF == 0xF && filling >> (low - 1) & 0x21 == 0x21)
+}
+
+fn in_window(hole: [Card; 2], window: u32) -> Vec<Card> {
+    hole.iter()
+        .copied()
+        .filter(|card| with_low_ace(rank_mask(&[*card])) & window != 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, parse_hole};
+
+    fn draws_for(hole: &str, board: &str) -> Draws {
+        draws(parse_hole(hole), &parse_cards(board).unwrap()).unwrap()
+    }
+
+    fn kinds(draws: &Draws) -> Vec<DrawKind> {
+        draws.draws.iter().map(|draw| draw.kind).collect()
+    }
+
+    #[test]
+    fn finds_flush_draws() {
+        let nut = draws_for("AhKh", "Qh7h2c");
+        assert_eq!(
+            kinds(&nut),
+            vec![DrawKind::NutFlushDraw, DrawKind::BackdoorStraightDraw]
+        );
+        assert_eq!(nut.draws[0].hole_cards.len(), 2);
+
+        let second = draws_for("Kh5c", "Qh7h2h");
+        assert_eq!(kinds(&second), vec![DrawKind::FlushDraw]);
+        assert_eq!(second.draws[0].hole_cards, parse_cards("Kh").unwrap());
+
+        // With the ace on board the king is the nuts
+        let king = draws_for("Kh5c", "Ah7h2h");
+        assert!(king.has(DrawKind::NutFlushDraw));
+
+        let backdoor = draws_for("Ah5c", "Qh7h2c");
+        assert!(backdoor.has(DrawKind::BackdoorFlushDraw));
+        assert!(!draws_for("Ah5c", "Qh7h2c4d").has(DrawKind::BackdoorFlushDraw));
+    }
+
+    #[test]
+    fn finds_straight_draws() {
+        assert_eq!(
+            kinds(&draws_for("9h8h", "7c6d2s")),
+            vec![DrawKind::OpenEnder]
+        );
+        assert_eq!(kinds(&draws_for("9c7d", "8s5h2c")), vec![DrawKind::Gutshot]);
+        assert_eq!(
+            kinds(&draws_for("9c7d", "6s5h3c")),
+            vec![DrawKind::DoubleGutshot]
+        );
+        // Only a ten fills ace-king-queen-jack
+        assert_eq!(kinds(&draws_for("AcKd", "QsJh2c")), vec![DrawKind::Gutshot]);
+        assert_eq!(kinds(&draws_for("Ac2d", "3s4h9c")), vec![DrawKind::Gutshot]);
+    }
+
+    #[test]
+    fn reports_the_hole_cards_involved() {
+        let draws = draws_for("9hAc", "8s7d6c");
+        assert_eq!(kinds(&draws), vec![DrawKind::OpenEnder]);
+        assert_eq!(draws.draws[0].hole_cards, parse_cards("9h").unwrap());
+    }
+
+    #[test]
+    fn ignores_draws_that_are_all_on_the_board() {
+        assert!(draws_for("AcKd", "9s8h7c6d").draws.is_empty());
+        assert!(draws_for("Ac2d", "9h8h7h6h").draws.is_empty());
+    }
+
+    #[test]
+    fn spots_combo_draws() {
+        let combo = draws_for("JhTh", "9h8c2h");
+        assert!(combo.is_combo_draw());
+        assert!(combo.has(DrawKind::FlushDraw));
+        assert!(combo.has(DrawKind::OpenEnder));
+        assert!(!draws_for("9h8h", "7c6d2s").is_combo_draw());
+    }
+
+    #[test]
+    fn made_hands_are_not_draws() {
+        let made = draws_for("9h8h", "7h6h5h");
+        assert!(!made.has_flush_draw());
+        assert!(!made.has_straight_draw());
+    }
+
+    #[test]
+    fn rejects_bad_boards() {
+        let hole = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let river = parse_cards("Qh7h2c3d4s").unwrap();
+        assert_eq!(
+            draws(hole, &river),
+            Err(BoardError::BoardSize {
+                cards: 5,
+                min: 3,
+                max: 4
+            })
+        );
+        assert_eq!(
+            draws(hole, &parse_cards("Qh7hAh").unwrap()),
+            Err(BoardError::DuplicateCard(hole[0]))
+        );
+    }
+}
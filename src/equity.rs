@@ -0,0 +1,332 @@
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::deck::Deck;
+use crate::hand_rank::HandRank;
+use crate::strength::Strength;
+
+/// How a single player fares over every runout.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlayerEquity {
+    /// Fraction of runouts the player wins outright
+    pub win: f64,
+    /// Fraction of runouts the player splits with at least one other player
+    pub tie: f64,
+    /// Share of the pot the player can expect, counting a split between `k`
+    /// players as `1/k` of a win
+    pub equity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityResult {
+    /// Number of distinct boards that were dealt out
+    pub runouts: u64,
+    /// One entry per player, in the order the hole cards were given
+    pub players: Vec<PlayerEquity>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    /// Equity needs at least two players to be meaningful
+    TooFewPlayers,
+    /// A Hold'em board never has more than five cards
+    BoardTooLong(usize),
+    /// The same card was given twice across hole cards, board and dead cards
+    DuplicateCard(Card),
+    /// There are not enough cards left in the deck to complete the board
+    NotEnoughCards,
+    /// A range has no combos left once the known cards are removed from it
+    EmptyRange,
+    /// A simulation was asked to deal no boards at all
+    NoTrials,
+}
+
+impl core::fmt::Display for EquityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            EquityError::TooFewPlayers => write!(f, "at least two players are needed"),
+            EquityError::BoardTooLong(len) => {
+                write!(f, "a board has at most 5 cards, got {}", len)
+            }
+            EquityError::DuplicateCard(card) => {
+                write!(
+                    f,
+                    "{:?}{:?} appears more than once",
+                    card.rank(),
+                    card.suit()
+                )
+            }
+            EquityError::NotEnoughCards => write!(f, "not enough cards left to deal the board"),
+            EquityError::EmptyRange => write!(f, "a range has no combos left to play"),
+            EquityError::NoTrials => write!(f, "a simulation needs at least one trial"),
+        }
+    }
+}
+
+impl std::error::Error for EquityError {}
+
+/// Computes the exact all-in equity of Hold'em hands by dealing out every
+/// possible completion of `board` from the cards that are not held by a
+/// player, on the board or listed as `dead`.
+pub fn exact_equity(
+    players: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<EquityResult, EquityError> {
+    let deck = validate(players, board, dead)?;
+
+    let mut tally = Tally::new(players.len());
+    let mut cards = [players[0][0]; 7];
+    cards[2..2 + board.len()].copy_from_slice(board);
+
+    for runout in deck.combinations(5 - board.len()) {
+        for (slot, card) in cards[2 + board.len()..].iter_mut().zip(runout) {
+            *slot = card;
+        }
+        tally.deal(players, &mut cards);
+    }
+
+    Ok(tally.finish())
+}
+
+/// Checks the inputs of an equity calculation and returns the deck the rest of
+/// the board gets dealt from.
+pub(crate) fn validate(
+    players: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Deck, EquityError> {
+    if players.len() < 2 {
+        return Err(EquityError::TooFewPlayers);
+    }
+
+    let hole_cards: Vec<Card> = players.iter().flatten().copied().collect();
+    validate_board(&hole_cards, board, dead)
+}
+
+/// Checks that no card is given twice and that the board can still be
+/// completed, returning the deck the rest of the board gets dealt from.
+pub(crate) fn validate_board(
+    hole_cards: &[Card],
+    board: &[Card],
+    dead: &[Card],
+) -> Result<Deck, EquityError> {
+    if board.len() > 5 {
+        return Err(EquityError::BoardTooLong(board.len()));
+    }
+
+    let known = CardSet::from_distinct(hole_cards.iter().chain(board).chain(dead).copied())
+        .map_err(EquityError::DuplicateCard)?;
+
+    let deck = Deck::without(known);
+    if deck.len() < 5 - board.len() {
+        return Err(EquityError::NotEnoughCards);
+    }
+    Ok(deck)
+}
+
+/// Running win and split counts for a set of players.
+pub(crate) struct Tally {
+    runouts: u64,
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    shares: Vec<f64>,
+    squares: Vec<f64>,
+    strengths: Vec<Strength>,
+}
+
+impl Tally {
+    pub(crate) fn new(players: usize) -> Self {
+        Tally {
+            runouts: 0,
+            wins: vec![0; players],
+            ties: vec![0; players],
+            shares: vec![0.0; players],
+            squares: vec![0.0; players],
+            strengths: vec![Strength::MIN; players],
+        }
+    }
+
+    /// Scores one complete board. `cards` holds the board in its last five
+    /// slots, the first two get overwritten by each player's hole cards.
+    pub(crate) fn deal(&mut self, players: &[[Card; 2]], cards: &mut [Card; 7]) {
+        let mut best = Strength::MIN;
+        for (strength, hole) in self.strengths.iter_mut().zip(players) {
+            cards[..2].copy_from_slice(hole);
+            *strength = HandRank::best_of(cards).strength();
+            best = best.max(*strength);
+        }
+
+        let winners = self.strengths.iter().filter(|s| **s == best).count();
+        for (player, strength) in self.strengths.iter().enumerate() {
+            if *strength != best {
+                continue;
+            }
+            if winners == 1 {
+                self.wins[player] += 1;
+            } else {
+                self.ties[player] += 1;
+            }
+            let share = 1.0 / winners as f64;
+            self.shares[player] += share;
+            self.squares[player] += share * share;
+        }
+        self.runouts += 1;
+    }
+
+    pub(crate) fn runouts(&self) -> u64 {
+        self.runouts
+    }
+
+    /// Standard error of a player's mean share of the pot, treating every
+    /// runout as an independent sample.
+    pub(crate) fn std_error(&self, player: usize) -> f64 {
+        if self.runouts < 2 {
+            return f64::INFINITY;
+        }
+
+        let n = self.runouts as f64;
+        let mean = self.shares[player] / n;
+        let variance = (self.squares[player] / n - mean * mean).max(0.0) * n / (n - 1.0);
+        (variance / n).sqrt()
+    }
+
+    pub(crate) fn finish(self) -> EquityResult {
+        let runouts = self.runouts as f64;
+        let players = self
+            .wins
+            .iter()
+            .zip(&self.ties)
+            .zip(&self.shares)
+            .map(|((wins, ties), shares)| PlayerEquity {
+                win: *wins as f64 / runouts,
+                tie: *ties as f64 / runouts,
+                equity: shares / runouts,
+            })
+            .collect();
+
+        EquityResult {
+            runouts: self.runouts,
+            players,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{Rank, Suit};
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    #[test]
+    fn counts_outs_on_the_turn() {
+        let hero = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let villain = [
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Queen, Suit::Diamonds),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Nine, Suit::Clubs),
+            card(Rank::Three, Suit::Spades),
+        ];
+
+        let result = exact_equity(&[hero, villain], &board, &[]).unwrap();
+
+        // Nine hearts, three aces and three kings
+        assert_eq!(result.runouts, 44);
+        assert_eq!(result.players[0].win, 15.0 / 44.0);
+        assert_eq!(result.players[0].equity, 15.0 / 44.0);
+        assert_eq!(result.players[1].win, 29.0 / 44.0);
+        assert_eq!(result.players[1].tie, 0.0);
+
+        // Taking an ace out of the deck takes away one of the outs
+        let result =
+            exact_equity(&[hero, villain], &board, &[card(Rank::Ace, Suit::Clubs)]).unwrap();
+        assert_eq!(result.runouts, 43);
+        assert_eq!(result.players[0].win, 14.0 / 43.0);
+    }
+
+    #[test]
+    fn splits_identical_hands() {
+        let hero = [
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::King, Suit::Diamonds),
+        ];
+        let villain = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Nine, Suit::Spades),
+            card(Rank::Queen, Suit::Diamonds),
+        ];
+
+        let result = exact_equity(&[hero, villain], &board, &[]).unwrap();
+
+        for player in &result.players {
+            assert_eq!(player.win, 0.0);
+            assert_eq!(player.tie, 1.0);
+            assert_eq!(player.equity, 0.5);
+        }
+    }
+
+    #[test]
+    fn enumerates_every_flop_runout() {
+        let hero = [
+            card(Rank::Eight, Suit::Spades),
+            card(Rank::Eight, Suit::Clubs),
+        ];
+        let villain = [
+            card(Rank::Ace, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+        ];
+        let other = [
+            card(Rank::Six, Suit::Hearts),
+            card(Rank::Five, Suit::Hearts),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Jack, Suit::Spades),
+        ];
+
+        let result = exact_equity(&[hero, villain, other], &board, &[]).unwrap();
+
+        assert_eq!(result.runouts, 903); // 43 choose 2
+        let total: f64 = result.players.iter().map(|p| p.equity).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(result.players[0].equity > result.players[1].equity);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let hero = [
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::King, Suit::Diamonds),
+        ];
+        let villain = [card(Rank::Ace, Suit::Clubs), card(Rank::King, Suit::Hearts)];
+
+        assert_eq!(
+            exact_equity(&[hero], &[], &[]),
+            Err(EquityError::TooFewPlayers)
+        );
+        assert_eq!(
+            exact_equity(&[hero, villain], &[], &[]),
+            Err(EquityError::DuplicateCard(card(Rank::Ace, Suit::Clubs)))
+        );
+        assert_eq!(
+            exact_equity(&[hero, hero], &[hero[0]; 6], &[]),
+            Err(EquityError::BoardTooLong(6))
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::hand_rank::HandRank;
+
+/// Relabels the suits of `cards` so that every set of cards that only
+/// differs by a renaming of suits maps to the same set.
+///
+/// Suits are put in order of how many cards they hold, with ties broken by
+/// the ranks held, and then handed out spades first in `Card::index` order.
+/// Renaming suits never changes a hand's value, so this makes a good cache
+/// key: 7 card hands collapse from 133 million sets to about 6 million.
+pub fn canonical(cards: CardSet) -> CardSet {
+    let mut suits = [0_u64; 4];
+    for card in cards {
+        let index = card.index();
+        suits[index % 4] |= 1 << (index / 4);
+    }
+    suits.sort_unstable_by(|a, b| b.count_ones().cmp(&a.count_ones()).then(b.cmp(a)));
+
+    let mut mask = 0;
+    for (suit, ranks) in suits.iter().enumerate() {
+        for order in 0..13 {
+            if ranks & 1 << order != 0 {
+                mask |= 1 << (order * 4 + suit);
+            }
+        }
+    }
+    CardSet::from_mask(mask)
+}
+
+/// Hits and misses since a cache was created or last cleared.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups answered from the cache, or 0 before the first one
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Bounded least-recently-used cache in front of `HandRank::best_of`.
+///
+/// Hands are keyed by their `canonical` card set, so a hit on one hand
+/// answers for every suit relabelling of it too. Once `capacity` classes
+/// are stored, the one used longest ago makes way for the next.
+#[derive(Debug, Clone)]
+pub struct EvalCache {
+    capacity: usize,
+    slots: HashMap<CardSet, usize>,
+    entries: Vec<Entry>,
+    // Most and least recently used entries, the two ends of the list
+    head: usize,
+    tail: usize,
+    stats: CacheStats,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Entry {
+    key: CardSet,
+    rank: HandRank,
+    prev: usize,
+    next: usize,
+}
+
+/// Marks the end of the recency list
+const NONE: usize = usize::MAX;
+
+impl EvalCache {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "A cache needs room for at least one hand");
+        EvalCache {
+            capacity,
+            slots: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            head: NONE,
+            tail: NONE,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Same as `HandRank::best_of(cards)`, looked up when it can be. The
+    /// cards must all be different.
+    pub fn evaluate(&mut self, cards: &[Card]) -> HandRank {
+        let key = canonical(CardSet::from(cards));
+        match self.get(key) {
+            Some(rank) => rank,
+            None => {
+                let rank = HandRank::best_of(cards);
+                self.insert(key, rank);
+                rank
+            }
+        }
+    }
+
+    /// Looks up a canonical key, counting the hit or miss
+    fn get(&mut self, key: CardSet) -> Option<HandRank> {
+        match self.slots.get(&key) {
+            Some(&slot) => {
+                self.stats.hits += 1;
+                self.unlink(slot);
+                self.push_front(slot);
+                Some(self.entries[slot].rank)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, key: CardSet, rank: HandRank) {
+        let entry = Entry {
+            key,
+            rank,
+            prev: NONE,
+            next: NONE,
+        };
+
+        let slot = if let Some(&slot) = self.slots.get(&key) {
+            // Another thread got there first
+            self.unlink(slot);
+            slot
+        } else if self.entries.len() < self.capacity {
+            self.entries.push(entry);
+            self.entries.len() - 1
+        } else {
+            let oldest = self.tail;
+            self.unlink(oldest);
+            self.slots.remove(&self.entries[oldest].key);
+            self.entries[oldest] = entry;
+            oldest
+        };
+        self.slots.insert(key, slot);
+        self.push_front(slot);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Number of hand classes currently stored
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Forgets every stored hand along with the statistics
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.entries.clear();
+        self.head = NONE;
+        self.tail = NONE;
+        self.stats = CacheStats::default();
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Entry { prev, next, .. } = self.entries[slot];
+        match prev {
+            NONE => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NONE => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.entries[slot].prev = NONE;
+        self.entries[slot].next = self.head;
+        match self.head {
+            NONE => self.tail = slot,
+            head => self.entries[head].prev = slot,
+        }
+        self.head = slot;
+    }
+}
+
+/// An `EvalCache` behind a lock, for sharing one cache between the threads
+/// of a parallel simulation.
+#[derive(Debug)]
+pub struct SharedEvalCache(Mutex<EvalCache>);
+
+impl SharedEvalCache {
+    pub fn new(capacity: usize) -> Self {
+        SharedEvalCache(Mutex::new(EvalCache::new(capacity)))
+    }
+
+    /// Same as `HandRank::best_of(cards)`. The lock is not held while a miss
+    /// is being evaluated, so threads only wait on each other for lookups.
+    pub fn evaluate(&self, cards: &[Card]) -> HandRank {
+        let key = canonical(CardSet::from(cards));
+        if let Some(rank) = self.0.lock().unwrap().get(key) {
+            return rank;
+        }
+
+        let rank = HandRank::best_of(cards);
+        self.0.lock().unwrap().insert(key, rank);
+        rank
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.0.lock().unwrap().stats()
+    }
+
+    pub fn clear(&self) {
+        self.0.lock().unwrap().clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::parse_cards;
+    use crate::deck::Deck;
+    use crate::rng::SplitMix64;
+
+    fn set(cards: &str) -> CardSet {
+        CardSet::from(parse_cards(cards).unwrap().as_slice())
+    }
+
+    #[test]
+    fn relabels_suits_consistently() {
+        assert_eq!(canonical(set("AhKh7d2c9h")), canonical(set("AsKs7c2d9s")));
+        assert_ne!(canonical(set("AhKh7d2c9h")), canonical(set("AhKd7h2c9h")));
+        assert_eq!(canonical(set("AhKh7d2c9h")).len(), 5);
+        let canonical_once = canonical(set("QcJc3d3h8s"));
+        assert_eq!(canonical(canonical_once), canonical_once);
+    }
+
+    #[test]
+    fn agrees_with_direct_evaluation() {
+        let mut cache = EvalCache::new(1_000);
+        let mut rng = SplitMix64::new(49);
+        for _ in 0..5_000 {
+            let mut deck = Deck::new();
+            let cards: Vec<Card> = (0..7).map(|_| deck.draw(&mut rng).unwrap()).collect();
+            assert_eq!(cache.evaluate(&cards), HandRank::best_of(&cards));
+        }
+        assert_eq!(cache.len(), 1_000);
+        assert_eq!(cache.stats().hits + cache.stats().misses, 5_000);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used() {
+        let mut cache = EvalCache::new(2);
+        let first = parse_cards("AhKhQhJhTh").unwrap();
+        let second = parse_cards("2c2d2h7s7d").unwrap();
+        let third = parse_cards("9c8d7h6s5d").unwrap();
+
+        cache.evaluate(&first);
+        cache.evaluate(&second);
+        cache.evaluate(&first);
+        cache.evaluate(&third);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3 });
+
+        // `second` was pushed out by `third`, `first` was not
+        cache.evaluate(&parse_cards("AsKsQsJsTs").unwrap());
+        cache.evaluate(&second);
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 4 });
+        assert_eq!(cache.stats().hit_rate(), 1.0 / 3.0);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().hit_rate(), 0.0);
+    }
+
+    #[test]
+    fn shares_one_cache_between_threads() {
+        let cache = SharedEvalCache::new(10_000);
+        std::thread::scope(|scope| {
+            for seed in 0..4 {
+                let cache = &cache;
+                scope.spawn(move || {
+                    let mut rng = SplitMix64::new(seed);
+                    for _ in 0..1_000 {
+                        let mut deck = Deck::new();
+                        let cards: Vec<Card> =
+                            (0..5).map(|_| deck.draw(&mut rng).unwrap()).collect();
+                        assert_eq!(cache.evaluate(&cards), HandRank::best_of(&cards));
+                    }
+                });
+            }
+        });
+        let stats = cache.stats();
+        assert_eq!(stats.hits + stats.misses, 4_000);
+    }
+}
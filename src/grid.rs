@@ -0,0 +1,175 @@
+use crate::card::Rank;
+use crate::range::Range;
+use crate::starting_hand::StartingHand;
+
+const SIZE: usize = Rank::ALL.len();
+
+/// A value for every starting hand, laid out as the standard 13x13 grid:
+/// pairs on the diagonal, suited hands above it and offsuit hands below.
+///
+/// Values are expected to lie between 0 and 1, such as the weight of a hand
+/// in a range or its equity. Hands with a value of 0 are left blank.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    values: Vec<f64>,
+}
+
+impl Grid {
+    /// Builds a grid from one value per hand, indexed by `StartingHand::index`
+    pub fn new(values: Vec<f64>) -> Self {
+        assert_eq!(
+            values.len(),
+            StartingHand::COUNT,
+            "A grid needs a value for every starting hand"
+        );
+        Grid { values }
+    }
+
+    pub fn from_fn<F>(value: F) -> Self
+    where
+        F: Fn(StartingHand) -> f64,
+    {
+        Grid::new(StartingHand::all().map(value).collect())
+    }
+
+    /// Average weight of each hand's combos in `range`
+    pub fn from_range(range: &Range) -> Self {
+        let mut values = vec![0.0; StartingHand::COUNT];
+        for (combo, weight) in range.combos() {
+            let hand = combo.starting_hand();
+            values[hand.index()] += weight / hand.combo_count() as f64;
+        }
+        Grid::new(values)
+    }
+
+    pub fn value(&self, hand: StartingHand) -> f64 {
+        self.values[hand.index()]
+    }
+
+    /// Renders the grid with every cell coloured from red for low values
+    /// through yellow to green for high ones, using 24-bit ANSI colours.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in 0..SIZE {
+            for col in 0..SIZE {
+                let hand = StartingHand::from_index(row * SIZE + col);
+                let value = self.value(hand);
+                if value > 0.0 {
+                    let (r, g, b) = heat(value);
+                    out.push_str(&format!(
+                        "\x1b[30;48;2;{};{};{}m {:<4}\x1b[0m",
+                        r,
+                        g,
+                        b,
+                        hand.to_string()
+                    ));
+                } else {
+                    out.push_str(&format!(" {:<4}", hand.to_string()));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl From<&Range> for Grid {
+    fn from(range: &Range) -> Self {
+        Grid::from_range(range)
+    }
+}
+
+/// Plain text version of the grid for terminals without colour: hands with a
+/// value are written out and the rest are dots.
+impl core::fmt::Display for Grid {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for row in 0..SIZE {
+            let mut line = String::new();
+            for col in 0..SIZE {
+                let hand = StartingHand::from_index(row * SIZE + col);
+                let cell = if self.value(hand) > 0.0 {
+                    hand.to_string()
+                } else {
+                    ".".to_string()
+                };
+                line.push_str(&format!("{:<5}", cell));
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Red at 0, yellow at 0.5 and green at 1
+fn heat(value: f64) -> (u8, u8, u8) {
+    const RED: (f64, f64, f64) = (214.0, 48.0, 49.0);
+    const YELLOW: (f64, f64, f64) = (230.0, 200.0, 60.0);
+    const GREEN: (f64, f64, f64) = (60.0, 170.0, 80.0);
+
+    let value = value.clamp(0.0, 1.0);
+    let (from, to, t) = if value < 0.5 {
+        (RED, YELLOW, value * 2.0)
+    } else {
+        (YELLOW, GREEN, value * 2.0 - 1.0)
+    };
+    let mix = |a: f64, b: f64| (a + (b - a) * t).round() as u8;
+    (mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::starting_hand::Shape;
+
+    #[test]
+    fn lays_out_a_range() {
+        let range: Range = "QQ+, AKs, T9o:0.5".parse().unwrap();
+        let grid = Grid::from(&range);
+        let lines: Vec<String> = grid.to_string().lines().map(String::from).collect();
+
+        assert_eq!(lines.len(), 13);
+        assert!(lines[0].starts_with("AA   AKs  .    ."));
+        assert!(lines[1].starts_with(".    KK   .    ."));
+        assert_eq!(lines[5].split_whitespace().nth(4), Some("T9o"));
+        assert_eq!(grid.value("T9o".parse().unwrap()), 0.5);
+        assert_eq!(grid.value("AKo".parse().unwrap()), 0.0);
+    }
+
+    #[test]
+    fn labels_tens_with_a_t() {
+        let range: Range = "TT, T9s, AT".parse().unwrap();
+        let grid = Grid::from(&range);
+        let lines: Vec<String> = grid.to_string().lines().map(String::from).collect();
+
+        assert!(lines[4].starts_with("ATo  .    .    .    TT   T9s  ."));
+        assert_eq!(lines[0].split_whitespace().nth(4), Some("ATs"));
+        assert!(grid.to_ansi().lines().nth(4).unwrap().contains(" TT  "));
+    }
+
+    #[test]
+    fn averages_partial_hands() {
+        let range: Range = "AhKh, AsKs".parse().unwrap();
+        let grid = Grid::from_range(&range);
+        assert_eq!(grid.value("AKs".parse().unwrap()), 0.5);
+    }
+
+    #[test]
+    fn colours_cells_by_value() {
+        assert_eq!(heat(0.0), (214, 48, 49));
+        assert_eq!(heat(0.5), (230, 200, 60));
+        assert_eq!(heat(1.0), (60, 170, 80));
+
+        let grid = Grid::from_fn(|hand| {
+            if hand.shape() == Shape::Pair {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        let ansi = grid.to_ansi();
+        assert_eq!(ansi.lines().count(), 13);
+        assert_eq!(ansi.matches("\x1b[30;48;2;60;170;80m").count(), 13);
+        assert_eq!(ansi.matches("\x1b[0m").count(), 13);
+    }
+}
@@ -1,40 +1,172 @@
+use core::array::TryFromSliceError;
+use core::convert::TryFrom;
+
 use crate::card::Card;
 use crate::hand_rank::HandRank;
+use crate::strength::Strength;
 
+/// A poker hand of `N` cards, five unless stated otherwise. Bigger hands,
+/// such as a Hold'em player's seven cards at showdown, are worth the best
+/// five cards they hold.
 #[derive(Eq, Debug, Copy, Clone)]
-pub(crate) struct Hand([Card; 5]);
+pub struct Hand<const N: usize = 5>([Card; N]);
+
+impl<const N: usize> Hand<N> {
+    /// Fails the build for hands too small to be worth anything
+    const VALID: () = assert!(N >= 5, "Need at least five cards to make a hand");
 
-impl Hand {
-    pub(crate) fn new(cards: [Card; 5]) -> Self {
+    pub const fn new(cards: [Card; N]) -> Self {
+        let () = Self::VALID;
         Hand(cards)
     }
 
     /// Implementation of the scheme described in
     /// http://suffe.cool/poker/evaluator.html
-    pub(crate) fn rank(&self) -> HandRank {
-        HandRank::compute(self)
+    pub const fn rank(&self) -> HandRank {
+        let () = Self::VALID;
+        HandRank::best_of(&self.0)
     }
 
-    pub(crate) fn cards(&self) -> &[Card; 5] {
+    pub const fn cards(&self) -> &[Card; N] {
         &self.0
     }
+
+    pub const fn strength(&self) -> Strength {
+        self.rank().strength()
+    }
+
+    /// The five cards that make up the hand's value. Of several equally good
+    /// choices, the first in the order the cards were given wins.
+    pub fn best_five(&self) -> Hand {
+        let (_, [a, b, c, d, e]) = HandRank::best_five_of(&self.0);
+        let cards = self.0;
+        Hand([cards[a], cards[b], cards[c], cards[d], cards[e]])
+    }
+
+    /// The hand with `more` cards added on the end, such as the turn and
+    /// river going onto a player's first five. Fails to build unless that
+    /// comes to `M` cards.
+    pub fn add_cards<const K: usize, const M: usize>(&self, more: &[Card; K]) -> Hand<M> {
+        let () = Sum::<N, K, M>::VALID;
+
+        let mut cards = [Card::from_index(0); M];
+        cards[..N].copy_from_slice(&self.0);
+        cards[N..].copy_from_slice(more);
+        Hand::new(cards)
+    }
+}
+
+/// Checks at compile time that `A` and `B` cards come to `C`
+struct Sum<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> Sum<A, B, C> {
+    const VALID: () = assert!(A + B == C, "Cards added do not make a hand of that size");
+}
+
+impl<const N: usize> TryFrom<&[Card]> for Hand<N> {
+    type Error = TryFromSliceError;
+
+    /// Takes the cards if there are exactly `N` of them
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        <[Card; N]>::try_from(cards).map(Hand::new)
+    }
+}
+
+impl<const N: usize> From<Hand<N>> for [Card; N] {
+    fn from(hand: Hand<N>) -> Self {
+        hand.0
+    }
+}
+
+impl<const N: usize> Ord for Hand<N> {
+    fn cmp(&self, other: &Hand<N>) -> core::cmp::Ordering {
+        self.strength().cmp(&other.strength())
+    }
 }
 
-impl Ord for Hand {
-    fn cmp(&self, other: &Hand) -> std::cmp::Ordering {
-        self.rank().numeric().cmp(&other.rank().numeric())
+impl<const N: usize> PartialOrd for Hand<N> {
+    fn partial_cmp(&self, other: &Hand<N>) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Hand) -> Option<std::cmp::Ordering> {
-        // NOTE(Nik): Reverse here since smaller NumericHandRanks are greater value
-        Some(self.cmp(other).reverse())
+impl<const N: usize> PartialEq for Hand<N> {
+    fn eq(&self, other: &Hand<N>) -> bool {
+        self.strength() == other.strength()
     }
 }
 
-impl PartialEq for Hand {
-    fn eq(&self, other: &Hand) -> bool {
-        self.rank().numeric() == other.rank().numeric()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, Rank, Suit};
+
+    #[test]
+    fn ord_and_partial_ord_agree() {
+        let mut hands = [
+            Hand::new([
+                Card::new(Rank::Two, Suit::Hearts),
+                Card::new(Rank::Two, Suit::Diamonds),
+                Card::new(Rank::Four, Suit::Hearts),
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Hearts),
+            ]),
+            Hand::new([
+                Card::new(Rank::Two, Suit::Hearts),
+                Card::new(Rank::Three, Suit::Hearts),
+                Card::new(Rank::Four, Suit::Hearts),
+                Card::new(Rank::Five, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Hearts),
+            ]),
+            Hand::new([
+                Card::new(Rank::King, Suit::Spades),
+                Card::new(Rank::Queen, Suit::Clubs),
+                Card::new(Rank::Jack, Suit::Hearts),
+                Card::new(Rank::Ten, Suit::Hearts),
+                Card::new(Rank::Nine, Suit::Hearts),
+            ]),
+        ];
+
+        hands.sort();
+
+        assert!(hands[0] < hands[1]);
+        assert!(hands[1] < hands[2]);
+        assert_eq!(hands[2].cmp(&hands[0]), core::cmp::Ordering::Greater);
+        assert_eq!(hands[0].rank(), HandRank::OnePair(6182));
+        assert_eq!(hands[2].rank(), HandRank::StraightFlush(9));
+    }
+
+    fn cards<const N: usize>(notation: &str) -> Hand<N> {
+        Hand::try_from(parse_cards(notation).unwrap().as_slice()).unwrap()
+    }
+
+    #[test]
+    fn ranks_bigger_hands_by_their_best_five() {
+        let holdem: Hand<7> = cards("AhKh2h7h9cAs4h");
+        let best: Hand = cards("AhKh2h7h4h");
+        assert_eq!(holdem.rank(), best.rank());
+        assert_eq!(holdem.best_five().cards(), best.cards());
+
+        let six: Hand<6> = cards("9c9d9h2s2d3c");
+        assert_eq!(six.best_five().cards(), cards::<5>("9c9d9h2s2d").cards());
+        assert!(holdem > cards("AdAcKsKdQh2c7d"));
+    }
+
+    #[test]
+    fn converts_between_sizes() {
+        let flop: Hand = cards("AhKhQh2c3d");
+        let jack = Card::new(Rank::Jack, Suit::Hearts);
+        let ten = Card::new(Rank::Ten, Suit::Hearts);
+        let river: Hand<7> = flop.add_cards(&[jack, ten]);
+        assert_eq!(river.rank(), HandRank::StraightFlush(1));
+        let turn: Hand<6> = flop.add_cards(&[ten]);
+        assert!(flop.rank() < turn.rank() && turn.rank() < river.rank());
+
+        let five: &[Card] = &parse_cards("AhKhQhJhTh").unwrap();
+        assert!(Hand::<5>::try_from(five).is_ok());
+        assert!(Hand::<7>::try_from(five).is_err());
+        let array: [Card; 7] = river.into();
+        assert_eq!(&array, river.cards());
     }
 }
@@ -0,0 +1,183 @@
+use crate::card::{Card, Rank, Suit};
+use crate::combinatorics::choose;
+use crate::hand::Hand;
+use crate::hand_rank::{Category, HandRank, NumericHandRank};
+
+/// One of the 7462 distinct values a 5 card hand can have, i.e. a set of
+/// hands that tie with each other.
+#[derive(Debug, Copy, Clone)]
+pub struct HandClass {
+    pub numeric: NumericHandRank,
+    pub category: Category,
+    /// A hand of this value, written with its most repeated and then its
+    /// highest ranks first, such as `KhKdKs2h2d`
+    pub representative: Hand,
+    /// Number of 5 card hands out of a full deck with this value
+    pub hands: u32,
+}
+
+/// Every hand class, from the royal flush down to seven high.
+///
+/// Classes are worked out from the 6175 multisets of ranks a hand can hold,
+/// each of which is one class, plus the 1287 sets of five distinct ranks
+/// again as flushes. Every class's representative is evaluated once, so
+/// nothing depends on enumerating all 2.6 million hands.
+pub fn hand_classes() -> HandClasses {
+    let mut classes = Vec::with_capacity(7462);
+    let mut ranks = Vec::with_capacity(5);
+    rank_multisets(12, &mut ranks, &mut |ranks| {
+        let distinct = ranks.windows(2).all(|pair| pair[0] != pair[1]);
+        if distinct {
+            classes.push(class(ranks, true));
+        }
+        classes.push(class(ranks, false));
+    });
+    classes.sort_by_key(|class| class.numeric);
+
+    HandClasses {
+        classes: classes.into_iter(),
+    }
+}
+
+/// Iterator returned by `hand_classes`.
+pub struct HandClasses {
+    classes: std::vec::IntoIter<HandClass>,
+}
+
+impl Iterator for HandClasses {
+    type Item = HandClass;
+
+    fn next(&mut self) -> Option<HandClass> {
+        self.classes.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.classes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for HandClasses {}
+
+/// Calls `visit` with every multiset of 5 ranks holding no more than four of
+/// any rank, each listed from the highest rank down.
+fn rank_multisets<F>(highest: u32, ranks: &mut Vec<Rank>, visit: &mut F)
+where
+    F: FnMut(&[Rank]),
+{
+    if ranks.len() == 5 {
+        visit(ranks);
+        return;
+    }
+
+    for order in (0..=highest).rev() {
+        let rank = Rank::from_order(order);
+        if ranks.iter().filter(|r| **r == rank).count() < 4 {
+            ranks.push(rank);
+            rank_multisets(order, ranks, visit);
+            ranks.pop();
+        }
+    }
+}
+
+fn class(ranks: &[Rank], flush: bool) -> HandClass {
+    let copies = |rank: Rank| ranks.iter().filter(|r| **r == rank).count();
+    let distinct = ranks.windows(2).all(|pair| pair[0] != pair[1]);
+
+    // Most repeated ranks first, ties broken by rank as `ranks` is sorted
+    let mut ordered = ranks.to_vec();
+    ordered.sort_by_key(|rank| core::cmp::Reverse(copies(*rank)));
+
+    // The nth card of a rank gets the nth suit. That only leaves five
+    // distinct ranks all in one suit, so break those up unless they are
+    // meant to be a flush.
+    let mut cards = [Card::new(Rank::Two, Suit::Hearts); 5];
+    for (i, (card, rank)) in cards.iter_mut().zip(&ordered).enumerate() {
+        let nth = ordered[..i].iter().filter(|r| *r == rank).count();
+        *card = Card::new(*rank, Suit::ALL[nth]);
+    }
+    if distinct && !flush {
+        cards[4] = Card::new(ordered[4], Suit::Diamonds);
+    }
+
+    let hand = Hand::new(cards);
+    let rank = HandRank::compute(&hand);
+    let hands = ranks
+        .iter()
+        .enumerate()
+        .filter(|(i, rank)| !ranks[..*i].contains(rank))
+        .map(|(_, rank)| choose(4, copies(*rank) as u64) as u32)
+        .product::<u32>();
+
+    HandClass {
+        numeric: rank.numeric(),
+        category: rank.category(),
+        representative: hand,
+        hands: match (distinct, flush) {
+            (true, true) => 4,
+            (true, false) => hands - 4,
+            _ => hands,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::percentile_tables::FIVE_CARD;
+
+    #[test]
+    fn covers_every_value_once() {
+        let classes: Vec<HandClass> = hand_classes().collect();
+        assert_eq!(classes.len(), 7462);
+        for (i, class) in classes.iter().enumerate() {
+            assert_eq!(class.numeric, i as NumericHandRank + 1);
+            assert_eq!(class.representative.rank().numeric(), class.numeric);
+            assert_eq!(class.category, class.representative.rank().category());
+        }
+        let hands: u32 = classes.iter().map(|class| class.hands).sum();
+        assert_eq!(hands, 2_598_960);
+    }
+
+    #[test]
+    fn counts_classes_per_category() {
+        let mut per_category = [0; 9];
+        for class in hand_classes() {
+            per_category[class.category as usize] += 1;
+        }
+        assert_eq!(per_category, [1277, 2860, 858, 858, 10, 1277, 156, 156, 10]);
+    }
+
+    #[test]
+    fn counts_agree_with_the_percentile_table() {
+        let mut beaten_by_better = 2_598_960;
+        for class in hand_classes() {
+            let beaten = FIVE_CARD[class.numeric as usize - 1];
+            assert_eq!(beaten_by_better - beaten, class.hands);
+            beaten_by_better = beaten;
+        }
+    }
+
+    #[test]
+    fn picks_readable_representatives() {
+        let mut classes = hand_classes();
+        let royal = classes.next().unwrap();
+        assert_eq!(royal.category, Category::StraightFlush);
+        assert_eq!(royal.hands, 4);
+        assert_eq!(format_hand(&royal.representative), "AhKhQhJhTh");
+
+        let full_house = hand_classes()
+            .find(|class| class.category == Category::FullHouse)
+            .unwrap();
+        assert_eq!(format_hand(&full_house.representative), "AhAdAsKhKd");
+        assert_eq!(full_house.hands, 24);
+
+        let seven_high = hand_classes().last().unwrap();
+        assert_eq!(format_hand(&seven_high.representative), "7h5h4h3h2d");
+        assert_eq!(seven_high.hands, 1020);
+    }
+
+    fn format_hand(hand: &Hand) -> String {
+        hand.cards().iter().map(|card| card.to_string()).collect()
+    }
+}
@@ -1,11 +1,20 @@
-use crate::lookup_tables;
+use crate::card::{Card, Rank, Suit};
+#[cfg(feature = "compact-tables")]
+use crate::compact_tables;
 use crate::hand::Hand;
+#[cfg(not(feature = "compact-tables"))]
+use crate::lookup_tables;
+use crate::strength::Strength;
 
-pub(crate) type NumericHandRank = u32;
+pub type NumericHandRank = u32;
 
-#[derive(PartialEq, Debug, Copy, Clone)]
-#[allow(dead_code)]
-pub(crate) enum HandRank {
+/// Value of a five card hand, tagged with its category.
+///
+/// Equality and ordering both go through `strength`, so two ranks holding the
+/// same value are equal whatever variant they are in. Comparing a rank whose
+/// value lies outside 1 to 7462 panics, as no evaluation produces one.
+#[derive(Debug, Copy, Clone)]
+pub enum HandRank {
     HighCard(NumericHandRank),
     OnePair(NumericHandRank),
     TwoPair(NumericHandRank),
@@ -18,7 +27,7 @@ pub(crate) enum HandRank {
 }
 
 impl HandRank {
-    pub(crate) fn compute(hand: &Hand) -> Self {
+    pub const fn compute(hand: &Hand) -> Self {
         let card0 = hand.cards()[0].as_int();
         let card1 = hand.cards()[1].as_int();
         let card2 = hand.cards()[2].as_int();
@@ -27,13 +36,13 @@ impl HandRank {
 
         let lookup_index = (card0 | card1 | card2 | card3 | card4) >> 16;
 
-        if Self::all_same_suit(&hand) {
-            (lookup_tables::FLUSHES[lookup_index as usize] as NumericHandRank).into()
+        if Self::all_same_suit(hand) {
+            Self::from_numeric(Self::flush_value(lookup_index))
         } else {
-            let rank = lookup_tables::UNIQUES[lookup_index as usize] as NumericHandRank;
+            let rank = Self::unique_value(lookup_index);
 
             if rank != 0 {
-                rank.into()
+                Self::from_numeric(rank)
             } else {
                 let q = (hand.cards()[0].as_int() & 0xFF)
                     * (hand.cards()[1].as_int() & 0xFF)
@@ -41,12 +50,146 @@ impl HandRank {
                     * (hand.cards()[3].as_int() & 0xFF)
                     * (hand.cards()[4].as_int() & 0xFF);
 
-                (lookup_tables::VALUES[Self::find_value_index(q)] as NumericHandRank).into()
+                Self::from_numeric(Self::paired_value(q))
+            }
+        }
+    }
+
+    /// Best five card hand that can be made out of `cards`, such as a
+    /// Hold'em player's hole cards together with the board.
+    pub const fn best_of(cards: &[Card]) -> Self {
+        Self::best_five_of(cards).0
+    }
+
+    /// Best five card hand that can be made out of `cards` together with the
+    /// positions of the five cards that make it. Of several equally good
+    /// choices, the first in the order the cards were given wins.
+    pub(crate) const fn best_five_of(cards: &[Card]) -> (Self, [usize; 5]) {
+        assert!(cards.len() >= 5, "Need at least five cards to make a hand");
+
+        let n = cards.len();
+        let mut best = NumericHandRank::MAX;
+        let mut best_five = [0, 1, 2, 3, 4];
+
+        // `while` rather than `for` so that this stays a `const fn`
+        let mut a = 0;
+        while a < n {
+            let mut b = a + 1;
+            while b < n {
+                let mut c = b + 1;
+                while c < n {
+                    let mut d = c + 1;
+                    while d < n {
+                        let mut e = d + 1;
+                        while e < n {
+                            let hand =
+                                Hand::new([cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                            let value = Self::compute(&hand).numeric();
+                            if value < best {
+                                best = value;
+                                best_five = [a, b, c, d, e];
+                            }
+                            e += 1;
+                        }
+                        d += 1;
+                    }
+                    c += 1;
+                }
+                b += 1;
+            }
+            a += 1;
+        }
+
+        (Self::from_numeric(best), best_five)
+    }
+
+    /// Best hand that can be made out of a multiset of ranks, for when a
+    /// flush is impossible (see `flush_possible`). `counts` holds how many
+    /// cards there are of each rank, indexed like `Rank::ALL` from the ace
+    /// down.
+    ///
+    /// Returns `None` for fewer than five cards or more than four of a rank.
+    /// The best five ranks are picked straight from the counts, so this takes
+    /// a single lookup in the unique or paired value table whatever the
+    /// number of cards, where `best_of` evaluates every five card subset.
+    pub fn from_ranks(counts: &[u8; 13]) -> Option<Self> {
+        let mut total = 0;
+        let mut present = 0_u32;
+        for (i, count) in counts.iter().enumerate() {
+            if *count > 4 {
+                return None;
+            }
+            total += *count as usize;
+            if *count > 0 {
+                present |= 1 << Rank::ALL[i].order_encoding();
             }
         }
+        if total < 5 {
+            return None;
+        }
+
+        // Highest rank, other than those in `skip`, held at least `n` times
+        let highest = |n: u8, skip: &[Rank]| {
+            Rank::ALL
+                .iter()
+                .zip(counts.iter())
+                .find(|(rank, count)| **count >= n && !skip.contains(rank))
+                .map(|(rank, _)| *rank)
+        };
+        // Fills `picked` with the highest ranks held, other than those in `skip`
+        let kickers = |skip: &[Rank], picked: &mut [Rank]| {
+            let mut ranks = Rank::ALL
+                .iter()
+                .zip(counts.iter())
+                .filter(|(rank, count)| **count > 0 && !skip.contains(rank))
+                .map(|(rank, _)| *rank);
+            for slot in picked.iter_mut() {
+                *slot = ranks.next().unwrap();
+            }
+        };
+
+        let value = if let Some(quads) = highest(4, &[]) {
+            let mut kicker = [quads];
+            kickers(&[quads], &mut kicker);
+            Self::paired_value(prime_product(&[quads, quads, quads, quads, kicker[0]]))
+        } else if let Some((trips, pair)) =
+            highest(3, &[]).and_then(|trips| highest(2, &[trips]).map(|pair| (trips, pair)))
+        {
+            Self::paired_value(prime_product(&[trips, trips, trips, pair, pair]))
+        } else if let Some(straight) = best_straight(present) {
+            Self::unique_value(straight)
+        } else if let Some(trips) = highest(3, &[]) {
+            let mut picked = [trips; 2];
+            kickers(&[trips], &mut picked);
+            Self::paired_value(prime_product(&[trips, trips, trips, picked[0], picked[1]]))
+        } else if let Some(high) = highest(2, &[]) {
+            match highest(2, &[high]) {
+                Some(low) => {
+                    let mut kicker = [high];
+                    kickers(&[high, low], &mut kicker);
+                    Self::paired_value(prime_product(&[high, high, low, low, kicker[0]]))
+                }
+                None => {
+                    let mut picked = [high; 3];
+                    kickers(&[high], &mut picked);
+                    Self::paired_value(prime_product(&[
+                        high, high, picked[0], picked[1], picked[2],
+                    ]))
+                }
+            }
+        } else {
+            // Five distinct ranks or more: the top five, the rest are no use
+            let mut top = present;
+            while top.count_ones() > 5 {
+                top &= top - 1;
+            }
+            Self::unique_value(top)
+        };
+
+        Some(Self::from_numeric(value))
     }
 
-    fn all_same_suit(hand: &Hand) -> bool {
+    const fn all_same_suit(hand: &Hand) -> bool {
         let card0 = hand.cards()[0].as_int();
         let card1 = hand.cards()[1].as_int();
         let card2 = hand.cards()[2].as_int();
@@ -56,7 +199,42 @@ impl HandRank {
         card0 & card1 & card2 & card3 & card4 & 0xf000 != 0
     }
 
-    fn find_value_index(q: u32) -> usize {
+    /// Value of five ranks in one suit, given their rank bits
+    #[cfg(not(feature = "compact-tables"))]
+    const fn flush_value(bits: u32) -> NumericHandRank {
+        lookup_tables::FLUSHES[bits as usize] as NumericHandRank
+    }
+
+    /// Value of five distinct ranks that are not all one suit, given their
+    /// rank bits, or 0 if there are fewer than five ranks
+    #[cfg(not(feature = "compact-tables"))]
+    const fn unique_value(bits: u32) -> NumericHandRank {
+        lookup_tables::UNIQUES[bits as usize] as NumericHandRank
+    }
+
+    /// Value of a paired hand, given the product of its rank primes
+    #[cfg(not(feature = "compact-tables"))]
+    const fn paired_value(q: u32) -> NumericHandRank {
+        lookup_tables::VALUES[Self::find_value_index(q)] as NumericHandRank
+    }
+
+    #[cfg(feature = "compact-tables")]
+    const fn flush_value(bits: u32) -> NumericHandRank {
+        compact_tables::flushes(bits) as NumericHandRank
+    }
+
+    #[cfg(feature = "compact-tables")]
+    const fn unique_value(bits: u32) -> NumericHandRank {
+        compact_tables::uniques(bits) as NumericHandRank
+    }
+
+    #[cfg(feature = "compact-tables")]
+    const fn paired_value(q: u32) -> NumericHandRank {
+        compact_tables::values(q) as NumericHandRank
+    }
+
+    #[cfg(not(feature = "compact-tables"))]
+    const fn find_value_index(q: u32) -> usize {
         let mut low: usize = 0;
         let mut high: usize = 4888;
         let mut mid;
@@ -68,13 +246,13 @@ impl HandRank {
             } else if q > lookup_tables::PRODUCTS[mid] {
                 low = mid + 1;
             } else {
-                return mid as usize;
+                return mid;
             }
         }
-        panic!("No match found for key {}", q);
+        panic!("No match found for prime product key");
     }
 
-    pub(crate) fn numeric(&self) -> NumericHandRank {
+    pub const fn numeric(&self) -> NumericHandRank {
         match *self {
             HandRank::HighCard(i) => i,
             HandRank::OnePair(i) => i,
@@ -87,10 +265,28 @@ impl HandRank {
             HandRank::StraightFlush(i) => i,
         }
     }
-}
 
-impl From<NumericHandRank> for HandRank {
-    fn from(i: NumericHandRank) -> Self {
+    pub const fn category(&self) -> Category {
+        match *self {
+            HandRank::HighCard(_) => Category::HighCard,
+            HandRank::OnePair(_) => Category::OnePair,
+            HandRank::TwoPair(_) => Category::TwoPair,
+            HandRank::ThreeOfAKind(_) => Category::ThreeOfAKind,
+            HandRank::Straight(_) => Category::Straight,
+            HandRank::Flush(_) => Category::Flush,
+            HandRank::FullHouse(_) => Category::FullHouse,
+            HandRank::FourOfAKind(_) => Category::FourOfAKind,
+            HandRank::StraightFlush(_) => Category::StraightFlush,
+        }
+    }
+
+    pub const fn strength(&self) -> Strength {
+        Strength::from_numeric(self.numeric())
+    }
+
+    /// Const counterpart of `From<NumericHandRank>`, buckets a raw lookup
+    /// value into its hand category.
+    pub const fn from_numeric(i: NumericHandRank) -> Self {
         if i > 6185 {
             HandRank::HighCard(i)
         } else if i > 3325 {
@@ -113,11 +309,111 @@ impl From<NumericHandRank> for HandRank {
     }
 }
 
+/// Product of the ranks' primes, the key into the paired value table
+fn prime_product(ranks: &[Rank; 5]) -> u32 {
+    ranks.iter().map(|rank| rank.prime_encoding()).product()
+}
+
+/// Rank bits of the highest straight among the `present` ranks, if any
+fn best_straight(present: u32) -> Option<u32> {
+    const FIVE: u32 = 0x1F;
+    const WHEEL: u32 = 0x100F;
+
+    (0..=8)
+        .rev()
+        .map(|low| FIVE << low)
+        .chain(core::iter::once(WHEEL))
+        .find(|straight| present & straight == *straight)
+}
+
+/// Whether `cards` plus `to_come` more cards could hold a flush. When they
+/// cannot, suits make no difference to the hand and `HandRank::from_ranks`
+/// gives the same value as `HandRank::best_of`.
+pub fn flush_possible(cards: &[Card], to_come: usize) -> bool {
+    Suit::ALL.iter().any(|suit| {
+        let suited = cards.iter().filter(|card| card.suit() == *suit).count();
+        suited + to_come >= 5
+    })
+}
+
+/// The kind of hand a `HandRank` is, without the detail of which cards make
+/// it. Like `Strength`, greater categories are better hands.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
+pub enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl Category {
+    /// Every category, from the weakest to the strongest
+    pub const ALL: [Category; 9] = [
+        Category::HighCard,
+        Category::OnePair,
+        Category::TwoPair,
+        Category::ThreeOfAKind,
+        Category::Straight,
+        Category::Flush,
+        Category::FullHouse,
+        Category::FourOfAKind,
+        Category::StraightFlush,
+    ];
+}
+
+impl core::fmt::Display for Category {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let name = match *self {
+            Category::HighCard => "High card",
+            Category::OnePair => "One pair",
+            Category::TwoPair => "Two pair",
+            Category::ThreeOfAKind => "Three of a kind",
+            Category::Straight => "Straight",
+            Category::Flush => "Flush",
+            Category::FullHouse => "Full house",
+            Category::FourOfAKind => "Four of a kind",
+            Category::StraightFlush => "Straight flush",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Ord for HandRank {
+    fn cmp(&self, other: &HandRank) -> core::cmp::Ordering {
+        self.strength().cmp(&other.strength())
+    }
+}
+
+impl PartialOrd for HandRank {
+    fn partial_cmp(&self, other: &HandRank) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for HandRank {
+    fn eq(&self, other: &HandRank) -> bool {
+        self.strength() == other.strength()
+    }
+}
+
+impl Eq for HandRank {}
+
+impl From<NumericHandRank> for HandRank {
+    fn from(i: NumericHandRank) -> Self {
+        Self::from_numeric(i)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    
-    use crate::card::{Card, Suit, Rank};
+
+    use crate::card::parse_cards;
 
     #[test]
     fn calculates_value_of_a_hand() {
@@ -203,4 +499,143 @@ mod tests {
 
         assert!(hand1 == hand2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn equality_agrees_with_ordering() {
+        let tagged = HandRank::HighCard(1);
+        let royal = HandRank::StraightFlush(1);
+        assert_eq!(tagged.cmp(&royal), core::cmp::Ordering::Equal);
+        assert_eq!(tagged, royal);
+        assert_ne!(HandRank::OnePair(4000), HandRank::OnePair(4001));
+    }
+
+    #[test]
+    #[should_panic(expected = "Hand values run from 1 to 7462")]
+    fn refuses_to_compare_impossible_values() {
+        let _ = HandRank::OnePair(0) == HandRank::OnePair(0);
+    }
+
+    #[test]
+    fn picks_the_best_five_of_seven_cards() {
+        let cards = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Four, Suit::Hearts),
+        ];
+
+        assert_eq!(
+            HandRank::best_of(&cards),
+            Hand::new([cards[0], cards[1], cards[2], cards[3], cards[6]]).rank()
+        );
+        assert_eq!(
+            HandRank::best_of(&cards[..5]),
+            Hand::new([cards[0], cards[1], cards[2], cards[3], cards[4]]).rank()
+        );
+        assert!(matches!(
+            HandRank::best_of(&cards[1..]),
+            HandRank::HighCard(_)
+        ));
+    }
+
+    /// Calls `visit` with every way of holding `remaining` more cards, at most
+    /// four of a rank, from rank `from` on
+    fn rank_counts(
+        counts: &mut [u8; 13],
+        from: usize,
+        remaining: u8,
+        visit: &mut dyn FnMut(&[u8; 13]),
+    ) {
+        if remaining == 0 {
+            visit(counts);
+            return;
+        }
+        for i in from..13 {
+            if counts[i] < 4 {
+                counts[i] += 1;
+                rank_counts(counts, i, remaining - 1, visit);
+                counts[i] -= 1;
+            }
+        }
+    }
+
+    #[test]
+    fn ranks_alone_agree_with_cards_without_a_flush() {
+        for size in 5..=7 {
+            rank_counts(&mut [0; 13], 0, size, &mut |counts| {
+                // Dealing the suits round robin never puts five in one suit
+                let mut cards = Vec::new();
+                for (rank, count) in Rank::ALL.iter().zip(counts.iter()) {
+                    for _ in 0..*count {
+                        cards.push(Card::new(*rank, Suit::ALL[cards.len() % 4]));
+                    }
+                }
+                assert!(!flush_possible(&cards, 0));
+                assert_eq!(
+                    HandRank::from_ranks(counts),
+                    Some(HandRank::best_of(&cards))
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn rejects_impossible_rank_counts() {
+        let mut counts = [0; 13];
+        counts[0] = 5;
+        assert_eq!(HandRank::from_ranks(&counts), None);
+        counts[0] = 4;
+        assert_eq!(HandRank::from_ranks(&counts), None);
+        counts[12] = 1;
+        assert_eq!(
+            HandRank::from_ranks(&counts),
+            Some(HandRank::FourOfAKind(22))
+        );
+    }
+
+    #[test]
+    fn tells_when_a_flush_is_still_possible() {
+        let rainbow = parse_cards("Ah7d2c").unwrap();
+        assert!(!flush_possible(&rainbow, 1));
+        assert!(flush_possible(&rainbow, 4));
+
+        let two_tone = parse_cards("Ah7h2cKd9h").unwrap();
+        assert!(!flush_possible(&two_tone, 1));
+        assert!(flush_possible(&two_tone, 2));
+        assert!(!flush_possible(&two_tone, 0));
+    }
+
+    const ROYAL_FLUSH: HandRank = HandRank::compute(&Hand::new([
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::King, Suit::Spades),
+        Card::new(Rank::Queen, Suit::Spades),
+        Card::new(Rank::Jack, Suit::Spades),
+        Card::new(Rank::Ten, Suit::Spades),
+    ]));
+
+    const WHEEL: HandRank = HandRank::compute(&Hand::new([
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::Two, Suit::Clubs),
+        Card::new(Rank::Three, Suit::Hearts),
+        Card::new(Rank::Four, Suit::Hearts),
+        Card::new(Rank::Five, Suit::Diamonds),
+    ]));
+
+    const FULL_HOUSE: HandRank = HandRank::compute(&Hand::new([
+        Card::new(Rank::Seven, Suit::Spades),
+        Card::new(Rank::Seven, Suit::Clubs),
+        Card::new(Rank::Seven, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Diamonds),
+    ]));
+
+    #[test]
+    fn evaluates_hands_at_compile_time() {
+        assert_eq!(ROYAL_FLUSH, HandRank::StraightFlush(1));
+        assert_eq!(WHEEL, HandRank::Straight(1609));
+        assert_eq!(FULL_HOUSE, HandRank::FullHouse(256));
+    }
+}
@@ -0,0 +1,265 @@
+use core::cmp::Ordering;
+
+use crate::board::{self, BoardError};
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::hand_rank::HandRank;
+
+const AHEAD: usize = 0;
+const TIED: usize = 1;
+const BEHIND: usize = 2;
+
+/// Chance of improving past an opponent we are behind (`positive`), or of
+/// falling behind one we are ahead of (`negative`), once more cards come.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Potential {
+    pub positive: f64,
+    pub negative: f64,
+}
+
+/// The classic hand-strength features for a holding on a board.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HandMetrics {
+    /// Immediate hand strength against every opponent at once
+    pub strength: f64,
+    pub potential: Potential,
+}
+
+impl HandMetrics {
+    /// EHS, the chance of being ahead now or getting there: only the
+    /// positive potential counts, as though we could always fold once behind.
+    pub fn effective_strength(&self) -> f64 {
+        self.strength + (1.0 - self.strength) * self.potential.positive
+    }
+
+    /// The more cautious form of EHS that also takes away the negative
+    /// potential of hands that are ahead now.
+    pub fn effective_strength_with_negative(&self) -> f64 {
+        self.strength * (1.0 - self.potential.negative)
+            + (1.0 - self.strength) * self.potential.positive
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HandStrengthError {
+    /// Hand strength is measured on the flop, turn or river
+    Board(BoardError),
+    /// There has to be somebody to be ahead of
+    NoOpponents,
+    /// More cards were asked for than are left to come
+    Lookahead(usize),
+}
+
+impl core::fmt::Display for HandStrengthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            HandStrengthError::Board(error) => error.fmt(f),
+            HandStrengthError::NoOpponents => write!(f, "need at least one opponent"),
+            HandStrengthError::Lookahead(cards) => {
+                write!(f, "cannot look {} cards ahead on this board", cards)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HandStrengthError {}
+
+impl From<BoardError> for HandStrengthError {
+    fn from(error: BoardError) -> Self {
+        HandStrengthError::Board(error)
+    }
+}
+
+/// Immediate hand strength: the share of opponent holdings we beat right now,
+/// counting ties as half, raised to the number of `opponents`.
+pub fn hand_strength(
+    hole: [Card; 2],
+    board: &[Card],
+    opponents: usize,
+) -> Result<f64, HandStrengthError> {
+    if opponents == 0 {
+        return Err(HandStrengthError::NoOpponents);
+    }
+    let deck = validate(hole, board)?;
+    let ours = rank_with(hole, board);
+
+    let mut counts = [0_u64; 3];
+    for opponent in holdings(deck) {
+        counts[compare(ours, rank_with(opponent, board))] += 1;
+    }
+
+    let total = counts.iter().sum::<u64>() as f64;
+    let single = (counts[AHEAD] as f64 + counts[TIED] as f64 / 2.0) / total;
+    Ok(single.powi(opponents as i32))
+}
+
+/// Positive and negative potential over the next `lookahead` cards, against a
+/// single opponent holding any two cards.
+///
+/// Every opponent holding and every run of `lookahead` cards is enumerated, so
+/// looking two cards ahead from the flop is a lot more work than one.
+pub fn hand_potential(
+    hole: [Card; 2],
+    board: &[Card],
+    lookahead: usize,
+) -> Result<Potential, HandStrengthError> {
+    let deck = validate(hole, board)?;
+    if lookahead > 5 - board.len() {
+        return Err(HandStrengthError::Lookahead(lookahead));
+    }
+    if lookahead == 0 {
+        return Ok(Potential::default());
+    }
+
+    let ours = rank_with(hole, board);
+    let mut future = board.to_vec();
+    future.resize(board.len() + lookahead, board[0]);
+
+    // Indexed by where we stand now, then where we stand after the new cards
+    let mut transitions = [[0_u64; 3]; 3];
+    for opponent in holdings(deck) {
+        let now = compare(ours, rank_with(opponent, board));
+
+        let mut remaining = deck;
+        for card in opponent.iter() {
+            remaining.remove(*card);
+        }
+        for runout in remaining.combinations(lookahead) {
+            for (slot, card) in future[board.len()..].iter_mut().zip(runout) {
+                *slot = card;
+            }
+            let later = compare(rank_with(hole, &future), rank_with(opponent, &future));
+            transitions[now][later] += 1;
+        }
+    }
+
+    let from = |now: usize| transitions[now].iter().sum::<u64>() as f64;
+    let ratio = |numerator: f64, denominator: f64| {
+        if denominator > 0.0 {
+            numerator / denominator
+        } else {
+            0.0
+        }
+    };
+    let t = |now: usize, later: usize| transitions[now][later] as f64;
+
+    Ok(Potential {
+        positive: ratio(
+            t(BEHIND, AHEAD) + t(BEHIND, TIED) / 2.0 + t(TIED, AHEAD) / 2.0,
+            from(BEHIND) + from(TIED) / 2.0,
+        ),
+        negative: ratio(
+            t(AHEAD, BEHIND) + t(TIED, BEHIND) / 2.0 + t(AHEAD, TIED) / 2.0,
+            from(AHEAD) + from(TIED) / 2.0,
+        ),
+    })
+}
+
+/// Hand strength against `opponents` together with the potential over the
+/// next `lookahead` cards.
+pub fn hand_metrics(
+    hole: [Card; 2],
+    board: &[Card],
+    opponents: usize,
+    lookahead: usize,
+) -> Result<HandMetrics, HandStrengthError> {
+    Ok(HandMetrics {
+        strength: hand_strength(hole, board, opponents)?,
+        potential: hand_potential(hole, board, lookahead)?,
+    })
+}
+
+fn validate(hole: [Card; 2], board: &[Card]) -> Result<Deck, HandStrengthError> {
+    let known = board::validate(board, 3..=5, &hole)?;
+    Ok(Deck::without(known))
+}
+
+fn holdings(deck: Deck) -> impl Iterator<Item = [Card; 2]> {
+    deck.combinations(2).map(|set| {
+        let mut cards = set.iter();
+        [cards.next().unwrap(), cards.next().unwrap()]
+    })
+}
+
+fn rank_with(hole: [Card; 2], board: &[Card]) -> HandRank {
+    let mut cards = hole.to_vec();
+    cards.extend_from_slice(board);
+    HandRank::best_of(&cards)
+}
+
+fn compare(ours: HandRank, theirs: HandRank) -> usize {
+    match ours.cmp(&theirs) {
+        Ordering::Greater => AHEAD,
+        Ordering::Equal => TIED,
+        Ordering::Less => BEHIND,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, parse_hole};
+
+    #[test]
+    fn matches_the_published_hand_strength() {
+        // Billings et al. use AdQc on 3h4cJh as their worked example, where
+        // we are ahead of 628, tied with 9 and behind 444 holdings
+        let board = parse_cards("3h4cJh").unwrap();
+        let single = hand_strength(parse_hole("AdQc"), &board, 1).unwrap();
+        assert!((single - (628.0 + 4.5) / 1081.0).abs() < 1e-12);
+
+        let five = hand_strength(parse_hole("AdQc"), &board, 5).unwrap();
+        assert!((five - single.powi(5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn the_nuts_on_the_river_has_full_strength_and_no_potential() {
+        let board = parse_cards("Ah Kh Qh 2c 7d").unwrap();
+        let metrics = hand_metrics(parse_hole("JhTh"), &board, 3, 0).unwrap();
+
+        assert_eq!(metrics.strength, 1.0);
+        assert_eq!(metrics.potential, Potential::default());
+        assert_eq!(metrics.effective_strength(), 1.0);
+        assert_eq!(metrics.effective_strength_with_negative(), 1.0);
+    }
+
+    #[test]
+    fn a_draw_has_more_positive_than_negative_potential() {
+        let board = parse_cards("Kh7h3c2d").unwrap();
+        let metrics = hand_metrics(parse_hole("Ah5h"), &board, 1, 1).unwrap();
+
+        let potential = metrics.potential;
+        assert!(potential.positive > potential.negative);
+        assert!(potential.positive > 0.0 && potential.positive < 1.0);
+        assert!(metrics.effective_strength() > metrics.strength);
+        assert!(metrics.effective_strength() >= metrics.effective_strength_with_negative());
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let flop = parse_cards("3h4cJh").unwrap();
+        let river = parse_cards("3h4cJh5s6s").unwrap();
+
+        assert_eq!(
+            hand_strength(parse_hole("AdQc"), &flop, 0),
+            Err(HandStrengthError::NoOpponents)
+        );
+        assert_eq!(
+            hand_strength(parse_hole("AdQc"), &flop[..2], 1),
+            Err(HandStrengthError::Board(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 5
+            }))
+        );
+        assert_eq!(
+            hand_strength(parse_hole("Ah3h"), &flop, 1),
+            Err(HandStrengthError::Board(BoardError::DuplicateCard(flop[0])))
+        );
+        assert_eq!(
+            hand_potential(parse_hole("AdQc"), &river, 1),
+            Err(HandStrengthError::Lookahead(1))
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::rng::Rng;
+
+/// Most players `icm` will solve exactly. The work doubles with every extra
+/// player, and past this point `icm_monte_carlo` is the better tool.
+pub const MAX_EXACT_PLAYERS: usize = 16;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IcmError {
+    NoPlayers,
+    /// The player at this index has no chips; busted players take no part
+    EmptyStack(usize),
+    /// Too many players to enumerate, see `MAX_EXACT_PLAYERS`
+    TooManyPlayers(usize),
+    /// A Monte Carlo estimate was asked to sample no finishing orders
+    NoTrials,
+}
+
+impl core::fmt::Display for IcmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            IcmError::NoPlayers => write!(f, "need at least one player"),
+            IcmError::EmptyStack(player) => write!(f, "player {} has no chips", player),
+            IcmError::TooManyPlayers(players) => write!(
+                f,
+                "{} players is too many for exact ICM, at most {} are supported",
+                players, MAX_EXACT_PLAYERS
+            ),
+            IcmError::NoTrials => write!(f, "need at least one trial"),
+        }
+    }
+}
+
+impl std::error::Error for IcmError {}
+
+/// Prize equity of every player under the Independent Chip Model.
+///
+/// Uses the Malmuth-Harville assumption: a player finishes first with
+/// probability proportional to their stack, and the remaining places are
+/// decided the same way among everyone left. `payouts[k]` is the prize for
+/// finishing in place `k + 1`; places past the end of it pay nothing.
+///
+/// Rather than walk every finishing order, this visits every set of players
+/// that could hold the paid places above the one being awarded, which is
+/// `2^players` work at most and far less when only a few places pay.
+pub fn icm(stacks: &[u64], payouts: &[f64]) -> Result<Vec<f64>, IcmError> {
+    validate(stacks)?;
+    if stacks.len() > MAX_EXACT_PLAYERS {
+        return Err(IcmError::TooManyPlayers(stacks.len()));
+    }
+
+    let players = stacks.len();
+    let places = payouts.len().min(players);
+    let total: u64 = stacks.iter().sum();
+
+    // Chance that the players in each set took the first places, in any order
+    let mut finished = vec![0.0; 1 << players];
+    finished[0] = 1.0;
+    let mut equities = vec![0.0; players];
+
+    for set in 0..finished.len() {
+        let taken = (set as u32).count_ones() as usize;
+        if taken >= places || finished[set] == 0.0 {
+            continue;
+        }
+
+        let placed: u64 = (0..players)
+            .filter(|player| set & 1 << player != 0)
+            .map(|player| stacks[player])
+            .sum();
+        let remaining = (total - placed) as f64;
+
+        for player in (0..players).filter(|player| set & 1 << player == 0) {
+            let next = finished[set] * stacks[player] as f64 / remaining;
+            equities[player] += next * payouts[taken];
+            finished[set | 1 << player] += next;
+        }
+    }
+
+    Ok(equities)
+}
+
+/// Estimates ICM equities by sampling `trials` finishing orders, for fields
+/// too large to solve exactly. Seeding `rng` the same way reproduces the
+/// estimate.
+pub fn icm_monte_carlo<R: Rng>(
+    stacks: &[u64],
+    payouts: &[f64],
+    trials: u64,
+    rng: &mut R,
+) -> Result<Vec<f64>, IcmError> {
+    validate(stacks)?;
+    if trials == 0 {
+        return Err(IcmError::NoTrials);
+    }
+
+    let places = payouts.len().min(stacks.len());
+    let mut equities = vec![0.0; stacks.len()];
+    let mut order: Vec<(f64, usize)> = Vec::with_capacity(stacks.len());
+
+    for _ in 0..trials {
+        // Sorting by exponential waiting times with the stacks as rates picks
+        // each place with probability proportional to the stacks left, which
+        // is exactly the Harville finishing order
+        order.clear();
+        for (player, stack) in stacks.iter().enumerate() {
+            let wait = -(1.0 - rng.next_f64()).ln() / *stack as f64;
+            order.push((wait, player));
+        }
+        order.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        for (payout, (_, player)) in payouts[..places].iter().zip(&order) {
+            equities[*player] += payout;
+        }
+    }
+
+    for equity in equities.iter_mut() {
+        *equity /= trials as f64;
+    }
+    Ok(equities)
+}
+
+fn validate(stacks: &[u64]) -> Result<(), IcmError> {
+    if stacks.is_empty() {
+        return Err(IcmError::NoPlayers);
+    }
+    match stacks.iter().position(|stack| *stack == 0) {
+        Some(player) => Err(IcmError::EmptyStack(player)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::rng::SplitMix64;
+
+    #[test]
+    fn matches_a_hand_worked_example() {
+        let equities = icm(&[50, 30, 20], &[50.0, 30.0, 20.0]).unwrap();
+
+        // The big stack wins half the time, comes second after either of the
+        // others wins and third otherwise
+        let first = 0.5;
+        let second = 0.3 * 50.0 / 70.0 + 0.2 * 50.0 / 80.0;
+        let third = 1.0 - first - second;
+        let expected = first * 50.0 + second * 30.0 + third * 20.0;
+
+        assert!((equities[0] - expected).abs() < 1e-9);
+        assert!((equities.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+        assert!(equities[0] > equities[1] && equities[1] > equities[2]);
+    }
+
+    #[test]
+    fn splits_evenly_between_equal_stacks() {
+        let payouts = [50.0, 30.0, 20.0];
+        let equities = icm(&[1000; 10], &payouts).unwrap();
+        for equity in equities {
+            assert!((equity - 10.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn gives_the_only_place_in_proportion_to_chips() {
+        let equities = icm(&[10, 30, 60], &[100.0]).unwrap();
+        assert!((equities[0] - 10.0).abs() < 1e-9);
+        assert!((equities[1] - 30.0).abs() < 1e-9);
+        assert!((equities[2] - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn leaves_unpaid_places_out() {
+        // More payouts than players: nobody is left to take the last ones
+        let equities = icm(&[10, 10], &[70.0, 20.0, 10.0]).unwrap();
+        assert_eq!(equities, vec![45.0, 45.0]);
+    }
+
+    #[test]
+    fn monte_carlo_converges_on_the_exact_answer() {
+        let stacks = [4200, 3100, 2500, 1800, 1500, 900, 700, 500, 300];
+        let payouts = [40.0, 25.0, 15.0, 10.0, 6.0, 4.0];
+        let exact = icm(&stacks, &payouts).unwrap();
+
+        let mut rng = SplitMix64::new(11);
+        let estimate = icm_monte_carlo(&stacks, &payouts, 20_000, &mut rng).unwrap();
+        for (estimate, exact) in estimate.iter().zip(&exact) {
+            assert!((estimate - exact).abs() < 0.5, "{} vs {}", estimate, exact);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_fields() {
+        assert_eq!(icm(&[], &[1.0]), Err(IcmError::NoPlayers));
+        assert_eq!(icm(&[10, 0], &[1.0]), Err(IcmError::EmptyStack(1)));
+        assert_eq!(icm(&[10; 17], &[1.0]), Err(IcmError::TooManyPlayers(17)));
+        let mut rng = SplitMix64::new(1);
+        assert!(icm_monte_carlo(&[10; 100], &[1.0], 10, &mut rng).is_ok());
+        assert_eq!(
+            icm_monte_carlo(&[10, 20], &[1.0], 0, &mut rng),
+            Err(IcmError::NoTrials)
+        );
+    }
+}
@@ -1,4 +1,55 @@
-mod card;
-mod hand;
-mod hand_rank;
-mod lookup_tables;
\ No newline at end of file
+// Tests may use std for their fixtures even when the library is built without it
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "std")]
+pub mod blockers;
+pub mod board;
+pub mod card;
+pub mod card_set;
+pub mod combinatorics;
+#[cfg(any(test, feature = "compact-tables"))]
+mod compact_tables;
+pub mod deck;
+#[cfg(feature = "std")]
+pub mod draws;
+#[cfg(feature = "std")]
+pub mod equity;
+#[cfg(feature = "std")]
+pub mod eval_cache;
+#[cfg(feature = "std")]
+pub mod grid;
+pub mod hand;
+#[cfg(feature = "std")]
+pub mod hand_class;
+pub mod hand_rank;
+#[cfg(feature = "std")]
+pub mod hand_strength;
+#[cfg(feature = "std")]
+pub mod icm;
+#[cfg(any(test, not(feature = "compact-tables")))]
+mod lookup_tables;
+#[cfg(feature = "std")]
+pub mod monte_carlo;
+#[cfg(feature = "std")]
+pub mod nuts;
+#[cfg(feature = "std")]
+pub mod outs;
+pub mod percentile;
+mod percentile_tables;
+#[cfg(feature = "std")]
+pub mod preflop;
+#[cfg(feature = "std")]
+mod preflop_tables;
+#[cfg(feature = "std")]
+pub mod push_fold;
+#[cfg(feature = "std")]
+pub mod range;
+#[cfg(feature = "std")]
+pub mod range_equity;
+pub mod rng;
+#[cfg(feature = "std")]
+pub mod showdown;
+#[cfg(feature = "std")]
+pub mod starting_hand;
+pub mod strength;
+pub mod texture;
@@ -1,4 +1,4 @@
-pub static FLUSHES : &[u16; 7937] = &[
+pub const FLUSHES: &[u16; 7937] = &[
 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 0, 0, 0, 1599, 0, 0, 0, 0, 0, 0, 0, 1598, 0, 0, 0, 1597, 0, 1596,
@@ -416,7 +416,7 @@ pub static FLUSHES : &[u16; 7937] = &[
 ];
 
 
-pub static UNIQUES : &[u16; 7937] = &[
+pub const UNIQUES: &[u16; 7937] = &[
 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 0, 0, 0, 0, 0, 0, 0, 0, 0, 1608, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 0, 0, 0, 0, 7462, 0, 0, 0, 0, 0, 0, 0, 7461, 0, 0,  0,  7460,  0,
@@ -841,7 +841,7 @@ pub static UNIQUES : &[u16; 7937] = &[
 0,  0,  0,  0,  0, 0, 0, 0, 0, 0, 0, 1600
 ];
 
-pub static PRODUCTS : &[u32; 4888] = &[
+pub const PRODUCTS: &[u32; 4888] = &[
 48, 72, 80, 108, 112, 120, 162, 168, 176,  180,  200,  208,  252,
 264,  270, 272, 280, 300, 304, 312, 368, 378, 392, 396, 405, 408,
 420, 440, 450, 456, 464, 468, 496, 500, 520, 552, 567, 588,  592,
@@ -1470,7 +1470,7 @@ pub static PRODUCTS : &[u32; 4888] = &[
 ];
 
 
-pub static VALUES : &[u16; 4888] = &[
+pub const VALUES: &[u16; 4888] = &[
 166, 322, 165, 310, 164, 2467, 154, 2466, 163,  3325,  321,  162,
 3324,  2464,  2401,  161,  2465, 3314, 160, 2461, 159, 2400, 320,
 3323, 153, 2457, 6185, 2463, 3303, 2452,  158,  3322,  157,  298,
@@ -0,0 +1,280 @@
+use std::time::{Duration, Instant};
+
+use crate::card::Card;
+use crate::equity::{self, EquityError, PlayerEquity, Tally};
+use crate::rng::Rng;
+
+/// Number of trials run between checks of the time budget and the target
+/// standard error.
+const BATCH: u64 = 1000;
+
+/// When a simulation stops dealing boards.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Budget {
+    /// Deal exactly this many boards, reproducible from the seed
+    Trials(u64),
+    /// Keep dealing until the wall clock runs out, after at least one batch
+    Time(Duration),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Simulation {
+    pub budget: Budget,
+    /// Stop early once every player's standard error is below this
+    pub target_std_error: Option<f64>,
+}
+
+impl Simulation {
+    pub fn trials(trials: u64) -> Self {
+        Simulation {
+            budget: Budget::Trials(trials),
+            target_std_error: None,
+        }
+    }
+
+    pub fn time(budget: Duration) -> Self {
+        Simulation {
+            budget: Budget::Time(budget),
+            target_std_error: None,
+        }
+    }
+
+    pub fn until_std_error(self, target: f64) -> Self {
+        Simulation {
+            target_std_error: Some(target),
+            ..self
+        }
+    }
+}
+
+/// Hands out the trials of a simulation in batches, so that every simulation
+/// stops by the same rules.
+pub(crate) struct Batches<'a> {
+    simulation: &'a Simulation,
+    size: u64,
+    started: Instant,
+}
+
+impl<'a> Batches<'a> {
+    /// Starts the clock on `simulation`, dealing up to `size` trials between
+    /// checks of the budget and the target standard error.
+    pub(crate) fn new(simulation: &'a Simulation, size: u64) -> Self {
+        Batches {
+            simulation,
+            size,
+            started: Instant::now(),
+        }
+    }
+
+    /// Number of trials to deal next, given how many have been dealt so far
+    /// and the largest standard error among them, or 0 once it is time to stop
+    pub(crate) fn next_batch(&self, dealt: u64, std_error: f64) -> u64 {
+        if let Some(target) = self.simulation.target_std_error {
+            if std_error < target {
+                return 0;
+            }
+        }
+
+        match self.simulation.budget {
+            Budget::Trials(trials) => self.size.min(trials - dealt),
+            Budget::Time(budget) if dealt == 0 || self.started.elapsed() < budget => self.size,
+            Budget::Time(_) => 0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlayerEstimate {
+    /// Point estimates of win, tie and equity
+    pub equity: PlayerEquity,
+    /// Standard error of the equity estimate
+    pub std_error: f64,
+}
+
+impl PlayerEstimate {
+    /// Normal approximation interval of `z` standard errors around the
+    /// estimated equity, e.g. `z = 1.96` for 95% confidence.
+    pub fn confidence_interval(&self, z: f64) -> (f64, f64) {
+        let margin = z * self.std_error;
+        (
+            (self.equity.equity - margin).max(0.0),
+            (self.equity.equity + margin).min(1.0),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityEstimate {
+    /// Number of boards that were dealt
+    pub trials: u64,
+    /// One entry per player, in the order the hole cards were given
+    pub players: Vec<PlayerEstimate>,
+}
+
+/// Estimates Hold'em all-in equity by dealing random completions of `board`.
+///
+/// Takes the same inputs as `equity::exact_equity` and suits multiway pots
+/// where enumerating every runout is too slow. With a `Budget::Trials` budget
+/// the estimate only depends on the state of `rng`, so seeding it the same way
+/// reproduces the same numbers.
+pub fn monte_carlo_equity<R: Rng>(
+    players: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    simulation: &Simulation,
+    rng: &mut R,
+) -> Result<EquityEstimate, EquityError> {
+    let deck = equity::validate(players, board, dead)?;
+    if simulation.budget == Budget::Trials(0) {
+        return Err(EquityError::NoTrials);
+    }
+    let batches = Batches::new(simulation, BATCH);
+
+    let mut tally = Tally::new(players.len());
+    let mut cards = [players[0][0]; 7];
+    cards[2..2 + board.len()].copy_from_slice(board);
+
+    loop {
+        let std_error = (0..players.len())
+            .map(|player| tally.std_error(player))
+            .fold(0.0, f64::max);
+        let batch = batches.next_batch(tally.runouts(), std_error);
+        if batch == 0 {
+            break;
+        }
+
+        for _ in 0..batch {
+            let mut remaining = deck;
+            for slot in cards[2 + board.len()..].iter_mut() {
+                *slot = remaining
+                    .draw(rng)
+                    .expect("Deck was checked to be large enough");
+            }
+            tally.deal(players, &mut cards);
+        }
+    }
+
+    let trials = tally.runouts();
+    let std_errors: Vec<f64> = (0..players.len())
+        .map(|player| tally.std_error(player))
+        .collect();
+    let players = tally
+        .finish()
+        .players
+        .into_iter()
+        .zip(std_errors)
+        .map(|(equity, std_error)| PlayerEstimate { equity, std_error })
+        .collect();
+
+    Ok(EquityEstimate { trials, players })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{Rank, Suit};
+    use crate::equity::exact_equity;
+    use crate::rng::SplitMix64;
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    fn flop_spot() -> ([[Card; 2]; 2], [Card; 3]) {
+        let hero = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let villain = [
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Queen, Suit::Diamonds),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Nine, Suit::Clubs),
+        ];
+        ([hero, villain], board)
+    }
+
+    #[test]
+    fn is_reproducible_from_the_seed() {
+        let (players, board) = flop_spot();
+        let simulation = Simulation::trials(2500);
+
+        let first = monte_carlo_equity(&players, &board, &[], &simulation, &mut SplitMix64::new(9))
+            .unwrap();
+        let second =
+            monte_carlo_equity(&players, &board, &[], &simulation, &mut SplitMix64::new(9))
+                .unwrap();
+
+        assert_eq!(first.trials, 2500);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn converges_on_the_exact_equity() {
+        let (players, board) = flop_spot();
+        let exact = exact_equity(&players, &board, &[]).unwrap();
+
+        let estimate = monte_carlo_equity(
+            &players,
+            &board,
+            &[],
+            &Simulation::trials(20_000),
+            &mut SplitMix64::new(1),
+        )
+        .unwrap();
+
+        for (estimate, exact) in estimate.players.iter().zip(&exact.players) {
+            // Four standard errors keeps this test from ever being flaky
+            let (low, high) = estimate.confidence_interval(4.0);
+            assert!(low <= exact.equity && exact.equity <= high);
+            assert!(estimate.std_error < 0.005);
+        }
+    }
+
+    #[test]
+    fn stops_early_at_the_target_std_error() {
+        let (players, board) = flop_spot();
+        let simulation = Simulation::trials(1_000_000).until_std_error(0.01);
+
+        let estimate =
+            monte_carlo_equity(&players, &board, &[], &simulation, &mut SplitMix64::new(3))
+                .unwrap();
+
+        assert!(estimate.trials < 10_000);
+        assert!(estimate.players.iter().all(|p| p.std_error < 0.01));
+    }
+
+    #[test]
+    fn respects_a_time_budget() {
+        let (players, board) = flop_spot();
+        let simulation = Simulation::time(Duration::from_millis(20));
+
+        let estimate =
+            monte_carlo_equity(&players, &board, &[], &simulation, &mut SplitMix64::new(5))
+                .unwrap();
+
+        assert!(estimate.trials >= BATCH);
+        assert_eq!(estimate.trials % BATCH, 0);
+    }
+
+    #[test]
+    fn always_deals_some_boards() {
+        let (players, board) = flop_spot();
+        let mut rng = SplitMix64::new(7);
+
+        let simulation = Simulation::trials(0);
+        assert_eq!(
+            monte_carlo_equity(&players, &board, &[], &simulation, &mut rng),
+            Err(EquityError::NoTrials)
+        );
+
+        let simulation = Simulation::time(Duration::from_secs(0));
+        let estimate = monte_carlo_equity(&players, &board, &[], &simulation, &mut rng).unwrap();
+        assert_eq!(estimate.trials, BATCH);
+        assert!(!estimate.players[0].equity.equity.is_nan());
+    }
+}
@@ -0,0 +1,322 @@
+use crate::board::{self, BoardError};
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::deck::Deck;
+use crate::hand_rank::HandRank;
+use crate::range::Combo;
+
+/// Every holding that makes the same hand on a board
+#[derive(Debug, Clone, PartialEq)]
+pub struct NutTier {
+    pub rank: HandRank,
+    pub combos: Vec<Combo>,
+}
+
+/// Every two-card holding that can be dealt alongside a board, ranked from
+/// the nuts down.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardRanking {
+    board: Vec<Card>,
+    tiers: Vec<NutTier>,
+    holdings: usize,
+}
+
+impl BoardRanking {
+    /// Ranks all holdings on a flop, turn or river `board`.
+    pub fn new(board: &[Card]) -> Result<Self, BoardError> {
+        let known = board::validate(board, 3..=5, &[])?;
+
+        let mut ranked: Vec<(HandRank, Combo)> = holdings(known)
+            .map(|combo| (rank_with(board, combo), combo))
+            .collect();
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.index().cmp(&b.1.index())));
+
+        let holdings = ranked.len();
+        let mut tiers: Vec<NutTier> = Vec::new();
+        for (rank, combo) in ranked {
+            match tiers.last_mut() {
+                Some(tier) if tier.rank == rank => tier.combos.push(combo),
+                _ => tiers.push(NutTier {
+                    rank,
+                    combos: vec![combo],
+                }),
+            }
+        }
+
+        Ok(BoardRanking {
+            board: board.to_vec(),
+            tiers,
+            holdings,
+        })
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Every distinct hand that can be made, best first
+    pub fn tiers(&self) -> &[NutTier] {
+        &self.tiers
+    }
+
+    /// Number of holdings that do not share a card with the board
+    pub fn holdings(&self) -> usize {
+        self.holdings
+    }
+
+    pub fn nuts(&self) -> &NutTier {
+        &self.tiers[0]
+    }
+
+    /// The `n` best distinct hands, or all of them if there are fewer
+    pub fn top(&self, n: usize) -> &[NutTier] {
+        &self.tiers[..n.min(self.tiers.len())]
+    }
+
+    /// Where `hole` sits among everything that could be held on this board.
+    ///
+    /// Returns `None` when `hole` shares a card with the board or holds the
+    /// same card twice. Holdings that need one of our cards are left out of
+    /// the percentages, as nobody else can hold them.
+    pub fn position(&self, hole: [Card; 2]) -> Option<Position> {
+        if hole[0] == hole[1] {
+            return None;
+        }
+        let combo = Combo::from(hole);
+        if !combo.card_set().is_disjoint(CardSet::from(&self.board[..])) {
+            return None;
+        }
+
+        let rank = rank_with(&self.board, combo);
+        let nut_rank = self
+            .tiers
+            .iter()
+            .take_while(|tier| tier.rank > rank)
+            .count()
+            + 1;
+
+        // Anything sharing a card with us, ourselves included, is blocked
+        let blocked = combo.card_set();
+        let (mut better, mut ties, mut total) = (0, 0, 0);
+        for tier in &self.tiers {
+            let live = tier
+                .combos
+                .iter()
+                .filter(|other| other.card_set().is_disjoint(blocked))
+                .count();
+            total += live;
+            if tier.rank > rank {
+                better += live;
+            } else if tier.rank == rank {
+                ties += live;
+            }
+        }
+        Some(Position {
+            rank,
+            nut_rank,
+            beats: (total - better - ties) as f64 / total as f64,
+            ties: ties as f64 / total as f64,
+        })
+    }
+
+    /// What the nuts become for every card that can come on the next street.
+    /// Empty on the river.
+    pub fn next_street(&self) -> Vec<NutChange> {
+        if self.board.len() == 5 {
+            return Vec::new();
+        }
+
+        let known = CardSet::from(&self.board[..]);
+        let mut board = self.board.clone();
+        board.push(self.board[0]);
+        let last = board.len() - 1;
+
+        Deck::without(known)
+            .cards()
+            .iter()
+            .map(|card| {
+                board[last] = card;
+                let nuts = holdings(known.union(CardSet::from(&[card][..])))
+                    .map(|combo| rank_with(&board, combo))
+                    .max()
+                    .expect("a 5 card board always leaves holdings");
+
+                let still_nuts = self
+                    .nuts()
+                    .combos
+                    .iter()
+                    .filter(|combo| !combo.card_set().contains(card))
+                    .any(|combo| rank_with(&board, *combo) == nuts);
+
+                NutChange {
+                    card,
+                    nuts,
+                    still_nuts,
+                }
+            })
+            .collect()
+    }
+}
+
+/// How a holding compares with every other holding on the board
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Position {
+    pub rank: HandRank,
+    /// 1 for the nuts, 2 for the second nuts and so on, counting distinct
+    /// hands rather than holdings
+    pub nut_rank: usize,
+    /// Share of the other holdings that we beat
+    pub beats: f64,
+    /// Share of the other holdings that we tie with
+    pub ties: f64,
+}
+
+impl Position {
+    pub fn is_nuts(&self) -> bool {
+        self.nut_rank == 1
+    }
+}
+
+impl core::fmt::Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.is_nuts() {
+            write!(f, "the nuts")?;
+        } else {
+            let suffix = match (self.nut_rank % 10, self.nut_rank % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            write!(f, "{}{} nuts", self.nut_rank, suffix)?;
+        }
+        write!(
+            f,
+            ", beats {:.0}% of holdings",
+            (self.beats * 100.0).floor()
+        )
+    }
+}
+
+/// The nuts after one particular card on the next street
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NutChange {
+    pub card: Card,
+    pub nuts: HandRank,
+    /// Whether any of the current nut holdings is still the nuts
+    pub still_nuts: bool,
+}
+
+fn holdings(known: CardSet) -> impl Iterator<Item = Combo> {
+    Deck::without(known).combinations(2).map(|set| {
+        let mut cards = set.iter();
+        Combo::new(cards.next().unwrap(), cards.next().unwrap())
+    })
+}
+
+fn rank_with(board: &[Card], combo: Combo) -> HandRank {
+    let mut cards = combo.cards().to_vec();
+    cards.extend_from_slice(board);
+    HandRank::best_of(&cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, parse_hole};
+    use crate::hand_rank::Category;
+
+    #[test]
+    fn finds_the_nuts_on_a_flop() {
+        let board = parse_cards("Kh7h3c").unwrap();
+        let ranking = BoardRanking::new(&board).unwrap();
+
+        assert_eq!(ranking.holdings(), 1176);
+        assert_eq!(
+            ranking
+                .tiers()
+                .iter()
+                .map(|t| t.combos.len())
+                .sum::<usize>(),
+            1176
+        );
+
+        // Top set, then sevens and treys
+        let nuts = ranking.nuts();
+        assert_eq!(nuts.rank.category(), Category::ThreeOfAKind);
+        assert_eq!(nuts.combos.len(), 3);
+        let top: Vec<usize> = ranking.top(3).iter().map(|t| t.combos.len()).collect();
+        assert_eq!(top, vec![3, 3, 3]);
+        assert_eq!(ranking.top(3)[2].rank.category(), Category::ThreeOfAKind);
+    }
+
+    #[test]
+    fn places_a_hand_among_all_holdings() {
+        let board = parse_cards("Kh7h3c").unwrap();
+        let ranking = BoardRanking::new(&board).unwrap();
+
+        let position = ranking.position(parse_hole("KsKd")).unwrap();
+        assert!(position.is_nuts());
+        assert_eq!(position.ties, 0.0);
+        assert_eq!(position.beats, 1.0);
+
+        // Bottom set only loses to the three combos each of kings and sevens
+        let position = ranking.position(parse_hole("3s3d")).unwrap();
+        assert_eq!(position.nut_rank, 3);
+        assert_eq!(position.to_string(), "3rd nuts, beats 99% of holdings");
+
+        assert_eq!(ranking.position(parse_hole("Kh2c")), None);
+        let two = parse_hole("2c2d")[0];
+        assert_eq!(ranking.position([two, two]), None);
+    }
+
+    #[test]
+    fn counts_ties_but_not_ourselves() {
+        // Broadway on the board: everyone chops
+        let board = parse_cards("AsKdQhJcTs").unwrap();
+        let ranking = BoardRanking::new(&board).unwrap();
+
+        assert_eq!(ranking.tiers().len(), 1);
+        let position = ranking.position(parse_hole("2c3d")).unwrap();
+        assert!(position.is_nuts());
+        assert_eq!(position.beats, 0.0);
+        assert_eq!(position.ties, 1.0);
+        assert!(ranking.next_street().is_empty());
+    }
+
+    #[test]
+    fn shows_how_the_nuts_change_on_the_turn() {
+        let board = parse_cards("Kh7h3c").unwrap();
+        let ranking = BoardRanking::new(&board).unwrap();
+        let changes = ranking.next_street();
+        assert_eq!(changes.len(), 49);
+
+        // Top set pairs up into quads, a third heart makes flushes
+        let king = changes.iter().find(|c| c.card.to_string() == "Kd").unwrap();
+        assert_eq!(king.nuts.category(), Category::FourOfAKind);
+        assert!(king.still_nuts);
+
+        let heart = changes.iter().find(|c| c.card.to_string() == "Qh").unwrap();
+        assert_eq!(heart.nuts.category(), Category::Flush);
+        assert!(!heart.still_nuts);
+    }
+
+    #[test]
+    fn rejects_bad_boards() {
+        assert_eq!(
+            BoardRanking::new(&parse_cards("Kh7h").unwrap()),
+            Err(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 5
+            })
+        );
+        let board = parse_cards("Kh7hKh").unwrap();
+        assert_eq!(
+            BoardRanking::new(&board),
+            Err(BoardError::DuplicateCard(board[0]))
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::board::{self, BoardError};
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::deck::Deck;
+use crate::hand_rank::{Category, HandRank};
+use crate::range::Range;
+use crate::strength::Strength;
+
+/// Who we are drawing against, if anyone.
+#[derive(Debug, Copy, Clone)]
+pub enum Opponent<'a> {
+    Nobody,
+    Hand([Card; 2]),
+    Range(&'a Range),
+}
+
+/// A card that improves the player to a better category of hand than the
+/// board makes on its own.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Out {
+    pub card: Card,
+    /// What the player holds once the card comes
+    pub category: Category,
+    /// Share of the opponent's holdings, by weight, that end up with a better
+    /// hand than ours when the card comes. Anything above zero makes the out
+    /// "dirty"; against a single hand it is either 0 or 1.
+    pub dirty: f64,
+}
+
+impl Out {
+    pub fn is_clean(&self) -> bool {
+        self.dirty == 0.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outs {
+    /// What the player holds right now
+    pub current: Category,
+    /// Every improving card, in `Card::index` order
+    pub outs: Vec<Out>,
+    /// Number of cards the next card can be, i.e. the ones we have not seen
+    pub unseen: usize,
+    /// Cards still to be dealt: two on the flop, one on the turn
+    pub cards_to_come: usize,
+}
+
+impl Outs {
+    /// The outs grouped by the category they make, best category first
+    pub fn by_category(&self) -> Vec<(Category, Vec<Card>)> {
+        let mut groups: Vec<(Category, Vec<Card>)> = Vec::new();
+        for category in Category::ALL.iter().rev() {
+            let cards: Vec<Card> = self
+                .outs
+                .iter()
+                .filter(|out| out.category == *category)
+                .map(|out| out.card)
+                .collect();
+            if !cards.is_empty() {
+                groups.push((*category, cards));
+            }
+        }
+        groups
+    }
+
+    pub fn clean(&self) -> impl Iterator<Item = &Out> + '_ {
+        self.outs.iter().filter(|out| out.is_clean())
+    }
+
+    /// Chance that the very next card is an out
+    pub fn next_card_probability(&self) -> f64 {
+        self.outs.len() as f64 / self.unseen as f64
+    }
+
+    /// Chance of hitting at least one out by the river. This only counts
+    /// single cards, runner-runner draws are not outs.
+    pub fn by_river_probability(&self) -> f64 {
+        let misses = self.unseen - self.outs.len();
+        let mut miss_all = 1.0;
+        for i in 0..self.cards_to_come {
+            miss_all *= (misses - i) as f64 / (self.unseen - i) as f64;
+        }
+        1.0 - miss_all
+    }
+}
+
+/// Lists every unseen card that improves `hole` to a better category of hand
+/// on a flop or turn `board`.
+///
+/// A card only counts if the hand it makes is a better category than both
+/// the player's current hand and the best hand the new board makes on its
+/// own. One that merely pairs the board improves everybody's hand alike, and
+/// one that counterfeits two pair gains the player nothing.
+///
+/// Cards held by a known opponent are not unseen. Against a range, every
+/// unseen card is still a possible next card and the range only decides how
+/// dirty each out is.
+pub fn outs(hole: [Card; 2], board: &[Card], opponent: Opponent) -> Result<Outs, BoardError> {
+    let mut others = hole.to_vec();
+    if let Opponent::Hand(cards) = &opponent {
+        others.extend_from_slice(cards);
+    }
+    let known = board::validate(board, 3..=4, &others)?;
+
+    let mut cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+    let current = HandRank::best_of(&cards).category();
+    let deck = Deck::without(known);
+
+    let mut outs = Vec::new();
+    for card in deck.cards() {
+        cards.push(card);
+        let made = HandRank::best_of(&cards);
+        cards.pop();
+
+        let mut board = board.to_vec();
+        board.push(card);
+        if made.category() <= current || made.category() <= board_category(&board) {
+            continue;
+        }
+
+        outs.push(Out {
+            card,
+            category: made.category(),
+            dirty: beaten_by(made.strength(), &board, hole, opponent),
+        });
+    }
+
+    Ok(Outs {
+        current,
+        outs,
+        unseen: deck.len(),
+        cards_to_come: 5 - board.len(),
+    })
+}
+
+/// Category the board makes without any hole cards. Boards of fewer than five
+/// cards can only make the paired categories.
+fn board_category(board: &[Card]) -> Category {
+    if board.len() >= 5 {
+        return HandRank::best_of(board).category();
+    }
+
+    let mut counts = [0; 13];
+    for card in board {
+        counts[card.rank().order_encoding() as usize] += 1;
+    }
+    let most = counts.iter().copied().max().unwrap_or(0);
+    let pairs = counts.iter().filter(|count| **count == 2).count();
+    match (most, pairs) {
+        (4, _) => Category::FourOfAKind,
+        (3, _) => Category::ThreeOfAKind,
+        (_, 2) => Category::TwoPair,
+        (_, 1) => Category::OnePair,
+        _ => Category::HighCard,
+    }
+}
+
+/// Share of the opponent's holdings that beat `strength` on `board`
+fn beaten_by(strength: Strength, board: &[Card], hole: [Card; 2], opponent: Opponent) -> f64 {
+    let beats = |cards: [Card; 2]| {
+        let mut all = cards.to_vec();
+        all.extend_from_slice(board);
+        HandRank::best_of(&all).strength() > strength
+    };
+
+    match opponent {
+        Opponent::Nobody => 0.0,
+        Opponent::Hand(cards) => {
+            if beats(cards) {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        Opponent::Range(range) => {
+            let known = CardSet::from(board).union(CardSet::from(&hole[..]));
+            let (mut better, mut total) = (0.0, 0.0);
+            for (combo, weight) in range.without(known).combos() {
+                total += weight;
+                if beats(combo.cards()) {
+                    better += weight;
+                }
+            }
+            if total > 0.0 {
+                better / total
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, parse_hole, Rank};
+
+    #[test]
+    fn counts_flush_and_straight_outs() {
+        // Nut flush draw with an overcard to the board
+        let board = parse_cards("Kh7h3c").unwrap();
+        let outs = outs(parse_hole("Ah4h"), &board, Opponent::Nobody).unwrap();
+
+        assert_eq!(outs.current, Category::HighCard);
+        assert_eq!(outs.unseen, 47);
+        assert_eq!(outs.cards_to_come, 2);
+
+        let groups = outs.by_category();
+        assert_eq!(groups[0].0, Category::Flush);
+        assert_eq!(groups[0].1.len(), 9);
+        assert_eq!(groups[1].0, Category::OnePair);
+        // Three aces and three fours. Kings, sevens and treys pair the board
+        // for everybody, so they are not outs.
+        assert_eq!(groups[1].1.len(), 6);
+        assert_eq!(outs.outs.len(), 15);
+
+        assert!((outs.next_card_probability() - 15.0 / 47.0).abs() < 1e-12);
+        let by_river = 1.0 - (32.0 / 47.0) * (31.0 / 46.0);
+        assert!((outs.by_river_probability() - by_river).abs() < 1e-12);
+    }
+
+    #[test]
+    fn flags_dirty_outs_against_a_hand() {
+        // Our open ender against a flush draw on the turn
+        let board = parse_cards("9h8h2c3d").unwrap();
+        let outs = outs(
+            parse_hole("TsJs"),
+            &board,
+            Opponent::Hand(parse_hole("AhKh")),
+        )
+        .unwrap();
+
+        assert_eq!(outs.unseen, 44);
+        assert_eq!(outs.cards_to_come, 1);
+
+        let straights: Vec<&Out> = outs
+            .outs
+            .iter()
+            .filter(|out| out.category == Category::Straight)
+            .collect();
+        assert_eq!(straights.len(), 8);
+
+        // The queen and seven of hearts also give the opponent a flush
+        let dirty: Vec<String> = straights
+            .iter()
+            .filter(|out| !out.is_clean())
+            .map(|out| out.card.to_string())
+            .collect();
+        assert_eq!(dirty, vec!["7h", "Qh"]);
+        assert_eq!(
+            outs.clean()
+                .filter(|o| o.category == Category::Straight)
+                .count(),
+            6
+        );
+    }
+
+    #[test]
+    fn measures_dirtiness_against_a_range() {
+        let board = parse_cards("9h8h2c3d").unwrap();
+        let range: Range = "AhKh, QQ".parse().unwrap();
+        let outs = outs(parse_hole("TsJs"), &board, Opponent::Range(&range)).unwrap();
+
+        let seven_of_hearts = outs
+            .outs
+            .iter()
+            .find(|out| out.card.to_string() == "7h")
+            .unwrap();
+        // Only the flush draw out of seven combos gets there
+        assert!((seven_of_hearts.dirty - 1.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ignores_counterfeiting_cards() {
+        let board = parse_cards("8s8h2c").unwrap();
+        let outs = outs(parse_hole("5c5d"), &board, Opponent::Nobody).unwrap();
+
+        assert_eq!(outs.current, Category::TwoPair);
+        // A deuce gives the board two pair and leaves us with no more than it
+        assert!(outs.outs.iter().all(|out| out.card.rank() != Rank::Two));
+        let cards: Vec<String> = outs.outs.iter().map(|out| out.card.to_string()).collect();
+        assert_eq!(cards, vec!["5s", "5h", "8d", "8c"]);
+        assert!(outs
+            .outs
+            .iter()
+            .all(|out| out.category == Category::FullHouse));
+    }
+
+    #[test]
+    fn counts_quads_outs_on_a_paired_board() {
+        let board = parse_cards("7s2c2d").unwrap();
+        let outs = outs(parse_hole("7c7d"), &board, Opponent::Nobody).unwrap();
+
+        assert_eq!(outs.current, Category::FullHouse);
+        assert_eq!(outs.outs.len(), 1);
+        assert_eq!(outs.outs[0].card.to_string(), "7h");
+        assert_eq!(outs.outs[0].category, Category::FourOfAKind);
+    }
+
+    #[test]
+    fn rejects_bad_boards() {
+        let board = parse_cards("Kh7h").unwrap();
+        assert_eq!(
+            outs(parse_hole("Ah4h"), &board, Opponent::Nobody),
+            Err(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 4
+            })
+        );
+
+        let board = parse_cards("Kh7hAh").unwrap();
+        assert_eq!(
+            outs(parse_hole("Ah4h"), &board, Opponent::Nobody),
+            Err(BoardError::DuplicateCard(board[2]))
+        );
+    }
+}
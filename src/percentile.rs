@@ -0,0 +1,123 @@
+use crate::hand_rank::{HandRank, NumericHandRank};
+use crate::percentile_tables::{FIVE_CARD, SEVEN_CARD};
+
+/// The set of hands a hand value is ranked against.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Population {
+    /// Every 5 card hand, as in draw or stud games
+    FiveCard,
+    /// The best hand out of every 7 cards, as a Hold'em player sees at the
+    /// river
+    SevenCard,
+}
+
+impl Population {
+    /// Number of hands in the population
+    pub const fn hands(&self) -> u64 {
+        match self {
+            Population::FiveCard => 2_598_960,
+            Population::SevenCard => 133_784_560,
+        }
+    }
+
+    const fn table(&self) -> &'static [u32; 7462] {
+        match self {
+            Population::FiveCard => FIVE_CARD,
+            Population::SevenCard => SEVEN_CARD,
+        }
+    }
+}
+
+/// Percentage of the hands in `population` that a hand of value `rank`
+/// beats outright, e.g. 87.3 for "beats 87.3% of possible hands". Hands it
+/// only ties with do not count.
+///
+/// Panics if `rank` is not a value the evaluator hands out, 1 to 7462.
+pub fn percentile(rank: NumericHandRank, population: Population) -> f64 {
+    assert!((1..=7462).contains(&rank), "{} is not a hand value", rank);
+    let beaten = population.table()[rank as usize - 1];
+    f64::from(beaten) * 100.0 / population.hands() as f64
+}
+
+impl HandRank {
+    /// Shorthand for `percentile(self.numeric(), population)`
+    pub fn percentile(&self, population: Population) -> f64 {
+        percentile(self.numeric(), population)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{parse_cards, Card};
+    use crate::combinatorics::hand_counts;
+    use crate::deck::Deck;
+    use crate::hand_rank::Category;
+
+    fn rank(cards: &str) -> HandRank {
+        HandRank::best_of(&parse_cards(cards).unwrap())
+    }
+
+    /// Number of hands in the population with exactly this value
+    fn hands_valued(numeric: NumericHandRank, population: Population) -> u64 {
+        let table = population.table();
+        let i = numeric as usize - 1;
+        let beaten_by_better = if i == 0 {
+            population.hands()
+        } else {
+            u64::from(table[i - 1])
+        };
+        beaten_by_better - u64::from(table[i])
+    }
+
+    #[test]
+    fn spans_the_whole_population() {
+        for population in &[Population::FiveCard, Population::SevenCard] {
+            assert_eq!(percentile(7462, *population), 0.0);
+            let royals = hands_valued(1, *population) as f64;
+            let expected = 100.0 - royals * 100.0 / population.hands() as f64;
+            assert!((percentile(1, *population) - expected).abs() < 1e-9);
+            assert!(population.table().windows(2).all(|w| w[0] >= w[1]));
+        }
+    }
+
+    #[test]
+    fn five_card_table_matches_enumeration() {
+        let mut counts = vec![0_u64; 7462];
+        let mut cards: Vec<Card> = Vec::with_capacity(5);
+        for hand in Deck::new().combinations(5) {
+            cards.clear();
+            cards.extend(hand);
+            counts[HandRank::best_of(&cards).numeric() as usize - 1] += 1;
+        }
+        for (i, count) in counts.iter().enumerate() {
+            assert_eq!(hands_valued(i as u32 + 1, Population::FiveCard), *count);
+        }
+    }
+
+    #[test]
+    fn seven_card_table_matches_category_counts() {
+        let counts = hand_counts(7).unwrap();
+        for category in Category::ALL.iter() {
+            let in_table: u64 = (1..=7462)
+                .filter(|i| HandRank::from_numeric(*i).category() == *category)
+                .map(|i| hands_valued(i, Population::SevenCard))
+                .sum();
+            assert_eq!(in_table, counts.count(*category), "{}", category);
+        }
+    }
+
+    #[test]
+    fn places_familiar_hands() {
+        // Any pair of aces beats every high card and most other pairs
+        let aces = rank("AhAd7c4s2h");
+        assert!(aces.percentile(Population::FiveCard) > 85.0);
+        // With seven cards to choose from, one pair is far more ordinary
+        assert!(aces.percentile(Population::SevenCard) < 60.0);
+        assert!(
+            rank("AhKhQhJhTh").percentile(Population::SevenCard)
+                > rank("9h8h7h6h5h").percentile(Population::SevenCard)
+        );
+    }
+}
@@ -0,0 +1,1502 @@
+// Generated by examples/generate_percentile_tables.rs, do not edit.
+// Entry `i` counts the hands that lose to a `NumericHandRank` of `i + 1`.
+
+pub const FIVE_CARD: &[u32; 7462] = &[
+2598956, 2598952, 2598948, 2598944, 2598940, 2598936, 2598932, 2598928, 2598924, 2598920,
+2598916, 2598912, 2598908, 2598904, 2598900, 2598896, 2598892, 2598888, 2598884, 2598880,
+2598876, 2598872, 2598868, 2598864, 2598860, 2598856, 2598852, 2598848, 2598844, 2598840,
+2598836, 2598832, 2598828, 2598824, 2598820, 2598816, 2598812, 2598808, 2598804, 2598800,
+2598796, 2598792, 2598788, 2598784, 2598780, 2598776, 2598772, 2598768, 2598764, 2598760,
+2598756, 2598752, 2598748, 2598744, 2598740, 2598736, 2598732, 2598728, 2598724, 2598720,
+2598716, 2598712, 2598708, 2598704, 2598700, 2598696, 2598692, 2598688, 2598684, 2598680,
+2598676, 2598672, 2598668, 2598664, 2598660, 2598656, 2598652, 2598648, 2598644, 2598640,
+2598636, 2598632, 2598628, 2598624, 2598620, 2598616, 2598612, 2598608, 2598604, 2598600,
+2598596, 2598592, 2598588, 2598584, 2598580, 2598576, 2598572, 2598568, 2598564, 2598560,
+2598556, 2598552, 2598548, 2598544, 2598540, 2598536, 2598532, 2598528, 2598524, 2598520,
+2598516, 2598512, 2598508, 2598504, 2598500, 2598496, 2598492, 2598488, 2598484, 2598480,
+2598476, 2598472, 2598468, 2598464, 2598460, 2598456, 2598452, 2598448, 2598444, 2598440,
+2598436, 2598432, 2598428, 2598424, 2598420, 2598416, 2598412, 2598408, 2598404, 2598400,
+2598396, 2598392, 2598388, 2598384, 2598380, 2598376, 2598372, 2598368, 2598364, 2598360,
+2598356, 2598352, 2598348, 2598344, 2598340, 2598336, 2598332, 2598328, 2598324, 2598320,
+2598316, 2598312, 2598308, 2598304, 2598300, 2598296, 2598272, 2598248, 2598224, 2598200,
+2598176, 2598152, 2598128, 2598104, 2598080, 2598056, 2598032, 2598008, 2597984, 2597960,
+2597936, 2597912, 2597888, 2597864, 2597840, 2597816, 2597792, 2597768, 2597744, 2597720,
+2597696, 2597672, 2597648, 2597624, 2597600, 2597576, 2597552, 2597528, 2597504, 2597480,
+2597456, 2597432, 2597408, 2597384, 2597360, 2597336, 2597312, 2597288, 2597264, 2597240,
+2597216, 2597192, 2597168, 2597144, 2597120, 2597096, 2597072, 2597048, 2597024, 2597000,
+2596976, 2596952, 2596928, 2596904, 2596880, 2596856, 2596832, 2596808, 2596784, 2596760,
+2596736, 2596712, 2596688, 2596664, 2596640, 2596616, 2596592, 2596568, 2596544, 2596520,
+2596496, 2596472, 2596448, 2596424, 2596400, 2596376, 2596352, 2596328, 2596304, 2596280,
+2596256, 2596232, 2596208, 2596184, 2596160, 2596136, 2596112, 2596088, 2596064, 2596040,
+2596016, 2595992, 2595968, 2595944, 2595920, 2595896, 2595872, 2595848, 2595824, 2595800,
+2595776, 2595752, 2595728, 2595704, 2595680, 2595656, 2595632, 2595608, 2595584, 2595560,
+2595536, 2595512, 2595488, 2595464, 2595440, 2595416, 2595392, 2595368, 2595344, 2595320,
+2595296, 2595272, 2595248, 2595224, 2595200, 2595176, 2595152, 2595128, 2595104, 2595080,
+2595056, 2595032, 2595008, 2594984, 2594960, 2594936, 2594912, 2594888, 2594864, 2594840,
+2594816, 2594792, 2594768, 2594744, 2594720, 2594696, 2594672, 2594648, 2594624, 2594600,
+2594576, 2594552, 2594548, 2594544, 2594540, 2594536, 2594532, 2594528, 2594524, 2594520,
+2594516, 2594512, 2594508, 2594504, 2594500, 2594496, 2594492, 2594488, 2594484, 2594480,
+2594476, 2594472, 2594468, 2594464, 2594460, 2594456, 2594452, 2594448, 2594444, 2594440,
+2594436, 2594432, 2594428, 2594424, 2594420, 2594416, 2594412, 2594408, 2594404, 2594400,
+2594396, 2594392, 2594388, 2594384, 2594380, 2594376, 2594372, 2594368, 2594364, 2594360,
+2594356, 2594352, 2594348, 2594344, 2594340, 2594336, 2594332, 2594328, 2594324, 2594320,
+2594316, 2594312, 2594308, 2594304, 2594300, 2594296, 2594292, 2594288, 2594284, 2594280,
+2594276, 2594272, 2594268, 2594264, 2594260, 2594256, 2594252, 2594248, 2594244, 2594240,
+2594236, 2594232, 2594228, 2594224, 2594220, 2594216, 2594212, 2594208, 2594204, 2594200,
+2594196, 2594192, 2594188, 2594184, 2594180, 2594176, 2594172, 2594168, 2594164, 2594160,
+2594156, 2594152, 2594148, 2594144, 2594140, 2594136, 2594132, 2594128, 2594124, 2594120,
+2594116, 2594112, 2594108, 2594104, 2594100, 2594096, 2594092, 2594088, 2594084, 2594080,
+2594076, 2594072, 2594068, 2594064, 2594060, 2594056, 2594052, 2594048, 2594044, 2594040,
+2594036, 2594032, 2594028, 2594024, 2594020, 2594016, 2594012, 2594008, 2594004, 2594000,
+2593996, 2593992, 2593988, 2593984, 2593980, 2593976, 2593972, 2593968, 2593964, 2593960,
+2593956, 2593952, 2593948, 2593944, 2593940, 2593936, 2593932, 2593928, 2593924, 2593920,
+2593916, 2593912, 2593908, 2593904, 2593900, 2593896, 2593892, 2593888, 2593884, 2593880,
+2593876, 2593872, 2593868, 2593864, 2593860, 2593856, 2593852, 2593848, 2593844, 2593840,
+2593836, 2593832, 2593828, 2593824, 2593820, 2593816, 2593812, 2593808, 2593804, 2593800,
+2593796, 2593792, 2593788, 2593784, 2593780, 2593776, 2593772, 2593768, 2593764, 2593760,
+2593756, 2593752, 2593748, 2593744, 2593740, 2593736, 2593732, 2593728, 2593724, 2593720,
+2593716, 2593712, 2593708, 2593704, 2593700, 2593696, 2593692, 2593688, 2593684, 2593680,
+2593676, 2593672, 2593668, 2593664, 2593660, 2593656, 2593652, 2593648, 2593644, 2593640,
+2593636, 2593632, 2593628, 2593624, 2593620, 2593616, 2593612, 2593608, 2593604, 2593600,
+2593596, 2593592, 2593588, 2593584, 2593580, 2593576, 2593572, 2593568, 2593564, 2593560,
+2593556, 2593552, 2593548, 2593544, 2593540, 2593536, 2593532, 2593528, 2593524, 2593520,
+2593516, 2593512, 2593508, 2593504, 2593500, 2593496, 2593492, 2593488, 2593484, 2593480,
+2593476, 2593472, 2593468, 2593464, 2593460, 2593456, 2593452, 2593448, 2593444, 2593440,
+2593436, 2593432, 2593428, 2593424, 2593420, 2593416, 2593412, 2593408, 2593404, 2593400,
+2593396, 2593392, 2593388, 2593384, 2593380, 2593376, 2593372, 2593368, 2593364, 2593360,
+2593356, 2593352, 2593348, 2593344, 2593340, 2593336, 2593332, 2593328, 2593324, 2593320,
+2593316, 2593312, 2593308, 2593304, 2593300, 2593296, 2593292, 2593288, 2593284, 2593280,
+2593276, 2593272, 2593268, 2593264, 2593260, 2593256, 2593252, 2593248, 2593244, 2593240,
+2593236, 2593232, 2593228, 2593224, 2593220, 2593216, 2593212, 2593208, 2593204, 2593200,
+2593196, 2593192, 2593188, 2593184, 2593180, 2593176, 2593172, 2593168, 2593164, 2593160,
+2593156, 2593152, 2593148, 2593144, 2593140, 2593136, 2593132, 2593128, 2593124, 2593120,
+2593116, 2593112, 2593108, 2593104, 2593100, 2593096, 2593092, 2593088, 2593084, 2593080,
+2593076, 2593072, 2593068, 2593064, 2593060, 2593056, 2593052, 2593048, 2593044, 2593040,
+2593036, 2593032, 2593028, 2593024, 2593020, 2593016, 2593012, 2593008, 2593004, 2593000,
+2592996, 2592992, 2592988, 2592984, 2592980, 2592976, 2592972, 2592968, 2592964, 2592960,
+2592956, 2592952, 2592948, 2592944, 2592940, 2592936, 2592932, 2592928, 2592924, 2592920,
+2592916, 2592912, 2592908, 2592904, 2592900, 2592896, 2592892, 2592888, 2592884, 2592880,
+2592876, 2592872, 2592868, 2592864, 2592860, 2592856, 2592852, 2592848, 2592844, 2592840,
+2592836, 2592832, 2592828, 2592824, 2592820, 2592816, 2592812, 2592808, 2592804, 2592800,
+2592796, 2592792, 2592788, 2592784, 2592780, 2592776, 2592772, 2592768, 2592764, 2592760,
+2592756, 2592752, 2592748, 2592744, 2592740, 2592736, 2592732, 2592728, 2592724, 2592720,
+2592716, 2592712, 2592708, 2592704, 2592700, 2592696, 2592692, 2592688, 2592684, 2592680,
+2592676, 2592672, 2592668, 2592664, 2592660, 2592656, 2592652, 2592648, 2592644, 2592640,
+2592636, 2592632, 2592628, 2592624, 2592620, 2592616, 2592612, 2592608, 2592604, 2592600,
+2592596, 2592592, 2592588, 2592584, 2592580, 2592576, 2592572, 2592568, 2592564, 2592560,
+2592556, 2592552, 2592548, 2592544, 2592540, 2592536, 2592532, 2592528, 2592524, 2592520,
+2592516, 2592512, 2592508, 2592504, 2592500, 2592496, 2592492, 2592488, 2592484, 2592480,
+2592476, 2592472, 2592468, 2592464, 2592460, 2592456, 2592452, 2592448, 2592444, 2592440,
+2592436, 2592432, 2592428, 2592424, 2592420, 2592416, 2592412, 2592408, 2592404, 2592400,
+2592396, 2592392, 2592388, 2592384, 2592380, 2592376, 2592372, 2592368, 2592364, 2592360,
+2592356, 2592352, 2592348, 2592344, 2592340, 2592336, 2592332, 2592328, 2592324, 2592320,
+2592316, 2592312, 2592308, 2592304, 2592300, 2592296, 2592292, 2592288, 2592284, 2592280,
+2592276, 2592272, 2592268, 2592264, 2592260, 2592256, 2592252, 2592248, 2592244, 2592240,
+2592236, 2592232, 2592228, 2592224, 2592220, 2592216, 2592212, 2592208, 2592204, 2592200,
+2592196, 2592192, 2592188, 2592184, 2592180, 2592176, 2592172, 2592168, 2592164, 2592160,
+2592156, 2592152, 2592148, 2592144, 2592140, 2592136, 2592132, 2592128, 2592124, 2592120,
+2592116, 2592112, 2592108, 2592104, 2592100, 2592096, 2592092, 2592088, 2592084, 2592080,
+2592076, 2592072, 2592068, 2592064, 2592060, 2592056, 2592052, 2592048, 2592044, 2592040,
+2592036, 2592032, 2592028, 2592024, 2592020, 2592016, 2592012, 2592008, 2592004, 2592000,
+2591996, 2591992, 2591988, 2591984, 2591980, 2591976, 2591972, 2591968, 2591964, 2591960,
+2591956, 2591952, 2591948, 2591944, 2591940, 2591936, 2591932, 2591928, 2591924, 2591920,
+2591916, 2591912, 2591908, 2591904, 2591900, 2591896, 2591892, 2591888, 2591884, 2591880,
+2591876, 2591872, 2591868, 2591864, 2591860, 2591856, 2591852, 2591848, 2591844, 2591840,
+2591836, 2591832, 2591828, 2591824, 2591820, 2591816, 2591812, 2591808, 2591804, 2591800,
+2591796, 2591792, 2591788, 2591784, 2591780, 2591776, 2591772, 2591768, 2591764, 2591760,
+2591756, 2591752, 2591748, 2591744, 2591740, 2591736, 2591732, 2591728, 2591724, 2591720,
+2591716, 2591712, 2591708, 2591704, 2591700, 2591696, 2591692, 2591688, 2591684, 2591680,
+2591676, 2591672, 2591668, 2591664, 2591660, 2591656, 2591652, 2591648, 2591644, 2591640,
+2591636, 2591632, 2591628, 2591624, 2591620, 2591616, 2591612, 2591608, 2591604, 2591600,
+2591596, 2591592, 2591588, 2591584, 2591580, 2591576, 2591572, 2591568, 2591564, 2591560,
+2591556, 2591552, 2591548, 2591544, 2591540, 2591536, 2591532, 2591528, 2591524, 2591520,
+2591516, 2591512, 2591508, 2591504, 2591500, 2591496, 2591492, 2591488, 2591484, 2591480,
+2591476, 2591472, 2591468, 2591464, 2591460, 2591456, 2591452, 2591448, 2591444, 2591440,
+2591436, 2591432, 2591428, 2591424, 2591420, 2591416, 2591412, 2591408, 2591404, 2591400,
+2591396, 2591392, 2591388, 2591384, 2591380, 2591376, 2591372, 2591368, 2591364, 2591360,
+2591356, 2591352, 2591348, 2591344, 2591340, 2591336, 2591332, 2591328, 2591324, 2591320,
+2591316, 2591312, 2591308, 2591304, 2591300, 2591296, 2591292, 2591288, 2591284, 2591280,
+2591276, 2591272, 2591268, 2591264, 2591260, 2591256, 2591252, 2591248, 2591244, 2591240,
+2591236, 2591232, 2591228, 2591224, 2591220, 2591216, 2591212, 2591208, 2591204, 2591200,
+2591196, 2591192, 2591188, 2591184, 2591180, 2591176, 2591172, 2591168, 2591164, 2591160,
+2591156, 2591152, 2591148, 2591144, 2591140, 2591136, 2591132, 2591128, 2591124, 2591120,
+2591116, 2591112, 2591108, 2591104, 2591100, 2591096, 2591092, 2591088, 2591084, 2591080,
+2591076, 2591072, 2591068, 2591064, 2591060, 2591056, 2591052, 2591048, 2591044, 2591040,
+2591036, 2591032, 2591028, 2591024, 2591020, 2591016, 2591012, 2591008, 2591004, 2591000,
+2590996, 2590992, 2590988, 2590984, 2590980, 2590976, 2590972, 2590968, 2590964, 2590960,
+2590956, 2590952, 2590948, 2590944, 2590940, 2590936, 2590932, 2590928, 2590924, 2590920,
+2590916, 2590912, 2590908, 2590904, 2590900, 2590896, 2590892, 2590888, 2590884, 2590880,
+2590876, 2590872, 2590868, 2590864, 2590860, 2590856, 2590852, 2590848, 2590844, 2590840,
+2590836, 2590832, 2590828, 2590824, 2590820, 2590816, 2590812, 2590808, 2590804, 2590800,
+2590796, 2590792, 2590788, 2590784, 2590780, 2590776, 2590772, 2590768, 2590764, 2590760,
+2590756, 2590752, 2590748, 2590744, 2590740, 2590736, 2590732, 2590728, 2590724, 2590720,
+2590716, 2590712, 2590708, 2590704, 2590700, 2590696, 2590692, 2590688, 2590684, 2590680,
+2590676, 2590672, 2590668, 2590664, 2590660, 2590656, 2590652, 2590648, 2590644, 2590640,
+2590636, 2590632, 2590628, 2590624, 2590620, 2590616, 2590612, 2590608, 2590604, 2590600,
+2590596, 2590592, 2590588, 2590584, 2590580, 2590576, 2590572, 2590568, 2590564, 2590560,
+2590556, 2590552, 2590548, 2590544, 2590540, 2590536, 2590532, 2590528, 2590524, 2590520,
+2590516, 2590512, 2590508, 2590504, 2590500, 2590496, 2590492, 2590488, 2590484, 2590480,
+2590476, 2590472, 2590468, 2590464, 2590460, 2590456, 2590452, 2590448, 2590444, 2590440,
+2590436, 2590432, 2590428, 2590424, 2590420, 2590416, 2590412, 2590408, 2590404, 2590400,
+2590396, 2590392, 2590388, 2590384, 2590380, 2590376, 2590372, 2590368, 2590364, 2590360,
+2590356, 2590352, 2590348, 2590344, 2590340, 2590336, 2590332, 2590328, 2590324, 2590320,
+2590316, 2590312, 2590308, 2590304, 2590300, 2590296, 2590292, 2590288, 2590284, 2590280,
+2590276, 2590272, 2590268, 2590264, 2590260, 2590256, 2590252, 2590248, 2590244, 2590240,
+2590236, 2590232, 2590228, 2590224, 2590220, 2590216, 2590212, 2590208, 2590204, 2590200,
+2590196, 2590192, 2590188, 2590184, 2590180, 2590176, 2590172, 2590168, 2590164, 2590160,
+2590156, 2590152, 2590148, 2590144, 2590140, 2590136, 2590132, 2590128, 2590124, 2590120,
+2590116, 2590112, 2590108, 2590104, 2590100, 2590096, 2590092, 2590088, 2590084, 2590080,
+2590076, 2590072, 2590068, 2590064, 2590060, 2590056, 2590052, 2590048, 2590044, 2590040,
+2590036, 2590032, 2590028, 2590024, 2590020, 2590016, 2590012, 2590008, 2590004, 2590000,
+2589996, 2589992, 2589988, 2589984, 2589980, 2589976, 2589972, 2589968, 2589964, 2589960,
+2589956, 2589952, 2589948, 2589944, 2589940, 2589936, 2589932, 2589928, 2589924, 2589920,
+2589916, 2589912, 2589908, 2589904, 2589900, 2589896, 2589892, 2589888, 2589884, 2589880,
+2589876, 2589872, 2589868, 2589864, 2589860, 2589856, 2589852, 2589848, 2589844, 2589840,
+2589836, 2589832, 2589828, 2589824, 2589820, 2589816, 2589812, 2589808, 2589804, 2589800,
+2589796, 2589792, 2589788, 2589784, 2589780, 2589776, 2589772, 2589768, 2589764, 2589760,
+2589756, 2589752, 2589748, 2589744, 2589740, 2589736, 2589732, 2589728, 2589724, 2589720,
+2589716, 2589712, 2589708, 2589704, 2589700, 2589696, 2589692, 2589688, 2589684, 2589680,
+2589676, 2589672, 2589668, 2589664, 2589660, 2589656, 2589652, 2589648, 2589644, 2589640,
+2589636, 2589632, 2589628, 2589624, 2589620, 2589616, 2589612, 2589608, 2589604, 2589600,
+2589596, 2589592, 2589588, 2589584, 2589580, 2589576, 2589572, 2589568, 2589564, 2589560,
+2589556, 2589552, 2589548, 2589544, 2589540, 2589536, 2589532, 2589528, 2589524, 2589520,
+2589516, 2589512, 2589508, 2589504, 2589500, 2589496, 2589492, 2589488, 2589484, 2589480,
+2589476, 2589472, 2589468, 2589464, 2589460, 2589456, 2589452, 2589448, 2589444, 2588424,
+2587404, 2586384, 2585364, 2584344, 2583324, 2582304, 2581284, 2580264, 2579244, 2579180,
+2579116, 2579052, 2578988, 2578924, 2578860, 2578796, 2578732, 2578668, 2578604, 2578540,
+2578476, 2578412, 2578348, 2578284, 2578220, 2578156, 2578092, 2578028, 2577964, 2577900,
+2577836, 2577772, 2577708, 2577644, 2577580, 2577516, 2577452, 2577388, 2577324, 2577260,
+2577196, 2577132, 2577068, 2577004, 2576940, 2576876, 2576812, 2576748, 2576684, 2576620,
+2576556, 2576492, 2576428, 2576364, 2576300, 2576236, 2576172, 2576108, 2576044, 2575980,
+2575916, 2575852, 2575788, 2575724, 2575660, 2575596, 2575532, 2575468, 2575404, 2575340,
+2575276, 2575212, 2575148, 2575084, 2575020, 2574956, 2574892, 2574828, 2574764, 2574700,
+2574636, 2574572, 2574508, 2574444, 2574380, 2574316, 2574252, 2574188, 2574124, 2574060,
+2573996, 2573932, 2573868, 2573804, 2573740, 2573676, 2573612, 2573548, 2573484, 2573420,
+2573356, 2573292, 2573228, 2573164, 2573100, 2573036, 2572972, 2572908, 2572844, 2572780,
+2572716, 2572652, 2572588, 2572524, 2572460, 2572396, 2572332, 2572268, 2572204, 2572140,
+2572076, 2572012, 2571948, 2571884, 2571820, 2571756, 2571692, 2571628, 2571564, 2571500,
+2571436, 2571372, 2571308, 2571244, 2571180, 2571116, 2571052, 2570988, 2570924, 2570860,
+2570796, 2570732, 2570668, 2570604, 2570540, 2570476, 2570412, 2570348, 2570284, 2570220,
+2570156, 2570092, 2570028, 2569964, 2569900, 2569836, 2569772, 2569708, 2569644, 2569580,
+2569516, 2569452, 2569388, 2569324, 2569260, 2569196, 2569132, 2569068, 2569004, 2568940,
+2568876, 2568812, 2568748, 2568684, 2568620, 2568556, 2568492, 2568428, 2568364, 2568300,
+2568236, 2568172, 2568108, 2568044, 2567980, 2567916, 2567852, 2567788, 2567724, 2567660,
+2567596, 2567532, 2567468, 2567404, 2567340, 2567276, 2567212, 2567148, 2567084, 2567020,
+2566956, 2566892, 2566828, 2566764, 2566700, 2566636, 2566572, 2566508, 2566444, 2566380,
+2566316, 2566252, 2566188, 2566124, 2566060, 2565996, 2565932, 2565868, 2565804, 2565740,
+2565676, 2565612, 2565548, 2565484, 2565420, 2565356, 2565292, 2565228, 2565164, 2565100,
+2565036, 2564972, 2564908, 2564844, 2564780, 2564716, 2564652, 2564588, 2564524, 2564460,
+2564396, 2564332, 2564268, 2564204, 2564140, 2564076, 2564012, 2563948, 2563884, 2563820,
+2563756, 2563692, 2563628, 2563564, 2563500, 2563436, 2563372, 2563308, 2563244, 2563180,
+2563116, 2563052, 2562988, 2562924, 2562860, 2562796, 2562732, 2562668, 2562604, 2562540,
+2562476, 2562412, 2562348, 2562284, 2562220, 2562156, 2562092, 2562028, 2561964, 2561900,
+2561836, 2561772, 2561708, 2561644, 2561580, 2561516, 2561452, 2561388, 2561324, 2561260,
+2561196, 2561132, 2561068, 2561004, 2560940, 2560876, 2560812, 2560748, 2560684, 2560620,
+2560556, 2560492, 2560428, 2560364, 2560300, 2560236, 2560172, 2560108, 2560044, 2559980,
+2559916, 2559852, 2559788, 2559724, 2559660, 2559596, 2559532, 2559468, 2559404, 2559340,
+2559276, 2559212, 2559148, 2559084, 2559020, 2558956, 2558892, 2558828, 2558764, 2558700,
+2558636, 2558572, 2558508, 2558444, 2558380, 2558316, 2558252, 2558188, 2558124, 2558060,
+2557996, 2557932, 2557868, 2557804, 2557740, 2557676, 2557612, 2557548, 2557484, 2557420,
+2557356, 2557292, 2557228, 2557164, 2557100, 2557036, 2556972, 2556908, 2556844, 2556780,
+2556716, 2556652, 2556588, 2556524, 2556460, 2556396, 2556332, 2556268, 2556204, 2556140,
+2556076, 2556012, 2555948, 2555884, 2555820, 2555756, 2555692, 2555628, 2555564, 2555500,
+2555436, 2555372, 2555308, 2555244, 2555180, 2555116, 2555052, 2554988, 2554924, 2554860,
+2554796, 2554732, 2554668, 2554604, 2554540, 2554476, 2554412, 2554348, 2554284, 2554220,
+2554156, 2554092, 2554028, 2553964, 2553900, 2553836, 2553772, 2553708, 2553644, 2553580,
+2553516, 2553452, 2553388, 2553324, 2553260, 2553196, 2553132, 2553068, 2553004, 2552940,
+2552876, 2552812, 2552748, 2552684, 2552620, 2552556, 2552492, 2552428, 2552364, 2552300,
+2552236, 2552172, 2552108, 2552044, 2551980, 2551916, 2551852, 2551788, 2551724, 2551660,
+2551596, 2551532, 2551468, 2551404, 2551340, 2551276, 2551212, 2551148, 2551084, 2551020,
+2550956, 2550892, 2550828, 2550764, 2550700, 2550636, 2550572, 2550508, 2550444, 2550380,
+2550316, 2550252, 2550188, 2550124, 2550060, 2549996, 2549932, 2549868, 2549804, 2549740,
+2549676, 2549612, 2549548, 2549484, 2549420, 2549356, 2549292, 2549228, 2549164, 2549100,
+2549036, 2548972, 2548908, 2548844, 2548780, 2548716, 2548652, 2548588, 2548524, 2548460,
+2548396, 2548332, 2548268, 2548204, 2548140, 2548076, 2548012, 2547948, 2547884, 2547820,
+2547756, 2547692, 2547628, 2547564, 2547500, 2547436, 2547372, 2547308, 2547244, 2547180,
+2547116, 2547052, 2546988, 2546924, 2546860, 2546796, 2546732, 2546668, 2546604, 2546540,
+2546476, 2546412, 2546348, 2546284, 2546220, 2546156, 2546092, 2546028, 2545964, 2545900,
+2545836, 2545772, 2545708, 2545644, 2545580, 2545516, 2545452, 2545388, 2545324, 2545260,
+2545196, 2545132, 2545068, 2545004, 2544940, 2544876, 2544812, 2544748, 2544684, 2544620,
+2544556, 2544492, 2544428, 2544364, 2544300, 2544236, 2544172, 2544108, 2544044, 2543980,
+2543916, 2543852, 2543788, 2543724, 2543660, 2543596, 2543532, 2543468, 2543404, 2543340,
+2543276, 2543212, 2543148, 2543084, 2543020, 2542956, 2542892, 2542828, 2542764, 2542700,
+2542636, 2542572, 2542508, 2542444, 2542380, 2542316, 2542252, 2542188, 2542124, 2542060,
+2541996, 2541932, 2541868, 2541804, 2541740, 2541676, 2541612, 2541548, 2541484, 2541420,
+2541356, 2541292, 2541228, 2541164, 2541100, 2541036, 2540972, 2540908, 2540844, 2540780,
+2540716, 2540652, 2540588, 2540524, 2540460, 2540396, 2540332, 2540268, 2540204, 2540140,
+2540076, 2540012, 2539948, 2539884, 2539820, 2539756, 2539692, 2539628, 2539564, 2539500,
+2539436, 2539372, 2539308, 2539244, 2539180, 2539116, 2539052, 2538988, 2538924, 2538860,
+2538796, 2538732, 2538668, 2538604, 2538540, 2538476, 2538412, 2538348, 2538284, 2538220,
+2538156, 2538092, 2538028, 2537964, 2537900, 2537836, 2537772, 2537708, 2537644, 2537580,
+2537516, 2537452, 2537388, 2537324, 2537260, 2537196, 2537132, 2537068, 2537004, 2536940,
+2536876, 2536812, 2536748, 2536684, 2536620, 2536556, 2536492, 2536428, 2536364, 2536300,
+2536236, 2536172, 2536108, 2536044, 2535980, 2535916, 2535852, 2535788, 2535724, 2535660,
+2535596, 2535532, 2535468, 2535404, 2535340, 2535276, 2535212, 2535148, 2535084, 2535020,
+2534956, 2534892, 2534828, 2534764, 2534700, 2534636, 2534572, 2534508, 2534444, 2534380,
+2534316, 2534252, 2534188, 2534124, 2534060, 2533996, 2533932, 2533868, 2533804, 2533740,
+2533676, 2533612, 2533548, 2533484, 2533420, 2533356, 2533292, 2533228, 2533164, 2533100,
+2533036, 2532972, 2532908, 2532844, 2532780, 2532716, 2532652, 2532588, 2532524, 2532460,
+2532396, 2532332, 2532268, 2532204, 2532140, 2532076, 2532012, 2531948, 2531884, 2531820,
+2531756, 2531692, 2531628, 2531564, 2531500, 2531436, 2531372, 2531308, 2531244, 2531180,
+2531116, 2531052, 2530988, 2530924, 2530860, 2530796, 2530732, 2530668, 2530604, 2530540,
+2530476, 2530412, 2530348, 2530284, 2530220, 2530156, 2530092, 2530028, 2529964, 2529900,
+2529836, 2529772, 2529708, 2529644, 2529580, 2529516, 2529452, 2529388, 2529324, 2529260,
+2529196, 2529132, 2529068, 2529004, 2528940, 2528876, 2528812, 2528748, 2528684, 2528620,
+2528556, 2528492, 2528428, 2528364, 2528300, 2528236, 2528172, 2528108, 2528044, 2527980,
+2527916, 2527852, 2527788, 2527724, 2527660, 2527596, 2527532, 2527468, 2527404, 2527340,
+2527276, 2527212, 2527148, 2527084, 2527020, 2526956, 2526892, 2526828, 2526764, 2526700,
+2526636, 2526572, 2526508, 2526444, 2526380, 2526316, 2526252, 2526188, 2526124, 2526060,
+2525996, 2525932, 2525868, 2525804, 2525740, 2525676, 2525612, 2525548, 2525484, 2525420,
+2525356, 2525292, 2525228, 2525164, 2525100, 2525036, 2524972, 2524908, 2524844, 2524780,
+2524716, 2524652, 2524588, 2524524, 2524460, 2524396, 2524332, 2524188, 2524044, 2523900,
+2523756, 2523612, 2523468, 2523324, 2523180, 2523036, 2522892, 2522748, 2522604, 2522460,
+2522316, 2522172, 2522028, 2521884, 2521740, 2521596, 2521452, 2521308, 2521164, 2521020,
+2520876, 2520732, 2520588, 2520444, 2520300, 2520156, 2520012, 2519868, 2519724, 2519580,
+2519436, 2519292, 2519148, 2519004, 2518860, 2518716, 2518572, 2518428, 2518284, 2518140,
+2517996, 2517852, 2517708, 2517564, 2517420, 2517276, 2517132, 2516988, 2516844, 2516700,
+2516556, 2516412, 2516268, 2516124, 2515980, 2515836, 2515692, 2515548, 2515404, 2515260,
+2515116, 2514972, 2514828, 2514684, 2514540, 2514396, 2514252, 2514108, 2513964, 2513820,
+2513676, 2513532, 2513388, 2513244, 2513100, 2512956, 2512812, 2512668, 2512524, 2512380,
+2512236, 2512092, 2511948, 2511804, 2511660, 2511516, 2511372, 2511228, 2511084, 2510940,
+2510796, 2510652, 2510508, 2510364, 2510220, 2510076, 2509932, 2509788, 2509644, 2509500,
+2509356, 2509212, 2509068, 2508924, 2508780, 2508636, 2508492, 2508348, 2508204, 2508060,
+2507916, 2507772, 2507628, 2507484, 2507340, 2507196, 2507052, 2506908, 2506764, 2506620,
+2506476, 2506332, 2506188, 2506044, 2505900, 2505756, 2505612, 2505468, 2505324, 2505180,
+2505036, 2504892, 2504748, 2504604, 2504460, 2504316, 2504172, 2504028, 2503884, 2503740,
+2503596, 2503452, 2503308, 2503164, 2503020, 2502876, 2502732, 2502588, 2502444, 2502300,
+2502156, 2502012, 2501868, 2501724, 2501580, 2501436, 2501292, 2501148, 2501004, 2500860,
+2500716, 2500572, 2500428, 2500284, 2500140, 2499996, 2499852, 2499708, 2499564, 2499420,
+2499276, 2499132, 2498988, 2498844, 2498700, 2498556, 2498412, 2498268, 2498124, 2497980,
+2497836, 2497692, 2497548, 2497404, 2497260, 2497116, 2496972, 2496828, 2496684, 2496540,
+2496396, 2496252, 2496108, 2495964, 2495820, 2495676, 2495532, 2495388, 2495244, 2495100,
+2494956, 2494812, 2494668, 2494524, 2494380, 2494236, 2494092, 2493948, 2493804, 2493660,
+2493516, 2493372, 2493228, 2493084, 2492940, 2492796, 2492652, 2492508, 2492364, 2492220,
+2492076, 2491932, 2491788, 2491644, 2491500, 2491356, 2491212, 2491068, 2490924, 2490780,
+2490636, 2490492, 2490348, 2490204, 2490060, 2489916, 2489772, 2489628, 2489484, 2489340,
+2489196, 2489052, 2488908, 2488764, 2488620, 2488476, 2488332, 2488188, 2488044, 2487900,
+2487756, 2487612, 2487468, 2487324, 2487180, 2487036, 2486892, 2486748, 2486604, 2486460,
+2486316, 2486172, 2486028, 2485884, 2485740, 2485596, 2485452, 2485308, 2485164, 2485020,
+2484876, 2484732, 2484588, 2484444, 2484300, 2484156, 2484012, 2483868, 2483724, 2483580,
+2483436, 2483292, 2483148, 2483004, 2482860, 2482716, 2482572, 2482428, 2482284, 2482140,
+2481996, 2481852, 2481708, 2481564, 2481420, 2481276, 2481132, 2480988, 2480844, 2480700,
+2480556, 2480412, 2480268, 2480124, 2479980, 2479836, 2479692, 2479548, 2479404, 2479260,
+2479116, 2478972, 2478828, 2478684, 2478540, 2478396, 2478252, 2478108, 2477964, 2477820,
+2477676, 2477532, 2477388, 2477244, 2477100, 2476956, 2476812, 2476668, 2476524, 2476380,
+2476236, 2476092, 2475948, 2475804, 2475660, 2475516, 2475372, 2475228, 2475084, 2474940,
+2474796, 2474652, 2474508, 2474364, 2474220, 2474076, 2473932, 2473788, 2473644, 2473500,
+2473356, 2473212, 2473068, 2472924, 2472780, 2472636, 2472492, 2472348, 2472204, 2472060,
+2471916, 2471772, 2471628, 2471484, 2471340, 2471196, 2471052, 2470908, 2470764, 2470620,
+2470476, 2470332, 2470188, 2470044, 2469900, 2469756, 2469612, 2469468, 2469324, 2469180,
+2469036, 2468892, 2468748, 2468604, 2468460, 2468316, 2468172, 2468028, 2467884, 2467740,
+2467596, 2467452, 2467308, 2467164, 2467020, 2466876, 2466732, 2466588, 2466444, 2466300,
+2466156, 2466012, 2465868, 2465724, 2465580, 2465436, 2465292, 2465148, 2465004, 2464860,
+2464716, 2464572, 2464428, 2464284, 2464140, 2463996, 2463852, 2463708, 2463564, 2463420,
+2463276, 2463132, 2462988, 2462844, 2462700, 2462556, 2462412, 2462268, 2462124, 2461980,
+2461836, 2461692, 2461548, 2461404, 2461260, 2461116, 2460972, 2460828, 2460684, 2460540,
+2460396, 2460252, 2460108, 2459964, 2459820, 2459676, 2459532, 2459388, 2459244, 2459100,
+2458956, 2458812, 2458668, 2458524, 2458380, 2458236, 2458092, 2457948, 2457804, 2457660,
+2457516, 2457372, 2457228, 2457084, 2456940, 2456796, 2456652, 2456508, 2456364, 2456220,
+2456076, 2455932, 2455788, 2455644, 2455500, 2455356, 2455212, 2455068, 2454924, 2454780,
+2454636, 2454492, 2454348, 2454204, 2454060, 2453916, 2453772, 2453628, 2453484, 2453340,
+2453196, 2453052, 2452908, 2452764, 2452620, 2452476, 2452332, 2452188, 2452044, 2451900,
+2451756, 2451612, 2451468, 2451324, 2451180, 2451036, 2450892, 2450748, 2450604, 2450460,
+2450316, 2450172, 2450028, 2449884, 2449740, 2449596, 2449452, 2449308, 2449164, 2449020,
+2448876, 2448732, 2448588, 2448444, 2448300, 2448156, 2448012, 2447868, 2447724, 2447580,
+2447436, 2447292, 2447148, 2447004, 2446860, 2446716, 2446572, 2446428, 2446284, 2446140,
+2445996, 2445852, 2445708, 2445564, 2445420, 2445276, 2445132, 2444988, 2444844, 2444700,
+2444556, 2444412, 2444268, 2444124, 2443980, 2443836, 2443692, 2443548, 2443404, 2443260,
+2443116, 2442972, 2442828, 2442684, 2442540, 2442396, 2442252, 2442108, 2441964, 2441820,
+2441676, 2441532, 2441388, 2441244, 2441100, 2440956, 2440812, 2440668, 2440524, 2440380,
+2440236, 2440092, 2439948, 2439804, 2439660, 2439516, 2439372, 2439228, 2439084, 2438940,
+2438796, 2438652, 2438508, 2438364, 2438220, 2438076, 2437932, 2437788, 2437644, 2437500,
+2437356, 2437212, 2437068, 2436924, 2436780, 2436636, 2436492, 2436348, 2436204, 2436060,
+2435916, 2435772, 2435628, 2435484, 2435340, 2435196, 2435052, 2434908, 2434764, 2434620,
+2434476, 2434332, 2434188, 2434044, 2433900, 2433756, 2433612, 2433468, 2433324, 2433180,
+2433036, 2432892, 2432748, 2432604, 2432460, 2432316, 2432172, 2432028, 2431884, 2431740,
+2431596, 2431452, 2431308, 2431164, 2431020, 2430876, 2430732, 2430588, 2430444, 2430300,
+2430156, 2430012, 2429868, 2429724, 2429580, 2429436, 2429292, 2429148, 2429004, 2428860,
+2428716, 2428572, 2428428, 2428284, 2428140, 2427996, 2427852, 2427708, 2427564, 2427420,
+2427276, 2427132, 2426988, 2426844, 2426700, 2426556, 2426412, 2426268, 2426124, 2425980,
+2425836, 2425692, 2425548, 2425404, 2425260, 2425116, 2424972, 2424828, 2424684, 2424540,
+2424396, 2424252, 2424108, 2423964, 2423820, 2423676, 2423532, 2423388, 2423244, 2423100,
+2422956, 2422812, 2422668, 2422524, 2422380, 2422236, 2422092, 2421948, 2421804, 2421660,
+2421516, 2421372, 2421228, 2421084, 2420940, 2420796, 2420652, 2420508, 2420364, 2420220,
+2420076, 2419932, 2419788, 2419644, 2419500, 2419356, 2419212, 2419068, 2418924, 2418780,
+2418636, 2418492, 2418348, 2418204, 2418060, 2417916, 2417772, 2417628, 2417484, 2417340,
+2417196, 2417052, 2416908, 2416764, 2416620, 2416476, 2416332, 2416188, 2416044, 2415900,
+2415756, 2415612, 2415468, 2415324, 2415180, 2415036, 2414892, 2414748, 2414604, 2414460,
+2414316, 2414172, 2414028, 2413884, 2413740, 2413596, 2413452, 2413308, 2413164, 2413020,
+2412876, 2412732, 2412588, 2412444, 2412300, 2412156, 2412012, 2411868, 2411724, 2411580,
+2411436, 2411292, 2411148, 2411004, 2410860, 2410716, 2410572, 2410428, 2410284, 2410140,
+2409996, 2409852, 2409708, 2409564, 2409420, 2409276, 2409132, 2408988, 2408844, 2408700,
+2408556, 2408412, 2408268, 2408124, 2407980, 2407836, 2407692, 2407548, 2407404, 2407260,
+2407116, 2406972, 2406828, 2406684, 2406540, 2406396, 2406252, 2406108, 2405964, 2405820,
+2405676, 2405532, 2405388, 2405244, 2405100, 2404956, 2404812, 2404668, 2404524, 2404380,
+2404236, 2404092, 2403948, 2403804, 2403660, 2403516, 2403372, 2403228, 2403084, 2402940,
+2402796, 2402652, 2402508, 2402364, 2402220, 2402076, 2401932, 2401788, 2401644, 2401500,
+2401356, 2401212, 2401068, 2400924, 2400780, 2400396, 2400012, 2399628, 2399244, 2398860,
+2398476, 2398092, 2397708, 2397324, 2396940, 2396556, 2396172, 2395788, 2395404, 2395020,
+2394636, 2394252, 2393868, 2393484, 2393100, 2392716, 2392332, 2391948, 2391564, 2391180,
+2390796, 2390412, 2390028, 2389644, 2389260, 2388876, 2388492, 2388108, 2387724, 2387340,
+2386956, 2386572, 2386188, 2385804, 2385420, 2385036, 2384652, 2384268, 2383884, 2383500,
+2383116, 2382732, 2382348, 2381964, 2381580, 2381196, 2380812, 2380428, 2380044, 2379660,
+2379276, 2378892, 2378508, 2378124, 2377740, 2377356, 2376972, 2376588, 2376204, 2375820,
+2375436, 2375052, 2374668, 2374284, 2373900, 2373516, 2373132, 2372748, 2372364, 2371980,
+2371596, 2371212, 2370828, 2370444, 2370060, 2369676, 2369292, 2368908, 2368524, 2368140,
+2367756, 2367372, 2366988, 2366604, 2366220, 2365836, 2365452, 2365068, 2364684, 2364300,
+2363916, 2363532, 2363148, 2362764, 2362380, 2361996, 2361612, 2361228, 2360844, 2360460,
+2360076, 2359692, 2359308, 2358924, 2358540, 2358156, 2357772, 2357388, 2357004, 2356620,
+2356236, 2355852, 2355468, 2355084, 2354700, 2354316, 2353932, 2353548, 2353164, 2352780,
+2352396, 2352012, 2351628, 2351244, 2350860, 2350476, 2350092, 2349708, 2349324, 2348940,
+2348556, 2348172, 2347788, 2347404, 2347020, 2346636, 2346252, 2345868, 2345484, 2345100,
+2344716, 2344332, 2343948, 2343564, 2343180, 2342796, 2342412, 2342028, 2341644, 2341260,
+2340876, 2340492, 2340108, 2339724, 2339340, 2338956, 2338572, 2338188, 2337804, 2337420,
+2337036, 2336652, 2336268, 2335884, 2335500, 2335116, 2334732, 2334348, 2333964, 2333580,
+2333196, 2332812, 2332428, 2332044, 2331660, 2331276, 2330892, 2330508, 2330124, 2329740,
+2329356, 2328972, 2328588, 2328204, 2327820, 2327436, 2327052, 2326668, 2326284, 2325900,
+2325516, 2325132, 2324748, 2324364, 2323980, 2323596, 2323212, 2322828, 2322444, 2322060,
+2321676, 2321292, 2320908, 2320524, 2320140, 2319756, 2319372, 2318988, 2318604, 2318220,
+2317836, 2317452, 2317068, 2316684, 2316300, 2315916, 2315532, 2315148, 2314764, 2314380,
+2313996, 2313612, 2313228, 2312844, 2312460, 2312076, 2311692, 2311308, 2310924, 2310540,
+2310156, 2309772, 2309388, 2309004, 2308620, 2308236, 2307852, 2307468, 2307084, 2306700,
+2306316, 2305932, 2305548, 2305164, 2304780, 2304396, 2304012, 2303628, 2303244, 2302860,
+2302476, 2302092, 2301708, 2301324, 2300940, 2300556, 2300172, 2299788, 2299404, 2299020,
+2298636, 2298252, 2297868, 2297484, 2297100, 2296716, 2296332, 2295948, 2295564, 2295180,
+2294796, 2294412, 2294028, 2293644, 2293260, 2292876, 2292492, 2292108, 2291724, 2291340,
+2290956, 2290572, 2290188, 2289804, 2289420, 2289036, 2288652, 2288268, 2287884, 2287500,
+2287116, 2286732, 2286348, 2285964, 2285580, 2285196, 2284812, 2284428, 2284044, 2283660,
+2283276, 2282892, 2282508, 2282124, 2281740, 2281356, 2280972, 2280588, 2280204, 2279820,
+2279436, 2279052, 2278668, 2278284, 2277900, 2277516, 2277132, 2276748, 2276364, 2275980,
+2275596, 2275212, 2274828, 2274444, 2274060, 2273676, 2273292, 2272908, 2272524, 2272140,
+2271756, 2271372, 2270988, 2270604, 2270220, 2269836, 2269452, 2269068, 2268684, 2268300,
+2267916, 2267532, 2267148, 2266764, 2266380, 2265996, 2265612, 2265228, 2264844, 2264460,
+2264076, 2263692, 2263308, 2262924, 2262540, 2262156, 2261772, 2261388, 2261004, 2260620,
+2260236, 2259852, 2259468, 2259084, 2258700, 2258316, 2257932, 2257548, 2257164, 2256780,
+2256396, 2256012, 2255628, 2255244, 2254860, 2254476, 2254092, 2253708, 2253324, 2252940,
+2252556, 2252172, 2251788, 2251404, 2251020, 2250636, 2250252, 2249868, 2249484, 2249100,
+2248716, 2248332, 2247948, 2247564, 2247180, 2246796, 2246412, 2246028, 2245644, 2245260,
+2244876, 2244492, 2244108, 2243724, 2243340, 2242956, 2242572, 2242188, 2241804, 2241420,
+2241036, 2240652, 2240268, 2239884, 2239500, 2239116, 2238732, 2238348, 2237964, 2237580,
+2237196, 2236812, 2236428, 2236044, 2235660, 2235276, 2234892, 2234508, 2234124, 2233740,
+2233356, 2232972, 2232588, 2232204, 2231820, 2231436, 2231052, 2230668, 2230284, 2229900,
+2229516, 2229132, 2228748, 2228364, 2227980, 2227596, 2227212, 2226828, 2226444, 2226060,
+2225676, 2225292, 2224908, 2224524, 2224140, 2223756, 2223372, 2222988, 2222604, 2222220,
+2221836, 2221452, 2221068, 2220684, 2220300, 2219916, 2219532, 2219148, 2218764, 2218380,
+2217996, 2217612, 2217228, 2216844, 2216460, 2216076, 2215692, 2215308, 2214924, 2214540,
+2214156, 2213772, 2213388, 2213004, 2212620, 2212236, 2211852, 2211468, 2211084, 2210700,
+2210316, 2209932, 2209548, 2209164, 2208780, 2208396, 2208012, 2207628, 2207244, 2206860,
+2206476, 2206092, 2205708, 2205324, 2204940, 2204556, 2204172, 2203788, 2203404, 2203020,
+2202636, 2202252, 2201868, 2201484, 2201100, 2200716, 2200332, 2199948, 2199564, 2199180,
+2198796, 2198412, 2198028, 2197644, 2197260, 2196876, 2196492, 2196108, 2195724, 2195340,
+2194956, 2194572, 2194188, 2193804, 2193420, 2193036, 2192652, 2192268, 2191884, 2191500,
+2191116, 2190732, 2190348, 2189964, 2189580, 2189196, 2188812, 2188428, 2188044, 2187660,
+2187276, 2186892, 2186508, 2186124, 2185740, 2185356, 2184972, 2184588, 2184204, 2183820,
+2183436, 2183052, 2182668, 2182284, 2181900, 2181516, 2181132, 2180748, 2180364, 2179980,
+2179596, 2179212, 2178828, 2178444, 2178060, 2177676, 2177292, 2176908, 2176524, 2176140,
+2175756, 2175372, 2174988, 2174604, 2174220, 2173836, 2173452, 2173068, 2172684, 2172300,
+2171916, 2171532, 2171148, 2170764, 2170380, 2169996, 2169612, 2169228, 2168844, 2168460,
+2168076, 2167692, 2167308, 2166924, 2166540, 2166156, 2165772, 2165388, 2165004, 2164620,
+2164236, 2163852, 2163468, 2163084, 2162700, 2162316, 2161932, 2161548, 2161164, 2160780,
+2160396, 2160012, 2159628, 2159244, 2158860, 2158476, 2158092, 2157708, 2157324, 2156940,
+2156556, 2156172, 2155788, 2155404, 2155020, 2154636, 2154252, 2153868, 2153484, 2153100,
+2152716, 2152332, 2151948, 2151564, 2151180, 2150796, 2150412, 2150028, 2149644, 2149260,
+2148876, 2148492, 2148108, 2147724, 2147340, 2146956, 2146572, 2146188, 2145804, 2145420,
+2145036, 2144652, 2144268, 2143884, 2143500, 2143116, 2142732, 2142348, 2141964, 2141580,
+2141196, 2140812, 2140428, 2140044, 2139660, 2139276, 2138892, 2138508, 2138124, 2137740,
+2137356, 2136972, 2136588, 2136204, 2135820, 2135436, 2135052, 2134668, 2134284, 2133900,
+2133516, 2133132, 2132748, 2132364, 2131980, 2131596, 2131212, 2130828, 2130444, 2130060,
+2129676, 2129292, 2128908, 2128524, 2128140, 2127756, 2127372, 2126988, 2126604, 2126220,
+2125836, 2125452, 2125068, 2124684, 2124300, 2123916, 2123532, 2123148, 2122764, 2122380,
+2121996, 2121612, 2121228, 2120844, 2120460, 2120076, 2119692, 2119308, 2118924, 2118540,
+2118156, 2117772, 2117388, 2117004, 2116620, 2116236, 2115852, 2115468, 2115084, 2114700,
+2114316, 2113932, 2113548, 2113164, 2112780, 2112396, 2112012, 2111628, 2111244, 2110860,
+2110476, 2110092, 2109708, 2109324, 2108940, 2108556, 2108172, 2107788, 2107404, 2107020,
+2106636, 2106252, 2105868, 2105484, 2105100, 2104716, 2104332, 2103948, 2103564, 2103180,
+2102796, 2102412, 2102028, 2101644, 2101260, 2100876, 2100492, 2100108, 2099724, 2099340,
+2098956, 2098572, 2098188, 2097804, 2097420, 2097036, 2096652, 2096268, 2095884, 2095500,
+2095116, 2094732, 2094348, 2093964, 2093580, 2093196, 2092812, 2092428, 2092044, 2091660,
+2091276, 2090892, 2090508, 2090124, 2089740, 2089356, 2088972, 2088588, 2088204, 2087820,
+2087436, 2087052, 2086668, 2086284, 2085900, 2085516, 2085132, 2084748, 2084364, 2083980,
+2083596, 2083212, 2082828, 2082444, 2082060, 2081676, 2081292, 2080908, 2080524, 2080140,
+2079756, 2079372, 2078988, 2078604, 2078220, 2077836, 2077452, 2077068, 2076684, 2076300,
+2075916, 2075532, 2075148, 2074764, 2074380, 2073996, 2073612, 2073228, 2072844, 2072460,
+2072076, 2071692, 2071308, 2070924, 2070540, 2070156, 2069772, 2069388, 2069004, 2068620,
+2068236, 2067852, 2067468, 2067084, 2066700, 2066316, 2065932, 2065548, 2065164, 2064780,
+2064396, 2064012, 2063628, 2063244, 2062860, 2062476, 2062092, 2061708, 2061324, 2060940,
+2060556, 2060172, 2059788, 2059404, 2059020, 2058636, 2058252, 2057868, 2057484, 2057100,
+2056716, 2056332, 2055948, 2055564, 2055180, 2054796, 2054412, 2054028, 2053644, 2053260,
+2052876, 2052492, 2052108, 2051724, 2051340, 2050956, 2050572, 2050188, 2049804, 2049420,
+2049036, 2048652, 2048268, 2047884, 2047500, 2047116, 2046732, 2046348, 2045964, 2045580,
+2045196, 2044812, 2044428, 2044044, 2043660, 2043276, 2042892, 2042508, 2042124, 2041740,
+2041356, 2040972, 2040588, 2040204, 2039820, 2039436, 2039052, 2038668, 2038284, 2037900,
+2037516, 2037132, 2036748, 2036364, 2035980, 2035596, 2035212, 2034828, 2034444, 2034060,
+2033676, 2033292, 2032908, 2032524, 2032140, 2031756, 2031372, 2030988, 2030604, 2030220,
+2029836, 2029452, 2029068, 2028684, 2028300, 2027916, 2027532, 2027148, 2026764, 2026380,
+2025996, 2025612, 2025228, 2024844, 2024460, 2024076, 2023692, 2023308, 2022924, 2022540,
+2022156, 2021772, 2021388, 2021004, 2020620, 2020236, 2019852, 2019468, 2019084, 2018700,
+2018316, 2017932, 2017548, 2017164, 2016780, 2016396, 2016012, 2015628, 2015244, 2014860,
+2014476, 2014092, 2013708, 2013324, 2012940, 2012556, 2012172, 2011788, 2011404, 2011020,
+2010636, 2010252, 2009868, 2009484, 2009100, 2008716, 2008332, 2007948, 2007564, 2007180,
+2006796, 2006412, 2006028, 2005644, 2005260, 2004876, 2004492, 2004108, 2003724, 2003340,
+2002956, 2002572, 2002188, 2001804, 2001420, 2001036, 2000652, 2000268, 1999884, 1999500,
+1999116, 1998732, 1998348, 1997964, 1997580, 1997196, 1996812, 1996428, 1996044, 1995660,
+1995276, 1994892, 1994508, 1994124, 1993740, 1993356, 1992972, 1992588, 1992204, 1991820,
+1991436, 1991052, 1990668, 1990284, 1989900, 1989516, 1989132, 1988748, 1988364, 1987980,
+1987596, 1987212, 1986828, 1986444, 1986060, 1985676, 1985292, 1984908, 1984524, 1984140,
+1983756, 1983372, 1982988, 1982604, 1982220, 1981836, 1981452, 1981068, 1980684, 1980300,
+1979916, 1979532, 1979148, 1978764, 1978380, 1977996, 1977612, 1977228, 1976844, 1976460,
+1976076, 1975692, 1975308, 1974924, 1974540, 1974156, 1973772, 1973388, 1973004, 1972620,
+1972236, 1971852, 1971468, 1971084, 1970700, 1970316, 1969932, 1969548, 1969164, 1968780,
+1968396, 1968012, 1967628, 1967244, 1966860, 1966476, 1966092, 1965708, 1965324, 1964940,
+1964556, 1964172, 1963788, 1963404, 1963020, 1962636, 1962252, 1961868, 1961484, 1961100,
+1960716, 1960332, 1959948, 1959564, 1959180, 1958796, 1958412, 1958028, 1957644, 1957260,
+1956876, 1956492, 1956108, 1955724, 1955340, 1954956, 1954572, 1954188, 1953804, 1953420,
+1953036, 1952652, 1952268, 1951884, 1951500, 1951116, 1950732, 1950348, 1949964, 1949580,
+1949196, 1948812, 1948428, 1948044, 1947660, 1947276, 1946892, 1946508, 1946124, 1945740,
+1945356, 1944972, 1944588, 1944204, 1943820, 1943436, 1943052, 1942668, 1942284, 1941900,
+1941516, 1941132, 1940748, 1940364, 1939980, 1939596, 1939212, 1938828, 1938444, 1938060,
+1937676, 1937292, 1936908, 1936524, 1936140, 1935756, 1935372, 1934988, 1934604, 1934220,
+1933836, 1933452, 1933068, 1932684, 1932300, 1931916, 1931532, 1931148, 1930764, 1930380,
+1929996, 1929612, 1929228, 1928844, 1928460, 1928076, 1927692, 1927308, 1926924, 1926540,
+1926156, 1925772, 1925388, 1925004, 1924620, 1924236, 1923852, 1923468, 1923084, 1922700,
+1922316, 1921932, 1921548, 1921164, 1920780, 1920396, 1920012, 1919628, 1919244, 1918860,
+1918476, 1918092, 1917708, 1917324, 1916940, 1916556, 1916172, 1915788, 1915404, 1915020,
+1914636, 1914252, 1913868, 1913484, 1913100, 1912716, 1912332, 1911948, 1911564, 1911180,
+1910796, 1910412, 1910028, 1909644, 1909260, 1908876, 1908492, 1908108, 1907724, 1907340,
+1906956, 1906572, 1906188, 1905804, 1905420, 1905036, 1904652, 1904268, 1903884, 1903500,
+1903116, 1902732, 1902348, 1901964, 1901580, 1901196, 1900812, 1900428, 1900044, 1899660,
+1899276, 1898892, 1898508, 1898124, 1897740, 1897356, 1896972, 1896588, 1896204, 1895820,
+1895436, 1895052, 1894668, 1894284, 1893900, 1893516, 1893132, 1892748, 1892364, 1891980,
+1891596, 1891212, 1890828, 1890444, 1890060, 1889676, 1889292, 1888908, 1888524, 1888140,
+1887756, 1887372, 1886988, 1886604, 1886220, 1885836, 1885452, 1885068, 1884684, 1884300,
+1883916, 1883532, 1883148, 1882764, 1882380, 1881996, 1881612, 1881228, 1880844, 1880460,
+1880076, 1879692, 1879308, 1878924, 1878540, 1878156, 1877772, 1877388, 1877004, 1876620,
+1876236, 1875852, 1875468, 1875084, 1874700, 1874316, 1873932, 1873548, 1873164, 1872780,
+1872396, 1872012, 1871628, 1871244, 1870860, 1870476, 1870092, 1869708, 1869324, 1868940,
+1868556, 1868172, 1867788, 1867404, 1867020, 1866636, 1866252, 1865868, 1865484, 1865100,
+1864716, 1864332, 1863948, 1863564, 1863180, 1862796, 1862412, 1862028, 1861644, 1861260,
+1860876, 1860492, 1860108, 1859724, 1859340, 1858956, 1858572, 1858188, 1857804, 1857420,
+1857036, 1856652, 1856268, 1855884, 1855500, 1855116, 1854732, 1854348, 1853964, 1853580,
+1853196, 1852812, 1852428, 1852044, 1851660, 1851276, 1850892, 1850508, 1850124, 1849740,
+1849356, 1848972, 1848588, 1848204, 1847820, 1847436, 1847052, 1846668, 1846284, 1845900,
+1845516, 1845132, 1844748, 1844364, 1843980, 1843596, 1843212, 1842828, 1842444, 1842060,
+1841676, 1841292, 1840908, 1840524, 1840140, 1839756, 1839372, 1838988, 1838604, 1838220,
+1837836, 1837452, 1837068, 1836684, 1836300, 1835916, 1835532, 1835148, 1834764, 1834380,
+1833996, 1833612, 1833228, 1832844, 1832460, 1832076, 1831692, 1831308, 1830924, 1830540,
+1830156, 1829772, 1829388, 1829004, 1828620, 1828236, 1827852, 1827468, 1827084, 1826700,
+1826316, 1825932, 1825548, 1825164, 1824780, 1824396, 1824012, 1823628, 1823244, 1822860,
+1822476, 1822092, 1821708, 1821324, 1820940, 1820556, 1820172, 1819788, 1819404, 1819020,
+1818636, 1818252, 1817868, 1817484, 1817100, 1816716, 1816332, 1815948, 1815564, 1815180,
+1814796, 1814412, 1814028, 1813644, 1813260, 1812876, 1812492, 1812108, 1811724, 1811340,
+1810956, 1810572, 1810188, 1809804, 1809420, 1809036, 1808652, 1808268, 1807884, 1807500,
+1807116, 1806732, 1806348, 1805964, 1805580, 1805196, 1804812, 1804428, 1804044, 1803660,
+1803276, 1802892, 1802508, 1802124, 1801740, 1801356, 1800972, 1800588, 1800204, 1799820,
+1799436, 1799052, 1798668, 1798284, 1797900, 1797516, 1797132, 1796748, 1796364, 1795980,
+1795596, 1795212, 1794828, 1794444, 1794060, 1793676, 1793292, 1792908, 1792524, 1792140,
+1791756, 1791372, 1790988, 1790604, 1790220, 1789836, 1789452, 1789068, 1788684, 1788300,
+1787916, 1787532, 1787148, 1786764, 1786380, 1785996, 1785612, 1785228, 1784844, 1784460,
+1784076, 1783692, 1783308, 1782924, 1782540, 1782156, 1781772, 1781388, 1781004, 1780620,
+1780236, 1779852, 1779468, 1779084, 1778700, 1778316, 1777932, 1777548, 1777164, 1776780,
+1776396, 1776012, 1775628, 1775244, 1774860, 1774476, 1774092, 1773708, 1773324, 1772940,
+1772556, 1772172, 1771788, 1771404, 1771020, 1770636, 1770252, 1769868, 1769484, 1769100,
+1768716, 1768332, 1767948, 1767564, 1767180, 1766796, 1766412, 1766028, 1765644, 1765260,
+1764876, 1764492, 1764108, 1763724, 1763340, 1762956, 1762572, 1762188, 1761804, 1761420,
+1761036, 1760652, 1760268, 1759884, 1759500, 1759116, 1758732, 1758348, 1757964, 1757580,
+1757196, 1756812, 1756428, 1756044, 1755660, 1755276, 1754892, 1754508, 1754124, 1753740,
+1753356, 1752972, 1752588, 1752204, 1751820, 1751436, 1751052, 1750668, 1750284, 1749900,
+1749516, 1749132, 1748748, 1748364, 1747980, 1747596, 1747212, 1746828, 1746444, 1746060,
+1745676, 1745292, 1744908, 1744524, 1744140, 1743756, 1743372, 1742988, 1742604, 1742220,
+1741836, 1741452, 1741068, 1740684, 1740300, 1739916, 1739532, 1739148, 1738764, 1738380,
+1737996, 1737612, 1737228, 1736844, 1736460, 1736076, 1735692, 1735308, 1734924, 1734540,
+1734156, 1733772, 1733388, 1733004, 1732620, 1732236, 1731852, 1731468, 1731084, 1730700,
+1730316, 1729932, 1729548, 1729164, 1728780, 1728396, 1728012, 1727628, 1727244, 1726860,
+1726476, 1726092, 1725708, 1725324, 1724940, 1724556, 1724172, 1723788, 1723404, 1723020,
+1722636, 1722252, 1721868, 1721484, 1721100, 1720716, 1720332, 1719948, 1719564, 1719180,
+1718796, 1718412, 1718028, 1717644, 1717260, 1716876, 1716492, 1716108, 1715724, 1715340,
+1714956, 1714572, 1714188, 1713804, 1713420, 1713036, 1712652, 1712268, 1711884, 1711500,
+1711116, 1710732, 1710348, 1709964, 1709580, 1709196, 1708812, 1708428, 1708044, 1707660,
+1707276, 1706892, 1706508, 1706124, 1705740, 1705356, 1704972, 1704588, 1704204, 1703820,
+1703436, 1703052, 1702668, 1702284, 1701900, 1701516, 1701132, 1700748, 1700364, 1699980,
+1699596, 1699212, 1698828, 1698444, 1698060, 1697676, 1697292, 1696908, 1696524, 1696140,
+1695756, 1695372, 1694988, 1694604, 1694220, 1693836, 1693452, 1693068, 1692684, 1692300,
+1691916, 1691532, 1691148, 1690764, 1690380, 1689996, 1689612, 1689228, 1688844, 1688460,
+1688076, 1687692, 1687308, 1686924, 1686540, 1686156, 1685772, 1685388, 1685004, 1684620,
+1684236, 1683852, 1683468, 1683084, 1682700, 1682316, 1681932, 1681548, 1681164, 1680780,
+1680396, 1680012, 1679628, 1679244, 1678860, 1678476, 1678092, 1677708, 1677324, 1676940,
+1676556, 1676172, 1675788, 1675404, 1675020, 1674636, 1674252, 1673868, 1673484, 1673100,
+1672716, 1672332, 1671948, 1671564, 1671180, 1670796, 1670412, 1670028, 1669644, 1669260,
+1668876, 1668492, 1668108, 1667724, 1667340, 1666956, 1666572, 1666188, 1665804, 1665420,
+1665036, 1664652, 1664268, 1663884, 1663500, 1663116, 1662732, 1662348, 1661964, 1661580,
+1661196, 1660812, 1660428, 1660044, 1659660, 1659276, 1658892, 1658508, 1658124, 1657740,
+1657356, 1656972, 1656588, 1656204, 1655820, 1655436, 1655052, 1654668, 1654284, 1653900,
+1653516, 1653132, 1652748, 1652364, 1651980, 1651596, 1651212, 1650828, 1650444, 1650060,
+1649676, 1649292, 1648908, 1648524, 1648140, 1647756, 1647372, 1646988, 1646604, 1646220,
+1645836, 1645452, 1645068, 1644684, 1644300, 1643916, 1643532, 1643148, 1642764, 1642380,
+1641996, 1641612, 1641228, 1640844, 1640460, 1640076, 1639692, 1639308, 1638924, 1638540,
+1638156, 1637772, 1637388, 1637004, 1636620, 1636236, 1635852, 1635468, 1635084, 1634700,
+1634316, 1633932, 1633548, 1633164, 1632780, 1632396, 1632012, 1631628, 1631244, 1630860,
+1630476, 1630092, 1629708, 1629324, 1628940, 1628556, 1628172, 1627788, 1627404, 1627020,
+1626636, 1626252, 1625868, 1625484, 1625100, 1624716, 1624332, 1623948, 1623564, 1623180,
+1622796, 1622412, 1622028, 1621644, 1621260, 1620876, 1620492, 1620108, 1619724, 1619340,
+1618956, 1618572, 1618188, 1617804, 1617420, 1617036, 1616652, 1616268, 1615884, 1615500,
+1615116, 1614732, 1614348, 1613964, 1613580, 1613196, 1612812, 1612428, 1612044, 1611660,
+1611276, 1610892, 1610508, 1610124, 1609740, 1609356, 1608972, 1608588, 1608204, 1607820,
+1607436, 1607052, 1606668, 1606284, 1605900, 1605516, 1605132, 1604748, 1604364, 1603980,
+1603596, 1603212, 1602828, 1602444, 1602060, 1601676, 1601292, 1600908, 1600524, 1600140,
+1599756, 1599372, 1598988, 1598604, 1598220, 1597836, 1597452, 1597068, 1596684, 1596300,
+1595916, 1595532, 1595148, 1594764, 1594380, 1593996, 1593612, 1593228, 1592844, 1592460,
+1592076, 1591692, 1591308, 1590924, 1590540, 1590156, 1589772, 1589388, 1589004, 1588620,
+1588236, 1587852, 1587468, 1587084, 1586700, 1586316, 1585932, 1585548, 1585164, 1584780,
+1584396, 1584012, 1583628, 1583244, 1582860, 1582476, 1582092, 1581708, 1581324, 1580940,
+1580556, 1580172, 1579788, 1579404, 1579020, 1578636, 1578252, 1577868, 1577484, 1577100,
+1576716, 1576332, 1575948, 1575564, 1575180, 1574796, 1574412, 1574028, 1573644, 1573260,
+1572876, 1572492, 1572108, 1571724, 1571340, 1570956, 1570572, 1570188, 1569804, 1569420,
+1569036, 1568652, 1568268, 1567884, 1567500, 1567116, 1566732, 1566348, 1565964, 1565580,
+1565196, 1564812, 1564428, 1564044, 1563660, 1563276, 1562892, 1562508, 1562124, 1561740,
+1561356, 1560972, 1560588, 1560204, 1559820, 1559436, 1559052, 1558668, 1558284, 1557900,
+1557516, 1557132, 1556748, 1556364, 1555980, 1555596, 1555212, 1554828, 1554444, 1554060,
+1553676, 1553292, 1552908, 1552524, 1552140, 1551756, 1551372, 1550988, 1550604, 1550220,
+1549836, 1549452, 1549068, 1548684, 1548300, 1547916, 1547532, 1547148, 1546764, 1546380,
+1545996, 1545612, 1545228, 1544844, 1544460, 1544076, 1543692, 1543308, 1542924, 1542540,
+1542156, 1541772, 1541388, 1541004, 1540620, 1540236, 1539852, 1539468, 1539084, 1538700,
+1538316, 1537932, 1537548, 1537164, 1536780, 1536396, 1536012, 1535628, 1535244, 1534860,
+1534476, 1534092, 1533708, 1533324, 1532940, 1532556, 1532172, 1531788, 1531404, 1531020,
+1530636, 1530252, 1529868, 1529484, 1529100, 1528716, 1528332, 1527948, 1527564, 1527180,
+1526796, 1526412, 1526028, 1525644, 1525260, 1524876, 1524492, 1524108, 1523724, 1523340,
+1522956, 1522572, 1522188, 1521804, 1521420, 1521036, 1520652, 1520268, 1519884, 1519500,
+1519116, 1518732, 1518348, 1517964, 1517580, 1517196, 1516812, 1516428, 1516044, 1515660,
+1515276, 1514892, 1514508, 1514124, 1513740, 1513356, 1512972, 1512588, 1512204, 1511820,
+1511436, 1511052, 1510668, 1510284, 1509900, 1509516, 1509132, 1508748, 1508364, 1507980,
+1507596, 1507212, 1506828, 1506444, 1506060, 1505676, 1505292, 1504908, 1504524, 1504140,
+1503756, 1503372, 1502988, 1502604, 1502220, 1501836, 1501452, 1501068, 1500684, 1500300,
+1499916, 1499532, 1499148, 1498764, 1498380, 1497996, 1497612, 1497228, 1496844, 1496460,
+1496076, 1495692, 1495308, 1494924, 1494540, 1494156, 1493772, 1493388, 1493004, 1492620,
+1492236, 1491852, 1491468, 1491084, 1490700, 1490316, 1489932, 1489548, 1489164, 1488780,
+1488396, 1488012, 1487628, 1487244, 1486860, 1486476, 1486092, 1485708, 1485324, 1484940,
+1484556, 1484172, 1483788, 1483404, 1483020, 1482636, 1482252, 1481868, 1481484, 1481100,
+1480716, 1480332, 1479948, 1479564, 1479180, 1478796, 1478412, 1478028, 1477644, 1477260,
+1476876, 1476492, 1476108, 1475724, 1475340, 1474956, 1474572, 1474188, 1473804, 1473420,
+1473036, 1472652, 1472268, 1471884, 1471500, 1471116, 1470732, 1470348, 1469964, 1469580,
+1469196, 1468812, 1468428, 1468044, 1467660, 1467276, 1466892, 1466508, 1466124, 1465740,
+1465356, 1464972, 1464588, 1464204, 1463820, 1463436, 1463052, 1462668, 1462284, 1461900,
+1461516, 1461132, 1460748, 1460364, 1459980, 1459596, 1459212, 1458828, 1458444, 1458060,
+1457676, 1457292, 1456908, 1456524, 1456140, 1455756, 1455372, 1454988, 1454604, 1454220,
+1453836, 1453452, 1453068, 1452684, 1452300, 1451916, 1451532, 1451148, 1450764, 1450380,
+1449996, 1449612, 1449228, 1448844, 1448460, 1448076, 1447692, 1447308, 1446924, 1446540,
+1446156, 1445772, 1445388, 1445004, 1444620, 1444236, 1443852, 1443468, 1443084, 1442700,
+1442316, 1441932, 1441548, 1441164, 1440780, 1440396, 1440012, 1439628, 1439244, 1438860,
+1438476, 1438092, 1437708, 1437324, 1436940, 1436556, 1436172, 1435788, 1435404, 1435020,
+1434636, 1434252, 1433868, 1433484, 1433100, 1432716, 1432332, 1431948, 1431564, 1431180,
+1430796, 1430412, 1430028, 1429644, 1429260, 1428876, 1428492, 1428108, 1427724, 1427340,
+1426956, 1426572, 1426188, 1425804, 1425420, 1425036, 1424652, 1424268, 1423884, 1423500,
+1423116, 1422732, 1422348, 1421964, 1421580, 1421196, 1420812, 1420428, 1420044, 1419660,
+1419276, 1418892, 1418508, 1418124, 1417740, 1417356, 1416972, 1416588, 1416204, 1415820,
+1415436, 1415052, 1414668, 1414284, 1413900, 1413516, 1413132, 1412748, 1412364, 1411980,
+1411596, 1411212, 1410828, 1410444, 1410060, 1409676, 1409292, 1408908, 1408524, 1408140,
+1407756, 1407372, 1406988, 1406604, 1406220, 1405836, 1405452, 1405068, 1404684, 1404300,
+1403916, 1403532, 1403148, 1402764, 1402380, 1401996, 1401612, 1401228, 1400844, 1400460,
+1400076, 1399692, 1399308, 1398924, 1398540, 1398156, 1397772, 1397388, 1397004, 1396620,
+1396236, 1395852, 1395468, 1395084, 1394700, 1394316, 1393932, 1393548, 1393164, 1392780,
+1392396, 1392012, 1391628, 1391244, 1390860, 1390476, 1390092, 1389708, 1389324, 1388940,
+1388556, 1388172, 1387788, 1387404, 1387020, 1386636, 1386252, 1385868, 1385484, 1385100,
+1384716, 1384332, 1383948, 1383564, 1383180, 1382796, 1382412, 1382028, 1381644, 1381260,
+1380876, 1380492, 1380108, 1379724, 1379340, 1378956, 1378572, 1378188, 1377804, 1377420,
+1377036, 1376652, 1376268, 1375884, 1375500, 1375116, 1374732, 1374348, 1373964, 1373580,
+1373196, 1372812, 1372428, 1372044, 1371660, 1371276, 1370892, 1370508, 1370124, 1369740,
+1369356, 1368972, 1368588, 1368204, 1367820, 1367436, 1367052, 1366668, 1366284, 1365900,
+1365516, 1365132, 1364748, 1364364, 1363980, 1363596, 1363212, 1362828, 1362444, 1362060,
+1361676, 1361292, 1360908, 1360524, 1360140, 1359756, 1359372, 1358988, 1358604, 1358220,
+1357836, 1357452, 1357068, 1356684, 1356300, 1355916, 1355532, 1355148, 1354764, 1354380,
+1353996, 1353612, 1353228, 1352844, 1352460, 1352076, 1351692, 1351308, 1350924, 1350540,
+1350156, 1349772, 1349388, 1349004, 1348620, 1348236, 1347852, 1347468, 1347084, 1346700,
+1346316, 1345932, 1345548, 1345164, 1344780, 1344396, 1344012, 1343628, 1343244, 1342860,
+1342476, 1342092, 1341708, 1341324, 1340940, 1340556, 1340172, 1339788, 1339404, 1339020,
+1338636, 1338252, 1337868, 1337484, 1337100, 1336716, 1336332, 1335948, 1335564, 1335180,
+1334796, 1334412, 1334028, 1333644, 1333260, 1332876, 1332492, 1332108, 1331724, 1331340,
+1330956, 1330572, 1330188, 1329804, 1329420, 1329036, 1328652, 1328268, 1327884, 1327500,
+1327116, 1326732, 1326348, 1325964, 1325580, 1325196, 1324812, 1324428, 1324044, 1323660,
+1323276, 1322892, 1322508, 1322124, 1321740, 1321356, 1320972, 1320588, 1320204, 1319820,
+1319436, 1319052, 1318668, 1318284, 1317900, 1317516, 1317132, 1316748, 1316364, 1315980,
+1315596, 1315212, 1314828, 1314444, 1314060, 1313676, 1313292, 1312908, 1312524, 1312140,
+1311756, 1311372, 1310988, 1310604, 1310220, 1309836, 1309452, 1309068, 1308684, 1308300,
+1307916, 1307532, 1307148, 1306764, 1306380, 1305996, 1305612, 1305228, 1304844, 1304460,
+1304076, 1303692, 1303308, 1302924, 1302540, 1301520, 1300500, 1299480, 1298460, 1297440,
+1296420, 1295400, 1294380, 1293360, 1292340, 1291320, 1290300, 1289280, 1288260, 1287240,
+1286220, 1285200, 1284180, 1283160, 1282140, 1281120, 1280100, 1279080, 1278060, 1277040,
+1276020, 1275000, 1273980, 1272960, 1271940, 1270920, 1269900, 1268880, 1267860, 1266840,
+1265820, 1264800, 1263780, 1262760, 1261740, 1260720, 1259700, 1258680, 1257660, 1256640,
+1255620, 1254600, 1253580, 1252560, 1251540, 1250520, 1249500, 1248480, 1247460, 1246440,
+1245420, 1244400, 1243380, 1242360, 1241340, 1240320, 1239300, 1238280, 1237260, 1236240,
+1235220, 1234200, 1233180, 1232160, 1231140, 1230120, 1229100, 1228080, 1227060, 1226040,
+1225020, 1224000, 1222980, 1221960, 1220940, 1219920, 1218900, 1217880, 1216860, 1215840,
+1214820, 1213800, 1212780, 1211760, 1210740, 1209720, 1208700, 1207680, 1206660, 1205640,
+1204620, 1203600, 1202580, 1201560, 1200540, 1199520, 1198500, 1197480, 1196460, 1195440,
+1194420, 1193400, 1192380, 1191360, 1190340, 1189320, 1188300, 1187280, 1186260, 1185240,
+1184220, 1183200, 1182180, 1181160, 1180140, 1179120, 1178100, 1177080, 1176060, 1175040,
+1174020, 1173000, 1171980, 1170960, 1169940, 1168920, 1167900, 1166880, 1165860, 1164840,
+1163820, 1162800, 1161780, 1160760, 1159740, 1158720, 1157700, 1156680, 1155660, 1154640,
+1153620, 1152600, 1151580, 1150560, 1149540, 1148520, 1147500, 1146480, 1145460, 1144440,
+1143420, 1142400, 1141380, 1140360, 1139340, 1138320, 1137300, 1136280, 1135260, 1134240,
+1133220, 1132200, 1131180, 1130160, 1129140, 1128120, 1127100, 1126080, 1125060, 1124040,
+1123020, 1122000, 1120980, 1119960, 1118940, 1117920, 1116900, 1115880, 1114860, 1113840,
+1112820, 1111800, 1110780, 1109760, 1108740, 1107720, 1106700, 1105680, 1104660, 1103640,
+1102620, 1101600, 1100580, 1099560, 1098540, 1097520, 1096500, 1095480, 1094460, 1093440,
+1092420, 1091400, 1090380, 1089360, 1088340, 1087320, 1086300, 1085280, 1084260, 1083240,
+1082220, 1081200, 1080180, 1079160, 1078140, 1077120, 1076100, 1075080, 1074060, 1073040,
+1072020, 1071000, 1069980, 1068960, 1067940, 1066920, 1065900, 1064880, 1063860, 1062840,
+1061820, 1060800, 1059780, 1058760, 1057740, 1056720, 1055700, 1054680, 1053660, 1052640,
+1051620, 1050600, 1049580, 1048560, 1047540, 1046520, 1045500, 1044480, 1043460, 1042440,
+1041420, 1040400, 1039380, 1038360, 1037340, 1036320, 1035300, 1034280, 1033260, 1032240,
+1031220, 1030200, 1029180, 1028160, 1027140, 1026120, 1025100, 1024080, 1023060, 1022040,
+1021020, 1020000, 1018980, 1017960, 1016940, 1015920, 1014900, 1013880, 1012860, 1011840,
+1010820, 1009800, 1008780, 1007760, 1006740, 1005720, 1004700, 1003680, 1002660, 1001640,
+1000620, 999600, 998580, 997560, 996540, 995520, 994500, 993480, 992460, 991440,
+990420, 989400, 988380, 987360, 986340, 985320, 984300, 983280, 982260, 981240,
+980220, 979200, 978180, 977160, 976140, 975120, 974100, 973080, 972060, 971040,
+970020, 969000, 967980, 966960, 965940, 964920, 963900, 962880, 961860, 960840,
+959820, 958800, 957780, 956760, 955740, 954720, 953700, 952680, 951660, 950640,
+949620, 948600, 947580, 946560, 945540, 944520, 943500, 942480, 941460, 940440,
+939420, 938400, 937380, 936360, 935340, 934320, 933300, 932280, 931260, 930240,
+929220, 928200, 927180, 926160, 925140, 924120, 923100, 922080, 921060, 920040,
+919020, 918000, 916980, 915960, 914940, 913920, 912900, 911880, 910860, 909840,
+908820, 907800, 906780, 905760, 904740, 903720, 902700, 901680, 900660, 899640,
+898620, 897600, 896580, 895560, 894540, 893520, 892500, 891480, 890460, 889440,
+888420, 887400, 886380, 885360, 884340, 883320, 882300, 881280, 880260, 879240,
+878220, 877200, 876180, 875160, 874140, 873120, 872100, 871080, 870060, 869040,
+868020, 867000, 865980, 864960, 863940, 862920, 861900, 860880, 859860, 858840,
+857820, 856800, 855780, 854760, 853740, 852720, 851700, 850680, 849660, 848640,
+847620, 846600, 845580, 844560, 843540, 842520, 841500, 840480, 839460, 838440,
+837420, 836400, 835380, 834360, 833340, 832320, 831300, 830280, 829260, 828240,
+827220, 826200, 825180, 824160, 823140, 822120, 821100, 820080, 819060, 818040,
+817020, 816000, 814980, 813960, 812940, 811920, 810900, 809880, 808860, 807840,
+806820, 805800, 804780, 803760, 802740, 801720, 800700, 799680, 798660, 797640,
+796620, 795600, 794580, 793560, 792540, 791520, 790500, 789480, 788460, 787440,
+786420, 785400, 784380, 783360, 782340, 781320, 780300, 779280, 778260, 777240,
+776220, 775200, 774180, 773160, 772140, 771120, 770100, 769080, 768060, 767040,
+766020, 765000, 763980, 762960, 761940, 760920, 759900, 758880, 757860, 756840,
+755820, 754800, 753780, 752760, 751740, 750720, 749700, 748680, 747660, 746640,
+745620, 744600, 743580, 742560, 741540, 740520, 739500, 738480, 737460, 736440,
+735420, 734400, 733380, 732360, 731340, 730320, 729300, 728280, 727260, 726240,
+725220, 724200, 723180, 722160, 721140, 720120, 719100, 718080, 717060, 716040,
+715020, 714000, 712980, 711960, 710940, 709920, 708900, 707880, 706860, 705840,
+704820, 703800, 702780, 701760, 700740, 699720, 698700, 697680, 696660, 695640,
+694620, 693600, 692580, 691560, 690540, 689520, 688500, 687480, 686460, 685440,
+684420, 683400, 682380, 681360, 680340, 679320, 678300, 677280, 676260, 675240,
+674220, 673200, 672180, 671160, 670140, 669120, 668100, 667080, 666060, 665040,
+664020, 663000, 661980, 660960, 659940, 658920, 657900, 656880, 655860, 654840,
+653820, 652800, 651780, 650760, 649740, 648720, 647700, 646680, 645660, 644640,
+643620, 642600, 641580, 640560, 639540, 638520, 637500, 636480, 635460, 634440,
+633420, 632400, 631380, 630360, 629340, 628320, 627300, 626280, 625260, 624240,
+623220, 622200, 621180, 620160, 619140, 618120, 617100, 616080, 615060, 614040,
+613020, 612000, 610980, 609960, 608940, 607920, 606900, 605880, 604860, 603840,
+602820, 601800, 600780, 599760, 598740, 597720, 596700, 595680, 594660, 593640,
+592620, 591600, 590580, 589560, 588540, 587520, 586500, 585480, 584460, 583440,
+582420, 581400, 580380, 579360, 578340, 577320, 576300, 575280, 574260, 573240,
+572220, 571200, 570180, 569160, 568140, 567120, 566100, 565080, 564060, 563040,
+562020, 561000, 559980, 558960, 557940, 556920, 555900, 554880, 553860, 552840,
+551820, 550800, 549780, 548760, 547740, 546720, 545700, 544680, 543660, 542640,
+541620, 540600, 539580, 538560, 537540, 536520, 535500, 534480, 533460, 532440,
+531420, 530400, 529380, 528360, 527340, 526320, 525300, 524280, 523260, 522240,
+521220, 520200, 519180, 518160, 517140, 516120, 515100, 514080, 513060, 512040,
+511020, 510000, 508980, 507960, 506940, 505920, 504900, 503880, 502860, 501840,
+500820, 499800, 498780, 497760, 496740, 495720, 494700, 493680, 492660, 491640,
+490620, 489600, 488580, 487560, 486540, 485520, 484500, 483480, 482460, 481440,
+480420, 479400, 478380, 477360, 476340, 475320, 474300, 473280, 472260, 471240,
+470220, 469200, 468180, 467160, 466140, 465120, 464100, 463080, 462060, 461040,
+460020, 459000, 457980, 456960, 455940, 454920, 453900, 452880, 451860, 450840,
+449820, 448800, 447780, 446760, 445740, 444720, 443700, 442680, 441660, 440640,
+439620, 438600, 437580, 436560, 435540, 434520, 433500, 432480, 431460, 430440,
+429420, 428400, 427380, 426360, 425340, 424320, 423300, 422280, 421260, 420240,
+419220, 418200, 417180, 416160, 415140, 414120, 413100, 412080, 411060, 410040,
+409020, 408000, 406980, 405960, 404940, 403920, 402900, 401880, 400860, 399840,
+398820, 397800, 396780, 395760, 394740, 393720, 392700, 391680, 390660, 389640,
+388620, 387600, 386580, 385560, 384540, 383520, 382500, 381480, 380460, 379440,
+378420, 377400, 376380, 375360, 374340, 373320, 372300, 371280, 370260, 369240,
+368220, 367200, 366180, 365160, 364140, 363120, 362100, 361080, 360060, 359040,
+358020, 357000, 355980, 354960, 353940, 352920, 351900, 350880, 349860, 348840,
+347820, 346800, 345780, 344760, 343740, 342720, 341700, 340680, 339660, 338640,
+337620, 336600, 335580, 334560, 333540, 332520, 331500, 330480, 329460, 328440,
+327420, 326400, 325380, 324360, 323340, 322320, 321300, 320280, 319260, 318240,
+317220, 316200, 315180, 314160, 313140, 312120, 311100, 310080, 309060, 308040,
+307020, 306000, 304980, 303960, 302940, 301920, 300900, 299880, 298860, 297840,
+296820, 295800, 294780, 293760, 292740, 291720, 290700, 289680, 288660, 287640,
+286620, 285600, 284580, 283560, 282540, 281520, 280500, 279480, 278460, 277440,
+276420, 275400, 274380, 273360, 272340, 271320, 270300, 269280, 268260, 267240,
+266220, 265200, 264180, 263160, 262140, 261120, 260100, 259080, 258060, 257040,
+256020, 255000, 253980, 252960, 251940, 250920, 249900, 248880, 247860, 246840,
+245820, 244800, 243780, 242760, 241740, 240720, 239700, 238680, 237660, 236640,
+235620, 234600, 233580, 232560, 231540, 230520, 229500, 228480, 227460, 226440,
+225420, 224400, 223380, 222360, 221340, 220320, 219300, 218280, 217260, 216240,
+215220, 214200, 213180, 212160, 211140, 210120, 209100, 208080, 207060, 206040,
+205020, 204000, 202980, 201960, 200940, 199920, 198900, 197880, 196860, 195840,
+194820, 193800, 192780, 191760, 190740, 189720, 188700, 187680, 186660, 185640,
+184620, 183600, 182580, 181560, 180540, 179520, 178500, 177480, 176460, 175440,
+174420, 173400, 172380, 171360, 170340, 169320, 168300, 167280, 166260, 165240,
+164220, 163200, 162180, 161160, 160140, 159120, 158100, 157080, 156060, 155040,
+154020, 153000, 151980, 150960, 149940, 148920, 147900, 146880, 145860, 144840,
+143820, 142800, 141780, 140760, 139740, 138720, 137700, 136680, 135660, 134640,
+133620, 132600, 131580, 130560, 129540, 128520, 127500, 126480, 125460, 124440,
+123420, 122400, 121380, 120360, 119340, 118320, 117300, 116280, 115260, 114240,
+113220, 112200, 111180, 110160, 109140, 108120, 107100, 106080, 105060, 104040,
+103020, 102000, 100980, 99960, 98940, 97920, 96900, 95880, 94860, 93840,
+92820, 91800, 90780, 89760, 88740, 87720, 86700, 85680, 84660, 83640,
+82620, 81600, 80580, 79560, 78540, 77520, 76500, 75480, 74460, 73440,
+72420, 71400, 70380, 69360, 68340, 67320, 66300, 65280, 64260, 63240,
+62220, 61200, 60180, 59160, 58140, 57120, 56100, 55080, 54060, 53040,
+52020, 51000, 49980, 48960, 47940, 46920, 45900, 44880, 43860, 42840,
+41820, 40800, 39780, 38760, 37740, 36720, 35700, 34680, 33660, 32640,
+31620, 30600, 29580, 28560, 27540, 26520, 25500, 24480, 23460, 22440,
+21420, 20400, 19380, 18360, 17340, 16320, 15300, 14280, 13260, 12240,
+11220, 10200, 9180, 8160, 7140, 6120, 5100, 4080, 3060, 2040,
+1020, 0,
+];
+
+pub const SEVEN_CARD: &[u32; 7462] = &[
+133780236, 133776096, 133771956, 133767816, 133763676, 133759536, 133755396, 133751256, 133747116, 133742976,
+133738924, 133735560, 133732820, 133730640, 133728956, 133727704, 133726820, 133726240, 133725900, 133725736,
+133725684, 133725680, 133721628, 133718264, 133715524, 133713344, 133711660, 133710408, 133709524, 133708944,
+133708604, 133708440, 133708388, 133708384, 133704332, 133700968, 133698228, 133696048, 133694364, 133693112,
+133692228, 133691648, 133691308, 133691144, 133691092, 133691088, 133687036, 133683672, 133680932, 133678752,
+133677068, 133675816, 133674932, 133674352, 133674012, 133673848, 133673796, 133673792, 133669740, 133666376,
+133663636, 133661456, 133659772, 133658520, 133657636, 133657056, 133656716, 133656552, 133656500, 133656496,
+133652444, 133649080, 133646340, 133644160, 133642476, 133641224, 133640340, 133639760, 133639420, 133639256,
+133639204, 133639200, 133635148, 133631784, 133629044, 133626864, 133625180, 133623928, 133623044, 133622464,
+133622124, 133621960, 133621908, 133621904, 133617852, 133614488, 133611748, 133609568, 133607884, 133606632,
+133605748, 133605168, 133604828, 133604664, 133604612, 133604608, 133600556, 133597192, 133594452, 133592272,
+133590588, 133589336, 133588452, 133587872, 133587532, 133587368, 133587316, 133587312, 133583260, 133579896,
+133577156, 133574976, 133573292, 133572040, 133571156, 133570576, 133570236, 133570072, 133570020, 133570016,
+133565964, 133562600, 133559860, 133557680, 133555996, 133554744, 133553860, 133553280, 133552940, 133552776,
+133552724, 133552720, 133548668, 133545304, 133542564, 133540384, 133538700, 133537448, 133536564, 133535984,
+133535644, 133535480, 133535428, 133535424, 133531372, 133528008, 133525268, 133523088, 133521404, 133520152,
+133519268, 133518688, 133518348, 133518184, 133518132, 133518128, 133494720, 133471456, 133448336, 133425360,
+133402528, 133379840, 133357296, 133334896, 133312640, 133290528, 133268560, 133246736, 133224032, 133200768,
+133177648, 133154672, 133131840, 133109152, 133086608, 133064208, 133041952, 133019840, 132997872, 132976048,
+132953344, 132930784, 132907664, 132884688, 132861856, 132839168, 132816624, 132794224, 132771968, 132749856,
+132727888, 132706064, 132683360, 132660800, 132638384, 132615408, 132592576, 132569888, 132547344, 132524944,
+132502688, 132480576, 132458608, 132436784, 132414080, 132391520, 132369104, 132346832, 132324000, 132301312,
+132278768, 132256368, 132234112, 132212000, 132190032, 132168208, 132145504, 132122944, 132100528, 132078256,
+132056128, 132033440, 132010896, 131988496, 131966240, 131944128, 131922160, 131900336, 131877632, 131855072,
+131832656, 131810384, 131788256, 131766272, 131743728, 131721328, 131699072, 131676960, 131654992, 131633168,
+131610464, 131587904, 131565488, 131543216, 131521088, 131499104, 131477264, 131454864, 131432608, 131410496,
+131388528, 131366704, 131344000, 131321440, 131299024, 131276752, 131254624, 131232640, 131210800, 131189104,
+131166848, 131144736, 131122768, 131100944, 131078240, 131055680, 131033264, 131010992, 130988864, 130966880,
+130945040, 130923344, 130901792, 130879680, 130857712, 130835888, 130813184, 130790624, 130768208, 130745936,
+130723808, 130701824, 130679984, 130658288, 130636736, 130615328, 130593360, 130571536, 130548832, 130526272,
+130503856, 130481584, 130459456, 130437472, 130415632, 130393936, 130372384, 130350976, 130329712, 130307888,
+130285184, 130262624, 130240208, 130217936, 130195808, 130173824, 130151984, 130130288, 130108736, 130087328,
+130066064, 130044944, 130040804, 130036844, 130033060, 130029448, 130026004, 130022724, 130019604, 130016640,
+130012500, 130008540, 130004756, 130001144, 129997700, 129994420, 129991300, 129988336, 129984376, 129980592,
+129976980, 129973536, 129970256, 129967136, 129964172, 129960388, 129956776, 129953332, 129950052, 129946932,
+129943968, 129940356, 129936912, 129933632, 129930512, 129927548, 129924104, 129920824, 129917704, 129914740,
+129911464, 129908344, 129905380, 129902260, 129899296, 129896332, 129892196, 129888236, 129884452, 129880840,
+129877396, 129874116, 129870996, 129868032, 129864072, 129860288, 129856676, 129853232, 129849952, 129846832,
+129843868, 129840084, 129836472, 129833028, 129829748, 129826628, 129823664, 129820052, 129816608, 129813328,
+129810208, 129807244, 129803800, 129800520, 129797400, 129794436, 129791160, 129788040, 129785076, 129781956,
+129778992, 129776028, 129772072, 129768288, 129764676, 129761232, 129757952, 129754832, 129751868, 129748084,
+129744472, 129741028, 129737748, 129734628, 129731664, 129728052, 129724608, 129721328, 129718208, 129715244,
+129711800, 129708520, 129705400, 129702436, 129699160, 129696040, 129693076, 129689956, 129686992, 129684028,
+129680248, 129676636, 129673192, 129669912, 129666792, 129663828, 129660216, 129656772, 129653492, 129650372,
+129647408, 129643964, 129640684, 129637564, 129634600, 129631324, 129628204, 129625240, 129622120, 129619156,
+129616192, 129612584, 129609140, 129605860, 129602740, 129599776, 129596332, 129593052, 129589932, 129586968,
+129583692, 129580572, 129577608, 129574488, 129571524, 129568560, 129565120, 129561840, 129558720, 129555756,
+129552480, 129549360, 129546396, 129543276, 129540312, 129537348, 129534072, 129530952, 129527988, 129524868,
+129521904, 129518940, 129515976, 129513012, 129510048, 129507084, 129503124, 129499164, 129495380, 129491768,
+129488324, 129485044, 129481924, 129478960, 129475000, 129471216, 129467604, 129464160, 129460880, 129457760,
+129454796, 129451012, 129447400, 129443956, 129440676, 129437556, 129434592, 129430980, 129427536, 129424256,
+129421136, 129418172, 129414728, 129411448, 129408328, 129405364, 129402088, 129398968, 129396004, 129392884,
+129389920, 129386956, 129383000, 129379216, 129375604, 129372160, 129368880, 129365760, 129362796, 129359012,
+129355400, 129351956, 129348676, 129345556, 129342592, 129338980, 129335536, 129332256, 129329136, 129326172,
+129322728, 129319448, 129316328, 129313364, 129310088, 129306968, 129304004, 129300884, 129297920, 129294956,
+129291176, 129287564, 129284120, 129280840, 129277720, 129274756, 129271144, 129267700, 129264420, 129261300,
+129258336, 129254892, 129251612, 129248492, 129245528, 129242252, 129239132, 129236168, 129233048, 129230084,
+129227120, 129223512, 129220068, 129216788, 129213668, 129210704, 129207260, 129203980, 129200860, 129197896,
+129194620, 129191500, 129188536, 129185416, 129182452, 129179488, 129176048, 129172768, 129169648, 129166684,
+129163408, 129160288, 129157324, 129154204, 129151240, 129148276, 129145000, 129141880, 129138916, 129135796,
+129132832, 129129868, 129126904, 129123940, 129120976, 129118012, 129114228, 129110444, 129106832, 129103388,
+129100108, 129096988, 129094024, 129090240, 129086628, 129083184, 129079904, 129076784, 129073820, 129070208,
+129066764, 129063484, 129060364, 129057400, 129053956, 129050676, 129047556, 129044592, 129041316, 129038196,
+129035232, 129032112, 129029148, 129026184, 129022404, 129018792, 129015348, 129012068, 129008948, 129005984,
+129002372, 128998928, 128995648, 128992528, 128989564, 128986120, 128982840, 128979720, 128976756, 128973480,
+128970360, 128967396, 128964276, 128961312, 128958348, 128954740, 128951296, 128948016, 128944896, 128941932,
+128938488, 128935208, 128932088, 128929124, 128925848, 128922728, 128919764, 128916644, 128913680, 128910716,
+128907276, 128903996, 128900876, 128897912, 128894636, 128891516, 128888552, 128885432, 128882468, 128879504,
+128876228, 128873108, 128870144, 128867024, 128864060, 128861096, 128858132, 128855168, 128852204, 128849240,
+128845628, 128842016, 128838572, 128835292, 128832172, 128829208, 128825596, 128822152, 128818872, 128815752,
+128812788, 128809344, 128806064, 128802944, 128799980, 128796704, 128793584, 128790620, 128787500, 128784536,
+128781572, 128777964, 128774520, 128771240, 128768120, 128765156, 128761712, 128758432, 128755312, 128752348,
+128749072, 128745952, 128742988, 128739868, 128736904, 128733940, 128730500, 128727220, 128724100, 128721136,
+128717860, 128714740, 128711776, 128708656, 128705692, 128702728, 128699452, 128696332, 128693368, 128690248,
+128687284, 128684320, 128681356, 128678392, 128675428, 128672464, 128669020, 128665576, 128662296, 128659176,
+128656212, 128652768, 128649488, 128646368, 128643404, 128640128, 128637008, 128634044, 128630924, 128627960,
+128624996, 128621556, 128618276, 128615156, 128612192, 128608916, 128605796, 128602832, 128599712, 128596748,
+128593784, 128590508, 128587388, 128584424, 128581304, 128578340, 128575376, 128572412, 128569448, 128566484,
+128563520, 128560240, 128556960, 128553840, 128550876, 128547600, 128544480, 128541516, 128538396, 128535432,
+128532468, 128529192, 128526072, 128523108, 128519988, 128517024, 128514060, 128511096, 128508132, 128505168,
+128502204, 128499084, 128495964, 128493000, 128489880, 128486916, 128483952, 128480988, 128478024, 128475060,
+128472096, 128469132, 128466168, 128463204, 128460240, 128456280, 128452496, 128448884, 128445440, 128442160,
+128439040, 128436076, 128432116, 128428332, 128424720, 128421276, 128417996, 128414876, 128411912, 128408128,
+128404516, 128401072, 128397792, 128394672, 128391708, 128388096, 128384652, 128381372, 128378252, 128375288,
+128371844, 128368564, 128365444, 128362480, 128359200, 128356080, 128353116, 128349996, 128347032, 128344068,
+128340112, 128336328, 128332716, 128329272, 128325992, 128322872, 128319908, 128316124, 128312512, 128309068,
+128305788, 128302668, 128299704, 128296092, 128292648, 128289368, 128286248, 128283284, 128279840, 128276560,
+128273440, 128270476, 128267196, 128264076, 128261112, 128257992, 128255028, 128252064, 128248284, 128244672,
+128241228, 128237948, 128234828, 128231864, 128228252, 128224808, 128221528, 128218408, 128215444, 128212000,
+128208720, 128205600, 128202636, 128199356, 128196236, 128193272, 128190152, 128187188, 128184224, 128180616,
+128177172, 128173892, 128170772, 128167808, 128164364, 128161084, 128157964, 128155000, 128151720, 128148600,
+128145636, 128142516, 128139552, 128136588, 128133148, 128129868, 128126748, 128123784, 128120504, 128117384,
+128114420, 128111300, 128108336, 128105372, 128102096, 128098976, 128096012, 128092892, 128089928, 128086964,
+128083844, 128080880, 128077916, 128074952, 128071168, 128067384, 128063772, 128060328, 128057048, 128053928,
+128050964, 128047180, 128043568, 128040124, 128036844, 128033724, 128030760, 128027148, 128023704, 128020424,
+128017304, 128014340, 128010896, 128007616, 128004496, 128001532, 127998252, 127995132, 127992168, 127989048,
+127986084, 127983120, 127979340, 127975728, 127972284, 127969004, 127965884, 127962920, 127959308, 127955864,
+127952584, 127949464, 127946500, 127943056, 127939776, 127936656, 127933692, 127930412, 127927292, 127924328,
+127921208, 127918244, 127915280, 127911672, 127908228, 127904948, 127901828, 127898864, 127895420, 127892140,
+127889020, 127886056, 127882776, 127879656, 127876692, 127873572, 127870608, 127867644, 127864204, 127860924,
+127857804, 127854840, 127851560, 127848440, 127845476, 127842356, 127839392, 127836428, 127833152, 127830032,
+127827068, 127823948, 127820984, 127818020, 127814900, 127811936, 127808972, 127806008, 127802396, 127798784,
+127795340, 127792060, 127788940, 127785976, 127782364, 127778920, 127775640, 127772520, 127769556, 127766112,
+127762832, 127759712, 127756748, 127753468, 127750348, 127747384, 127744264, 127741300, 127738336, 127734728,
+127731284, 127728004, 127724884, 127721920, 127718476, 127715196, 127712076, 127709112, 127705832, 127702712,
+127699748, 127696628, 127693664, 127690700, 127687260, 127683980, 127680860, 127677896, 127674616, 127671496,
+127668532, 127665412, 127662448, 127659484, 127656208, 127653088, 127650124, 127647004, 127644040, 127641076,
+127637956, 127634992, 127632028, 127629064, 127625620, 127622176, 127618896, 127615776, 127612812, 127609368,
+127606088, 127602968, 127600004, 127596724, 127593604, 127590640, 127587520, 127584556, 127581592, 127578152,
+127574872, 127571752, 127568788, 127565508, 127562388, 127559424, 127556304, 127553340, 127550376, 127547100,
+127543980, 127541016, 127537896, 127534932, 127531968, 127528848, 127525884, 127522920, 127519956, 127516676,
+127513396, 127510276, 127507312, 127504032, 127500912, 127497948, 127494828, 127491864, 127488900, 127485624,
+127482504, 127479540, 127476420, 127473456, 127470492, 127467372, 127464408, 127461444, 127458480, 127455360,
+127452240, 127449276, 127446156, 127443192, 127440228, 127437108, 127434144, 127431180, 127428216, 127425252,
+127422288, 127419324, 127416360, 127413396, 127409612, 127406000, 127402556, 127399276, 127396156, 127393192,
+127389408, 127385796, 127382352, 127379072, 127375952, 127372988, 127369376, 127365932, 127362652, 127359532,
+127356568, 127353124, 127349844, 127346724, 127343760, 127340480, 127337360, 127334396, 127331276, 127328312,
+127325348, 127321568, 127317956, 127314512, 127311232, 127308112, 127305148, 127301536, 127298092, 127294812,
+127291692, 127288728, 127285284, 127282004, 127278884, 127275920, 127272640, 127269520, 127266556, 127263436,
+127260472, 127257508, 127253900, 127250456, 127247176, 127244056, 127241092, 127237648, 127234368, 127231248,
+127228284, 127225004, 127221884, 127218920, 127215800, 127212836, 127209872, 127206432, 127203152, 127200032,
+127197068, 127193788, 127190668, 127187704, 127184584, 127181620, 127178656, 127175380, 127172260, 127169296,
+127166176, 127163212, 127160248, 127157128, 127154164, 127151200, 127148236, 127144624, 127141012, 127137568,
+127134288, 127131168, 127128204, 127124592, 127121148, 127117868, 127114748, 127111784, 127108340, 127105060,
+127101940, 127098976, 127095696, 127092576, 127089612, 127086492, 127083528, 127080564, 127076956, 127073512,
+127070232, 127067112, 127064148, 127060704, 127057424, 127054304, 127051340, 127048060, 127044940, 127041976,
+127038856, 127035892, 127032928, 127029488, 127026208, 127023088, 127020124, 127016844, 127013724, 127010760,
+127007640, 127004676, 127001712, 126998436, 126995316, 126992352, 126989232, 126986268, 126983304, 126980184,
+126977220, 126974256, 126971292, 126967848, 126964404, 126961124, 126958004, 126955040, 126951596, 126948316,
+126945196, 126942232, 126938952, 126935832, 126932868, 126929748, 126926784, 126923820, 126920380, 126917100,
+126913980, 126911016, 126907736, 126904616, 126901652, 126898532, 126895568, 126892604, 126889328, 126886208,
+126883244, 126880124, 126877160, 126874196, 126871076, 126868112, 126865148, 126862184, 126858904, 126855624,
+126852504, 126849540, 126846260, 126843140, 126840176, 126837056, 126834092, 126831128, 126827852, 126824732,
+126821768, 126818648, 126815684, 126812720, 126809600, 126806636, 126803672, 126800708, 126797588, 126794468,
+126791504, 126788384, 126785420, 126782456, 126779336, 126776372, 126773408, 126770444, 126767480, 126764516,
+126761552, 126758588, 126755624, 126752012, 126748568, 126745288, 126742168, 126739204, 126735592, 126732148,
+126728868, 126725748, 126722784, 126719340, 126716060, 126712940, 126709976, 126706696, 126703576, 126700612,
+126697492, 126694528, 126691564, 126687956, 126684512, 126681232, 126678112, 126675148, 126671704, 126668424,
+126665304, 126662340, 126659060, 126655940, 126652976, 126649856, 126646892, 126643928, 126640488, 126637208,
+126634088, 126631124, 126627844, 126624724, 126621760, 126618640, 126615676, 126612712, 126609436, 126606316,
+126603352, 126600232, 126597268, 126594304, 126591184, 126588220, 126585256, 126582292, 126578848, 126575404,
+126572124, 126569004, 126566040, 126562596, 126559316, 126556196, 126553232, 126549952, 126546832, 126543868,
+126540748, 126537784, 126534820, 126531380, 126528100, 126524980, 126522016, 126518736, 126515616, 126512652,
+126509532, 126506568, 126503604, 126500328, 126497208, 126494244, 126491124, 126488160, 126485196, 126482076,
+126479112, 126476148, 126473184, 126469904, 126466624, 126463504, 126460540, 126457260, 126454140, 126451176,
+126448056, 126445092, 126442128, 126438852, 126435732, 126432768, 126429648, 126426684, 126423720, 126420600,
+126417636, 126414672, 126411708, 126408588, 126405468, 126402504, 126399384, 126396420, 126393456, 126390336,
+126387372, 126384408, 126381444, 126378480, 126375516, 126372552, 126369588, 126366624, 126363180, 126359900,
+126356780, 126353816, 126350372, 126347092, 126343972, 126341008, 126337728, 126334608, 126331644, 126328524,
+126325560, 126322596, 126319156, 126315876, 126312756, 126309792, 126306512, 126303392, 126300428, 126297308,
+126294344, 126291380, 126288104, 126284984, 126282020, 126278900, 126275936, 126272972, 126269852, 126266888,
+126263924, 126260960, 126257680, 126254400, 126251280, 126248316, 126245036, 126241916, 126238952, 126235832,
+126232868, 126229904, 126226628, 126223508, 126220544, 126217424, 126214460, 126211496, 126208376, 126205412,
+126202448, 126199484, 126196364, 126193244, 126190280, 126187160, 126184196, 126181232, 126178112, 126175148,
+126172184, 126169220, 126166256, 126163292, 126160328, 126157364, 126154400, 126151120, 126148000, 126145036,
+126141756, 126138636, 126135672, 126132552, 126129588, 126126624, 126123348, 126120228, 126117264, 126114144,
+126111180, 126108216, 126105096, 126102132, 126099168, 126096204, 126093084, 126089964, 126087000, 126083880,
+126080916, 126077952, 126074832, 126071868, 126068904, 126065940, 126062976, 126060012, 126057048, 126054084,
+126051120, 126048000, 126045036, 126041916, 126038952, 126035988, 126032868, 126029904, 126026940, 126023976,
+126021012, 126018048, 126015084, 126012120, 126009156, 126006192, 126003228, 126000264, 125997300, 125249320,
+124645760, 124042200, 123438640, 122835080, 122231520, 121627960, 121024400, 120420840, 119817280, 119772752,
+119736320, 119707984, 119686732, 119671552, 119661432, 119655360, 119652324, 119651312, 119651312, 119651312,
+119614880, 119586544, 119565292, 119550112, 119539992, 119533920, 119530884, 119529872, 119529872, 119529872,
+119501536, 119480284, 119465104, 119454984, 119448912, 119445876, 119444864, 119444864, 119444864, 119423612,
+119408432, 119398312, 119392240, 119389204, 119388192, 119388192, 119388192, 119373012, 119362892, 119356820,
+119353784, 119352772, 119352772, 119352772, 119342652, 119336580, 119333544, 119332532, 119332532, 119332532,
+119326460, 119323424, 119322412, 119322412, 119322412, 119319376, 119318364, 119318364, 119318364, 119318364,
+119318364, 119318364, 119318364, 119318364, 119318364, 119273836, 119237404, 119209068, 119187816, 119172636,
+119162516, 119156444, 119153408, 119152396, 119152396, 119152396, 119116976, 119088640, 119067388, 119052208,
+119042088, 119036016, 119032980, 119031968, 119031968, 119031968, 119003632, 118982380, 118967200, 118957080,
+118951008, 118947972, 118946960, 118946960, 118946960, 118925708, 118910528, 118900408, 118894336, 118891300,
+118890288, 118890288, 118890288, 118875108, 118864988, 118858916, 118855880, 118854868, 118854868, 118854868,
+118844748, 118838676, 118835640, 118834628, 118834628, 118834628, 118828556, 118825520, 118824508, 118824508,
+118824508, 118821472, 118820460, 118820460, 118820460, 118819448, 118819448, 118819448, 118819448, 118819448,
+118819448, 118774920, 118738488, 118710152, 118688900, 118673720, 118663600, 118657528, 118654492, 118653480,
+118653480, 118653480, 118618060, 118589724, 118568472, 118553292, 118543172, 118537100, 118534064, 118533052,
+118533052, 118533052, 118505728, 118484476, 118469296, 118459176, 118453104, 118450068, 118449056, 118449056,
+118449056, 118427804, 118412624, 118402504, 118396432, 118393396, 118392384, 118392384, 118392384, 118377204,
+118367084, 118361012, 118357976, 118356964, 118356964, 118356964, 118346844, 118340772, 118337736, 118336724,
+118336724, 118336724, 118330652, 118327616, 118326604, 118326604, 118326604, 118323568, 118322556, 118322556,
+118322556, 118321544, 118321544, 118321544, 118321544, 118321544, 118321544, 118277016, 118240584, 118212248,
+118190996, 118175816, 118165696, 118159624, 118156588, 118155576, 118155576, 118155576, 118120156, 118091820,
+118070568, 118055388, 118045268, 118039196, 118036160, 118035148, 118035148, 118035148, 118007824, 117986572,
+117971392, 117961272, 117955200, 117952164, 117951152, 117951152, 117951152, 117930912, 117915732, 117905612,
+117899540, 117896504, 117895492, 117895492, 117895492, 117880312, 117870192, 117864120, 117861084, 117860072,
+117860072, 117860072, 117849952, 117843880, 117840844, 117839832, 117839832, 117839832, 117833760, 117830724,
+117829712, 117829712, 117829712, 117826676, 117825664, 117825664, 117825664, 117824652, 117824652, 117824652,
+117824652, 117824652, 117824652, 117780124, 117743692, 117715356, 117694104, 117678924, 117668804, 117662732,
+117659696, 117658684, 117658684, 117658684, 117623264, 117594928, 117573676, 117558496, 117548376, 117542304,
+117539268, 117538256, 117538256, 117538256, 117510932, 117489680, 117474500, 117464380, 117458308, 117455272,
+117454260, 117454260, 117454260, 117434020, 117418840, 117408720, 117402648, 117399612, 117398600, 117398600,
+117398600, 117384432, 117374312, 117368240, 117365204, 117364192, 117364192, 117364192, 117354072, 117348000,
+117344964, 117343952, 117343952, 117343952, 117337880, 117334844, 117333832, 117333832, 117333832, 117330796,
+117329784, 117329784, 117329784, 117328772, 117328772, 117328772, 117328772, 117328772, 117328772, 117283232,
+117246800, 117218464, 117197212, 117182032, 117171912, 117165840, 117162804, 117161792, 117161792, 117161792,
+117126372, 117098036, 117076784, 117061604, 117051484, 117045412, 117042376, 117041364, 117041364, 117041364,
+117014040, 116992788, 116977608, 116967488, 116961416, 116958380, 116957368, 116957368, 116957368, 116937128,
+116921948, 116911828, 116905756, 116902720, 116901708, 116901708, 116901708, 116887540, 116877420, 116871348,
+116868312, 116867300, 116867300, 116867300, 116858192, 116852120, 116849084, 116848072, 116848072, 116848072,
+116842000, 116838964, 116837952, 116837952, 116837952, 116834916, 116833904, 116833904, 116833904, 116832892,
+116832892, 116832892, 116832892, 116832892, 116832892, 116787352, 116750920, 116722584, 116701332, 116686152,
+116676032, 116669960, 116666924, 116665912, 116665912, 116665912, 116629480, 116601144, 116579892, 116564712,
+116554592, 116548520, 116545484, 116544472, 116544472, 116544472, 116517148, 116495896, 116480716, 116470596,
+116464524, 116461488, 116460476, 116460476, 116460476, 116440236, 116425056, 116414936, 116408864, 116405828,
+116404816, 116404816, 116404816, 116390648, 116380528, 116374456, 116371420, 116370408, 116370408, 116370408,
+116361300, 116355228, 116352192, 116351180, 116351180, 116351180, 116346120, 116343084, 116342072, 116342072,
+116342072, 116339036, 116338024, 116338024, 116338024, 116337012, 116337012, 116337012, 116337012, 116337012,
+116337012, 116291472, 116255040, 116226704, 116205452, 116190272, 116180152, 116174080, 116171044, 116170032,
+116170032, 116170032, 116133600, 116105264, 116084012, 116068832, 116058712, 116052640, 116049604, 116048592,
+116048592, 116048592, 116020256, 115999004, 115983824, 115973704, 115967632, 115964596, 115963584, 115963584,
+115963584, 115943344, 115928164, 115918044, 115911972, 115908936, 115907924, 115907924, 115907924, 115893756,
+115883636, 115877564, 115874528, 115873516, 115873516, 115873516, 115864408, 115858336, 115855300, 115854288,
+115854288, 115854288, 115849228, 115846192, 115845180, 115845180, 115845180, 115843156, 115842144, 115842144,
+115842144, 115841132, 115841132, 115841132, 115841132, 115841132, 115841132, 115795592, 115759160, 115730824,
+115709572, 115694392, 115684272, 115678200, 115675164, 115674152, 115674152, 115674152, 115637720, 115609384,
+115588132, 115572952, 115562832, 115556760, 115553724, 115552712, 115552712, 115552712, 115524376, 115503124,
+115487944, 115477824, 115471752, 115468716, 115467704, 115467704, 115467704, 115446452, 115431272, 115421152,
+115415080, 115412044, 115411032, 115411032, 115411032, 115396864, 115386744, 115380672, 115377636, 115376624,
+115376624, 115376624, 115367516, 115361444, 115358408, 115357396, 115357396, 115357396, 115352336, 115349300,
+115348288, 115348288, 115348288, 115346264, 115345252, 115345252, 115345252, 115345252, 115345252, 115345252,
+115345252, 115345252, 115345252, 115299712, 115263280, 115234944, 115213692, 115198512, 115188392, 115182320,
+115179284, 115179284, 115179284, 115179284, 115142852, 115114516, 115093264, 115078084, 115067964, 115061892,
+115058856, 115057844, 115057844, 115057844, 115029508, 115008256, 114993076, 114982956, 114976884, 114973848,
+114972836, 114972836, 114972836, 114951584, 114936404, 114926284, 114920212, 114917176, 114916164, 114916164,
+114916164, 114900984, 114890864, 114884792, 114881756, 114880744, 114880744, 114880744, 114871636, 114865564,
+114862528, 114861516, 114861516, 114861516, 114856456, 114853420, 114852408, 114852408, 114852408, 114850384,
+114849372, 114849372, 114849372, 114849372, 114849372, 114849372, 114849372, 114849372, 114849372, 114803832,
+114767400, 114739064, 114717812, 114702632, 114692512, 114686440, 114683404, 114683404, 114683404, 114683404,
+114646972, 114618636, 114597384, 114582204, 114572084, 114566012, 114562976, 114561964, 114561964, 114561964,
+114533628, 114512376, 114497196, 114487076, 114481004, 114477968, 114476956, 114476956, 114476956, 114455704,
+114440524, 114430404, 114424332, 114421296, 114420284, 114420284, 114420284, 114405104, 114394984, 114388912,
+114385876, 114384864, 114384864, 114384864, 114374744, 114368672, 114365636, 114364624, 114364624, 114364624,
+114359564, 114356528, 114355516, 114355516, 114355516, 114353492, 114352480, 114352480, 114352480, 114352480,
+114352480, 114352480, 114352480, 114352480, 114352480, 114306940, 114270508, 114242172, 114220920, 114205740,
+114195620, 114189548, 114186512, 114186512, 114186512, 114186512, 114150080, 114121744, 114100492, 114085312,
+114075192, 114069120, 114066084, 114065072, 114065072, 114065072, 114036736, 114015484, 114000304, 113990184,
+113984112, 113981076, 113980064, 113980064, 113980064, 113958812, 113943632, 113933512, 113927440, 113924404,
+113923392, 113923392, 113923392, 113908212, 113898092, 113892020, 113888984, 113887972, 113887972, 113887972,
+113877852, 113871780, 113868744, 113867732, 113867732, 113867732, 113861660, 113858624, 113857612, 113857612,
+113857612, 113855588, 113854576, 113854576, 113854576, 113854576, 113854576, 113854576, 113854576, 113854576,
+113854576, 113809036, 113772604, 113744268, 113723016, 113707836, 113697716, 113691644, 113688608, 113688608,
+113688608, 113688608, 113652176, 113623840, 113602588, 113587408, 113577288, 113571216, 113568180, 113567168,
+113567168, 113567168, 113538832, 113517580, 113502400, 113492280, 113486208, 113483172, 113482160, 113482160,
+113482160, 113460908, 113445728, 113435608, 113429536, 113426500, 113425488, 113425488, 113425488, 113410308,
+113400188, 113394116, 113391080, 113390068, 113390068, 113390068, 113379948, 113373876, 113370840, 113369828,
+113369828, 113369828, 113363756, 113360720, 113359708, 113359708, 113359708, 113356672, 113355660, 113355660,
+113355660, 113355660, 113355660, 113355660, 113355660, 113355660, 113355660, 113238588, 113141388, 113064060,
+113004336, 112959948, 112928628, 112908108, 112896120, 112890396, 112888668, 112888668, 112780236, 112683036,
+112605708, 112545984, 112501596, 112470276, 112449756, 112437768, 112432044, 112430316, 112430316, 112322748,
+112233324, 112155996, 112096272, 112051884, 112020564, 112000044, 111988056, 111982332, 111980604, 111980604,
+111873900, 111785340, 111714924, 111655200, 111610812, 111579492, 111558972, 111546984, 111541260, 111539532,
+111539532, 111431424, 111343728, 111274176, 111220500, 111176112, 111144792, 111124272, 111112284, 111106560,
+111104832, 111104832, 110997588, 110910756, 110842068, 110789256, 110750052, 110718732, 110698212, 110686224,
+110680500, 110678772, 110678772, 110572392, 110486424, 110418600, 110366652, 110328312, 110301312, 110280792,
+110268804, 110263080, 110261352, 110261352, 110155836, 110070732, 110003772, 109952688, 109915212, 109889076,
+109872012, 109860024, 109854300, 109852572, 109852572, 109747920, 109663680, 109597584, 109547364, 109510752,
+109485480, 109469280, 109459884, 109456428, 109454700, 109454700, 109350912, 109267536, 109202304, 109152948,
+109117200, 109092792, 109077456, 109068924, 109067196, 109065468, 109065468, 108962544, 108880032, 108815664,
+108767172, 108732288, 108708744, 108694272, 108686604, 108685740, 108684876, 108684876, 108582816, 108501168,
+108437664, 108390036, 108356016, 108333336, 108319728, 108312924, 108312924, 108312924, 108312924, 108204492,
+108109560, 108032232, 107972508, 107928120, 107896800, 107876280, 107864292, 107858568, 107856840, 107856840,
+107749272, 107662116, 107584788, 107525064, 107480676, 107449356, 107428836, 107416848, 107411124, 107409396,
+107409396, 107302692, 107216400, 107145984, 107086260, 107041872, 107010552, 106990032, 106978044, 106972320,
+106970592, 106970592, 106862484, 106777056, 106707504, 106653828, 106609440, 106578120, 106557600, 106545612,
+106539888, 106538160, 106538160, 106430916, 106344084, 106275396, 106222584, 106183380, 106152060, 106131540,
+106119552, 106113828, 106112100, 106112100, 106005720, 105919752, 105851928, 105799980, 105761640, 105734640,
+105714120, 105702132, 105696408, 105694680, 105694680, 105589164, 105504060, 105437100, 105386016, 105348540,
+105322404, 105305340, 105293352, 105287628, 105285900, 105285900, 105181248, 105097008, 105030912, 104980692,
+104944080, 104918808, 104902608, 104893212, 104887488, 104885760, 104885760, 104781972, 104698596, 104633364,
+104584008, 104548260, 104523852, 104508516, 104499984, 104495988, 104494260, 104494260, 104391336, 104308824,
+104244456, 104195964, 104161080, 104137536, 104123064, 104115396, 104112264, 104111400, 104111400, 104009340,
+103927692, 103864188, 103816560, 103782540, 103759860, 103746252, 103739448, 103737180, 103737180, 103737180,
+103629612, 103542456, 103467396, 103407672, 103363284, 103331964, 103311444, 103299456, 103293732, 103292004,
+103292004, 103185300, 103099008, 103030860, 102971136, 102926748, 102895428, 102874908, 102862920, 102857196,
+102855468, 102855468, 102747360, 102661932, 102594648, 102540972, 102496584, 102465264, 102444744, 102432756,
+102427032, 102425304, 102425304, 102318060, 102231228, 102164808, 102111996, 102072792, 102041472, 102020952,
+102008964, 102003240, 102001512, 102001512, 101895132, 101809164, 101741340, 101689392, 101651052, 101624052,
+101603532, 101591544, 101585820, 101584092, 101584092, 101478576, 101393472, 101326512, 101275428, 101237952,
+101211816, 101194752, 101182764, 101177040, 101175312, 101175312, 101070660, 100986420, 100920324, 100870104,
+100833492, 100808220, 100792020, 100782624, 100776900, 100775172, 100775172, 100671384, 100588008, 100522776,
+100473420, 100437672, 100413264, 100397928, 100389396, 100385400, 100383672, 100383672, 100280748, 100198236,
+100133868, 100085376, 100050492, 100026948, 100012476, 100004808, 100001676, 100000812, 100000812, 99898752,
+99817104, 99753600, 99705972, 99671952, 99649272, 99635664, 99628860, 99626592, 99626592, 99626592,
+99519888, 99433596, 99365448, 99307992, 99263604, 99232284, 99211764, 99199776, 99194052, 99192324,
+99192324, 99084216, 98998788, 98931504, 98880096, 98835708, 98804388, 98783868, 98771880, 98766156,
+98764428, 98764428, 98657184, 98570352, 98503932, 98453388, 98414184, 98382864, 98362344, 98350356,
+98344632, 98342904, 98342904, 98236524, 98150556, 98082732, 98033052, 97994712, 97967712, 97947192,
+97935204, 97929480, 97927752, 97927752, 97822236, 97737132, 97670172, 97619088, 97581612, 97555476,
+97538412, 97526424, 97520700, 97518972, 97518972, 97414320, 97330080, 97263984, 97213764, 97177152,
+97151880, 97135680, 97126284, 97120560, 97118832, 97118832, 97015044, 96931668, 96866436, 96817080,
+96781332, 96756924, 96741588, 96733056, 96729060, 96727332, 96727332, 96624408, 96541896, 96477528,
+96429036, 96394152, 96370608, 96356136, 96348468, 96345336, 96344472, 96344472, 96242412, 96160764,
+96097260, 96049632, 96015612, 95992932, 95979324, 95972520, 95970252, 95970252, 95970252, 95862144,
+95776716, 95709432, 95658024, 95615904, 95584584, 95564064, 95552076, 95546352, 95544624, 95544624,
+95437380, 95350548, 95284128, 95233584, 95196648, 95165328, 95144808, 95132820, 95127096, 95125368,
+95125368, 95018988, 94933020, 94865196, 94815516, 94779444, 94752444, 94731924, 94719936, 94714212,
+94712484, 94712484, 94606968, 94521864, 94454904, 94403820, 94368612, 94342476, 94325412, 94313424,
+94307700, 94305972, 94305972, 94201320, 94117080, 94050984, 94000764, 93964152, 93938880, 93922680,
+93913284, 93907560, 93905832, 93905832, 93802044, 93718668, 93653436, 93604080, 93568332, 93543924,
+93528588, 93520056, 93516060, 93514332, 93514332, 93411408, 93328896, 93264528, 93216036, 93181152,
+93157608, 93143136, 93135468, 93132336, 93131472, 93131472, 93029412, 92947764, 92884260, 92836632,
+92802612, 92779932, 92766324, 92759520, 92757252, 92757252, 92757252, 92650008, 92563176, 92496756,
+92446212, 92409276, 92380224, 92359704, 92347716, 92341992, 92340264, 92340264, 92233884, 92147916,
+92080092, 92030412, 91994340, 91969608, 91949088, 91937100, 91931376, 91929648, 91929648, 91824132,
+91739028, 91672068, 91620984, 91585776, 91561908, 91544844, 91532856, 91527132, 91525404, 91525404,
+91420752, 91336512, 91270416, 91220196, 91183584, 91160580, 91144380, 91134984, 91129260, 91127532,
+91127532, 91023744, 90940368, 90875136, 90825780, 90790032, 90765624, 90750288, 90741756, 90737760,
+90736032, 90736032, 90633108, 90550596, 90486228, 90437736, 90402852, 90379308, 90364836, 90357168,
+90354036, 90353172, 90353172, 90251112, 90169464, 90105960, 90058332, 90024312, 90001632, 89988024,
+89981220, 89978952, 89978952, 89978952, 89872572, 89786604, 89718780, 89669100, 89633028, 89608296,
+89590044, 89578056, 89572332, 89570604, 89570604, 89465088, 89379984, 89313024, 89261940, 89226732,
+89202864, 89188068, 89176080, 89170356, 89168628, 89168628, 89063976, 88979736, 88913640, 88863420,
+88826808, 88803804, 88789872, 88780476, 88774752, 88773024, 88773024, 88669236, 88585860, 88520628,
+88471272, 88435524, 88411116, 88398048, 88389516, 88385520, 88383792, 88383792, 88280868, 88198356,
+88133988, 88085496, 88050612, 88027068, 88012596, 88004928, 88001796, 88000932, 88000932, 87898872,
+87817224, 87753720, 87706092, 87672072, 87649392, 87635784, 87628980, 87626712, 87626712, 87626712,
+87521196, 87436092, 87369132, 87318048, 87282840, 87258972, 87244176, 87234456, 87228732, 87227004,
+87227004, 87122352, 87038112, 86972016, 86921796, 86885184, 86862180, 86848248, 86841120, 86835396,
+86833668, 86833668, 86729880, 86646504, 86581272, 86531916, 86496168, 86471760, 86458692, 86452428,
+86448432, 86446704, 86446704, 86343780, 86261268, 86196900, 86148408, 86113524, 86089980, 86075508,
+86070108, 86066976, 86066112, 86066112, 85964052, 85882404, 85818900, 85771272, 85737252, 85714572,
+85700964, 85694160, 85691892, 85691892, 85691892, 85587240, 85503000, 85436904, 85386684, 85350072,
+85327068, 85313136, 85306008, 85302552, 85300824, 85300824, 85197036, 85113660, 85048428, 84999072,
+84963324, 84938916, 84925848, 84919584, 84917856, 84916128, 84916128, 84813204, 84730692, 84666324,
+84617832, 84582948, 84559404, 84544932, 84539532, 84538668, 84537804, 84537804, 84435744, 84354096,
+84290592, 84242964, 84208944, 84186264, 84172656, 84165852, 84165852, 84165852, 84165852, 84064332,
+83980956, 83915724, 83866368, 83830620, 83806212, 83793144, 83786880, 83785152, 83783424, 83783424,
+83682768, 83600256, 83535888, 83487396, 83452512, 83428968, 83414496, 83409096, 83408232, 83407368,
+83407368, 83307576, 83225928, 83162424, 83114796, 83080776, 83058096, 83044488, 83037684, 83037684,
+83037684, 83037684, 82937028, 82854516, 82790148, 82741656, 82706772, 82683228, 82668756, 82663356,
+82662492, 82661628, 82661628, 82561836, 82480188, 82416684, 82369056, 82335036, 82312356, 82298748,
+82291944, 82291944, 82291944, 82291944, 82192152, 82110504, 82047000, 81999372, 81965352, 81942672,
+81929064, 81922260, 81922260, 81922260, 81922260, 81755940, 81589620, 81464880, 81375780, 81316380,
+81280740, 81262920, 81256980, 81256980, 81256980, 81090660, 80965920, 80876820, 80817420, 80781780,
+80763960, 80758020, 80758020, 80758020, 80633280, 80544180, 80484780, 80449140, 80431320, 80425380,
+80425380, 80425380, 80336280, 80276880, 80241240, 80223420, 80217480, 80217480, 80217480, 80158080,
+80122440, 80104620, 80098680, 80098680, 80098680, 80063040, 80045220, 80039280, 80039280, 80039280,
+80021460, 80015520, 80015520, 80015520, 80015520, 80015520, 80015520, 80015520, 80015520, 80015520,
+79855140, 79730400, 79641300, 79581900, 79546260, 79528440, 79522500, 79522500, 79522500, 79397760,
+79308660, 79249260, 79213620, 79195800, 79189860, 79189860, 79189860, 79100760, 79041360, 79005720,
+78987900, 78981960, 78981960, 78981960, 78922560, 78886920, 78869100, 78863160, 78863160, 78863160,
+78827520, 78809700, 78803760, 78803760, 78803760, 78785940, 78780000, 78780000, 78780000, 78780000,
+78780000, 78780000, 78780000, 78780000, 78780000, 78661200, 78572100, 78512700, 78477060, 78459240,
+78453300, 78453300, 78453300, 78364200, 78304800, 78269160, 78251340, 78245400, 78245400, 78245400,
+78186000, 78150360, 78132540, 78126600, 78126600, 78126600, 78090960, 78073140, 78067200, 78067200,
+78067200, 78049380, 78043440, 78043440, 78043440, 78043440, 78043440, 78043440, 78043440, 78043440,
+78043440, 77960280, 77900880, 77865240, 77847420, 77841480, 77841480, 77841480, 77782080, 77746440,
+77728620, 77722680, 77722680, 77722680, 77687040, 77669220, 77663280, 77663280, 77663280, 77645460,
+77639520, 77639520, 77639520, 77639520, 77639520, 77639520, 77639520, 77639520, 77639520, 77586060,
+77550420, 77532600, 77526660, 77526660, 77526660, 77491020, 77473200, 77467260, 77467260, 77467260,
+77449440, 77443500, 77443500, 77443500, 77443500, 77443500, 77443500, 77443500, 77443500, 77443500,
+77413800, 77395980, 77390040, 77390040, 77390040, 77372220, 77366280, 77366280, 77366280, 77366280,
+77366280, 77366280, 77366280, 77366280, 77366280, 77354400, 77348460, 77348460, 77348460, 77348460,
+77348460, 77348460, 77348460, 77348460, 77348460, 77348460, 77348460, 77348460, 77348460, 77348460,
+77348460, 77348460, 77348460, 77348460, 77348460, 77182140, 77015820, 76891080, 76801980, 76742580,
+76706940, 76689120, 76683180, 76683180, 76683180, 76516860, 76392120, 76303020, 76243620, 76207980,
+76190160, 76184220, 76184220, 76184220, 76059480, 75970380, 75910980, 75875340, 75857520, 75851580,
+75851580, 75851580, 75762480, 75703080, 75667440, 75649620, 75643680, 75643680, 75643680, 75584280,
+75548640, 75530820, 75524880, 75524880, 75524880, 75489240, 75471420, 75465480, 75465480, 75465480,
+75447660, 75441720, 75441720, 75441720, 75441720, 75441720, 75441720, 75441720, 75441720, 75441720,
+75316980, 75192240, 75103140, 75043740, 75008100, 74990280, 74984340, 74984340, 74984340, 74859600,
+74770500, 74711100, 74675460, 74657640, 74651700, 74651700, 74651700, 74562600, 74503200, 74467560,
+74449740, 74443800, 74443800, 74443800, 74384400, 74348760, 74330940, 74325000, 74325000, 74325000,
+74289360, 74271540, 74265600, 74265600, 74265600, 74247780, 74241840, 74241840, 74241840, 74235900,
+74235900, 74235900, 74235900, 74235900, 74235900, 74117100, 74028000, 73968600, 73932960, 73915140,
+73909200, 73909200, 73909200, 73820100, 73760700, 73725060, 73707240, 73701300, 73701300, 73701300,
+73641900, 73606260, 73588440, 73582500, 73582500, 73582500, 73546860, 73529040, 73523100, 73523100,
+73523100, 73505280, 73499340, 73499340, 73499340, 73493400, 73493400, 73493400, 73493400, 73493400,
+73493400, 73410240, 73350840, 73315200, 73297380, 73291440, 73291440, 73291440, 73232040, 73196400,
+73178580, 73172640, 73172640, 73172640, 73137000, 73119180, 73113240, 73113240, 73113240, 73095420,
+73089480, 73089480, 73089480, 73083540, 73083540, 73083540, 73083540, 73083540, 73083540, 73030080,
+72994440, 72976620, 72970680, 72970680, 72970680, 72935040, 72917220, 72911280, 72911280, 72911280,
+72893460, 72887520, 72887520, 72887520, 72881580, 72881580, 72881580, 72881580, 72881580, 72881580,
+72851880, 72834060, 72828120, 72828120, 72828120, 72810300, 72804360, 72804360, 72804360, 72798420,
+72798420, 72798420, 72798420, 72798420, 72798420, 72786540, 72780600, 72780600, 72780600, 72774660,
+72774660, 72774660, 72774660, 72774660, 72774660, 72774660, 72774660, 72774660, 72774660, 72774660,
+72774660, 72774660, 72774660, 72774660, 72774660, 72608340, 72442020, 72317280, 72228180, 72168780,
+72133140, 72115320, 72109380, 72109380, 72109380, 71949000, 71824260, 71735160, 71675760, 71640120,
+71622300, 71616360, 71616360, 71616360, 71491620, 71402520, 71343120, 71307480, 71289660, 71283720,
+71283720, 71283720, 71194620, 71135220, 71099580, 71081760, 71075820, 71075820, 71075820, 71016420,
+70980780, 70962960, 70957020, 70957020, 70957020, 70921380, 70903560, 70897620, 70897620, 70897620,
+70879800, 70873860, 70873860, 70873860, 70873860, 70873860, 70873860, 70873860, 70873860, 70873860,
+70749120, 70624380, 70535280, 70475880, 70440240, 70422420, 70416480, 70416480, 70416480, 70291740,
+70202640, 70143240, 70107600, 70089780, 70083840, 70083840, 70083840, 69994740, 69935340, 69899700,
+69881880, 69875940, 69875940, 69875940, 69816540, 69780900, 69763080, 69757140, 69757140, 69757140,
+69721500, 69703680, 69697740, 69697740, 69697740, 69679920, 69673980, 69673980, 69673980, 69668040,
+69668040, 69668040, 69668040, 69668040, 69668040, 69578940, 69489840, 69430440, 69394800, 69376980,
+69371040, 69371040, 69371040, 69281940, 69222540, 69186900, 69169080, 69163140, 69163140, 69163140,
+69103740, 69068100, 69050280, 69044340, 69044340, 69044340, 69008700, 68990880, 68984940, 68984940,
+68984940, 68967120, 68961180, 68961180, 68961180, 68955240, 68955240, 68955240, 68955240, 68955240,
+68955240, 68872080, 68812680, 68777040, 68759220, 68753280, 68753280, 68753280, 68693880, 68658240,
+68640420, 68634480, 68634480, 68634480, 68598840, 68581020, 68575080, 68575080, 68575080, 68557260,
+68551320, 68551320, 68551320, 68545380, 68545380, 68545380, 68545380, 68545380, 68545380, 68491920,
+68456280, 68438460, 68432520, 68432520, 68432520, 68396880, 68379060, 68373120, 68373120, 68373120,
+68355300, 68349360, 68349360, 68349360, 68343420, 68343420, 68343420, 68343420, 68343420, 68343420,
+68313720, 68295900, 68289960, 68289960, 68289960, 68272140, 68266200, 68266200, 68266200, 68260260,
+68260260, 68260260, 68260260, 68260260, 68260260, 68248380, 68242440, 68242440, 68242440, 68236500,
+68236500, 68236500, 68236500, 68236500, 68236500, 68236500, 68236500, 68236500, 68236500, 68236500,
+68236500, 68236500, 68236500, 68236500, 68236500, 68070180, 67903860, 67779120, 67690020, 67630620,
+67594980, 67577160, 67571220, 67571220, 67571220, 67410840, 67286100, 67197000, 67137600, 67101960,
+67084140, 67078200, 67078200, 67078200, 66959400, 66870300, 66810900, 66775260, 66757440, 66751500,
+66751500, 66751500, 66662400, 66603000, 66567360, 66549540, 66543600, 66543600, 66543600, 66484200,
+66448560, 66430740, 66424800, 66424800, 66424800, 66389160, 66371340, 66365400, 66365400, 66365400,
+66347580, 66341640, 66341640, 66341640, 66341640, 66341640, 66341640, 66341640, 66341640, 66341640,
+66216900, 66092160, 66003060, 65943660, 65908020, 65890200, 65884260, 65884260, 65884260, 65765460,
+65676360, 65616960, 65581320, 65563500, 65557560, 65557560, 65557560, 65468460, 65409060, 65373420,
+65355600, 65349660, 65349660, 65349660, 65290260, 65254620, 65236800, 65230860, 65230860, 65230860,
+65195220, 65177400, 65171460, 65171460, 65171460, 65153640, 65147700, 65147700, 65147700, 65141760,
+65141760, 65141760, 65141760, 65141760, 65141760, 65052660, 64963560, 64904160, 64868520, 64850700,
+64844760, 64844760, 64844760, 64755660, 64696260, 64660620, 64642800, 64636860, 64636860, 64636860,
+64577460, 64541820, 64524000, 64518060, 64518060, 64518060, 64482420, 64464600, 64458660, 64458660,
+64458660, 64440840, 64434900, 64434900, 64434900, 64428960, 64428960, 64428960, 64428960, 64428960,
+64428960, 64369560, 64310160, 64274520, 64256700, 64250760, 64250760, 64250760, 64191360, 64155720,
+64137900, 64131960, 64131960, 64131960, 64096320, 64078500, 64072560, 64072560, 64072560, 64054740,
+64048800, 64048800, 64048800, 64042860, 64042860, 64042860, 64042860, 64042860, 64042860, 63989400,
+63953760, 63935940, 63930000, 63930000, 63930000, 63894360, 63876540, 63870600, 63870600, 63870600,
+63852780, 63846840, 63846840, 63846840, 63840900, 63840900, 63840900, 63840900, 63840900, 63840900,
+63811200, 63793380, 63787440, 63787440, 63787440, 63769620, 63763680, 63763680, 63763680, 63757740,
+63757740, 63757740, 63757740, 63757740, 63757740, 63745860, 63739920, 63739920, 63739920, 63733980,
+63733980, 63733980, 63733980, 63733980, 63733980, 63733980, 63733980, 63733980, 63733980, 63733980,
+63733980, 63733980, 63733980, 63733980, 63733980, 63567660, 63401340, 63276600, 63187500, 63128100,
+63092460, 63074640, 63068700, 63068700, 63068700, 62908320, 62783580, 62694480, 62635080, 62599440,
+62581620, 62575680, 62575680, 62575680, 62456880, 62367780, 62308380, 62272740, 62254920, 62248980,
+62248980, 62248980, 62165820, 62106420, 62070780, 62052960, 62047020, 62047020, 62047020, 61987620,
+61951980, 61934160, 61928220, 61928220, 61928220, 61892580, 61874760, 61868820, 61868820, 61868820,
+61851000, 61845060, 61845060, 61845060, 61845060, 61845060, 61845060, 61845060, 61845060, 61845060,
+61720320, 61595580, 61506480, 61447080, 61411440, 61393620, 61387680, 61387680, 61387680, 61268880,
+61179780, 61120380, 61084740, 61066920, 61060980, 61060980, 61060980, 60977820, 60918420, 60882780,
+60864960, 60859020, 60859020, 60859020, 60799620, 60763980, 60746160, 60740220, 60740220, 60740220,
+60704580, 60686760, 60680820, 60680820, 60680820, 60663000, 60657060, 60657060, 60657060, 60651120,
+60651120, 60651120, 60651120, 60651120, 60651120, 60562020, 60472920, 60413520, 60377880, 60360060,
+60354120, 60354120, 60354120, 60270960, 60211560, 60175920, 60158100, 60152160, 60152160, 60152160,
+60092760, 60057120, 60039300, 60033360, 60033360, 60033360, 59997720, 59979900, 59973960, 59973960,
+59973960, 59956140, 59950200, 59950200, 59950200, 59944260, 59944260, 59944260, 59944260, 59944260,
+59944260, 59884860, 59825460, 59789820, 59772000, 59766060, 59766060, 59766060, 59706660, 59671020,
+59653200, 59647260, 59647260, 59647260, 59611620, 59593800, 59587860, 59587860, 59587860, 59570040,
+59564100, 59564100, 59564100, 59558160, 59558160, 59558160, 59558160, 59558160, 59558160, 59522520,
+59486880, 59469060, 59463120, 59463120, 59463120, 59427480, 59409660, 59403720, 59403720, 59403720,
+59385900, 59379960, 59379960, 59379960, 59374020, 59374020, 59374020, 59374020, 59374020, 59374020,
+59344320, 59326500, 59320560, 59320560, 59320560, 59302740, 59296800, 59296800, 59296800, 59290860,
+59290860, 59290860, 59290860, 59290860, 59290860, 59278980, 59273040, 59273040, 59273040, 59267100,
+59267100, 59267100, 59267100, 59267100, 59267100, 59267100, 59267100, 59267100, 59267100, 59267100,
+59267100, 59267100, 59267100, 59267100, 59267100, 59059200, 58892880, 58768140, 58679040, 58619640,
+58584000, 58566180, 58560240, 58560240, 58560240, 58399860, 58275120, 58186020, 58126620, 58090980,
+58073160, 58067220, 58067220, 58067220, 57948420, 57859320, 57799920, 57764280, 57746460, 57740520,
+57740520, 57740520, 57657360, 57597960, 57562320, 57544500, 57538560, 57538560, 57538560, 57485100,
+57449460, 57431640, 57425700, 57425700, 57425700, 57390060, 57372240, 57366300, 57366300, 57366300,
+57348480, 57342540, 57342540, 57342540, 57342540, 57342540, 57342540, 57342540, 57342540, 57342540,
+57217800, 57093060, 57003960, 56944560, 56908920, 56891100, 56885160, 56885160, 56885160, 56766360,
+56677260, 56617860, 56582220, 56564400, 56558460, 56558460, 56558460, 56475300, 56415900, 56380260,
+56362440, 56356500, 56356500, 56356500, 56303040, 56267400, 56249580, 56243640, 56243640, 56243640,
+56208000, 56190180, 56184240, 56184240, 56184240, 56166420, 56160480, 56160480, 56160480, 56154540,
+56154540, 56154540, 56154540, 56154540, 56154540, 56065440, 55976340, 55916940, 55881300, 55863480,
+55857540, 55857540, 55857540, 55774380, 55714980, 55679340, 55661520, 55655580, 55655580, 55655580,
+55602120, 55566480, 55548660, 55542720, 55542720, 55542720, 55507080, 55489260, 55483320, 55483320,
+55483320, 55465500, 55459560, 55459560, 55459560, 55453620, 55453620, 55453620, 55453620, 55453620,
+55453620, 55394220, 55334820, 55299180, 55281360, 55275420, 55275420, 55275420, 55221960, 55186320,
+55168500, 55162560, 55162560, 55162560, 55126920, 55109100, 55103160, 55103160, 55103160, 55085340,
+55079400, 55079400, 55079400, 55073460, 55073460, 55073460, 55073460, 55073460, 55073460, 55037820,
+55002180, 54984360, 54978420, 54978420, 54978420, 54942780, 54924960, 54919020, 54919020, 54919020,
+54901200, 54895260, 54895260, 54895260, 54889320, 54889320, 54889320, 54889320, 54889320, 54889320,
+54871500, 54853680, 54847740, 54847740, 54847740, 54829920, 54823980, 54823980, 54823980, 54818040,
+54818040, 54818040, 54818040, 54818040, 54818040, 54806160, 54800220, 54800220, 54800220, 54794280,
+54794280, 54794280, 54794280, 54794280, 54794280, 54794280, 54794280, 54794280, 54794280, 54794280,
+54794280, 54794280, 54794280, 54794280, 54794280, 54586380, 54420060, 54295320, 54206220, 54146820,
+54111180, 54093360, 54087420, 54087420, 54087420, 53927040, 53802300, 53713200, 53653800, 53618160,
+53600340, 53594400, 53594400, 53594400, 53475600, 53386500, 53327100, 53291460, 53273640, 53267700,
+53267700, 53267700, 53184540, 53125140, 53089500, 53071680, 53065740, 53065740, 53065740, 53012280,
+52976640, 52958820, 52952880, 52952880, 52952880, 52923180, 52905360, 52899420, 52899420, 52899420,
+52881600, 52875660, 52875660, 52875660, 52875660, 52875660, 52875660, 52875660, 52875660, 52875660,
+52715280, 52590540, 52501440, 52442040, 52406400, 52388580, 52382640, 52382640, 52382640, 52263840,
+52174740, 52115340, 52079700, 52061880, 52055940, 52055940, 52055940, 51972780, 51913380, 51877740,
+51859920, 51853980, 51853980, 51853980, 51800520, 51764880, 51747060, 51741120, 51741120, 51741120,
+51711420, 51693600, 51687660, 51687660, 51687660, 51669840, 51663900, 51663900, 51663900, 51657960,
+51657960, 51657960, 51657960, 51657960, 51657960, 51568860, 51479760, 51420360, 51384720, 51366900,
+51360960, 51360960, 51360960, 51277800, 51218400, 51182760, 51164940, 51159000, 51159000, 51159000,
+51105540, 51069900, 51052080, 51046140, 51046140, 51046140, 51016440, 50998620, 50992680, 50992680,
+50992680, 50974860, 50968920, 50968920, 50968920, 50962980, 50962980, 50962980, 50962980, 50962980,
+50962980, 50903580, 50844180, 50808540, 50790720, 50784780, 50784780, 50784780, 50731320, 50695680,
+50677860, 50671920, 50671920, 50671920, 50642220, 50624400, 50618460, 50618460, 50618460, 50600640,
+50594700, 50594700, 50594700, 50588760, 50588760, 50588760, 50588760, 50588760, 50588760, 50553120,
+50517480, 50499660, 50493720, 50493720, 50493720, 50464020, 50446200, 50440260, 50440260, 50440260,
+50422440, 50416500, 50416500, 50416500, 50410560, 50410560, 50410560, 50410560, 50410560, 50410560,
+50392740, 50374920, 50368980, 50368980, 50368980, 50351160, 50345220, 50345220, 50345220, 50339280,
+50339280, 50339280, 50339280, 50339280, 50339280, 50333340, 50327400, 50327400, 50327400, 50321460,
+50321460, 50321460, 50321460, 50321460, 50321460, 50321460, 50321460, 50321460, 50321460, 50321460,
+50321460, 50321460, 50321460, 50321460, 50321460, 50113560, 49947240, 49822500, 49733400, 49674000,
+49638360, 49620540, 49614600, 49614600, 49614600, 49448280, 49323540, 49234440, 49175040, 49139400,
+49121580, 49115640, 49115640, 49115640, 48996840, 48907740, 48848340, 48812700, 48794880, 48788940,
+48788940, 48788940, 48705780, 48646380, 48610740, 48592920, 48586980, 48586980, 48586980, 48533520,
+48497880, 48480060, 48474120, 48474120, 48474120, 48444420, 48426600, 48420660, 48420660, 48420660,
+48408780, 48402840, 48402840, 48402840, 48402840, 48402840, 48402840, 48402840, 48402840, 48402840,
+48242460, 48117720, 48028620, 47969220, 47933580, 47915760, 47909820, 47909820, 47909820, 47791020,
+47701920, 47642520, 47606880, 47589060, 47583120, 47583120, 47583120, 47499960, 47440560, 47404920,
+47387100, 47381160, 47381160, 47381160, 47327700, 47292060, 47274240, 47268300, 47268300, 47268300,
+47238600, 47220780, 47214840, 47214840, 47214840, 47202960, 47197020, 47197020, 47197020, 47191080,
+47191080, 47191080, 47191080, 47191080, 47191080, 47072280, 46983180, 46923780, 46888140, 46870320,
+46864380, 46864380, 46864380, 46781220, 46721820, 46686180, 46668360, 46662420, 46662420, 46662420,
+46608960, 46573320, 46555500, 46549560, 46549560, 46549560, 46519860, 46502040, 46496100, 46496100,
+46496100, 46484220, 46478280, 46478280, 46478280, 46472340, 46472340, 46472340, 46472340, 46472340,
+46472340, 46412940, 46353540, 46317900, 46300080, 46294140, 46294140, 46294140, 46240680, 46205040,
+46187220, 46181280, 46181280, 46181280, 46151580, 46133760, 46127820, 46127820, 46127820, 46115940,
+46110000, 46110000, 46110000, 46104060, 46104060, 46104060, 46104060, 46104060, 46104060, 46068420,
+46032780, 46014960, 46009020, 46009020, 46009020, 45979320, 45961500, 45955560, 45955560, 45955560,
+45943680, 45937740, 45937740, 45937740, 45931800, 45931800, 45931800, 45931800, 45931800, 45931800,
+45913980, 45896160, 45890220, 45890220, 45890220, 45878340, 45872400, 45872400, 45872400, 45866460,
+45866460, 45866460, 45866460, 45866460, 45866460, 45860520, 45854580, 45854580, 45854580, 45848640,
+45848640, 45848640, 45848640, 45848640, 45848640, 45848640, 45848640, 45848640, 45848640, 45848640,
+45848640, 45848640, 45848640, 45848640, 45848640, 45640740, 45474420, 45349680, 45260580, 45201180,
+45165540, 45147720, 45141780, 45141780, 45141780, 44975460, 44850720, 44761620, 44702220, 44666580,
+44648760, 44642820, 44642820, 44642820, 44518080, 44428980, 44369580, 44333940, 44316120, 44310180,
+44310180, 44310180, 44227020, 44167620, 44131980, 44114160, 44108220, 44108220, 44108220, 44054760,
+44019120, 44001300, 43995360, 43995360, 43995360, 43965660, 43947840, 43941900, 43941900, 43941900,
+43930020, 43924080, 43924080, 43924080, 43924080, 43924080, 43924080, 43924080, 43924080, 43924080,
+43763700, 43638960, 43549860, 43490460, 43454820, 43437000, 43431060, 43431060, 43431060, 43306320,
+43217220, 43157820, 43122180, 43104360, 43098420, 43098420, 43098420, 43015260, 42955860, 42920220,
+42902400, 42896460, 42896460, 42896460, 42843000, 42807360, 42789540, 42783600, 42783600, 42783600,
+42753900, 42736080, 42730140, 42730140, 42730140, 42718260, 42712320, 42712320, 42712320, 42712320,
+42712320, 42712320, 42712320, 42712320, 42712320, 42593520, 42504420, 42445020, 42409380, 42391560,
+42385620, 42385620, 42385620, 42302460, 42243060, 42207420, 42189600, 42183660, 42183660, 42183660,
+42130200, 42094560, 42076740, 42070800, 42070800, 42070800, 42041100, 42023280, 42017340, 42017340,
+42017340, 42005460, 41999520, 41999520, 41999520, 41999520, 41999520, 41999520, 41999520, 41999520,
+41999520, 41916360, 41856960, 41821320, 41803500, 41797560, 41797560, 41797560, 41744100, 41708460,
+41690640, 41684700, 41684700, 41684700, 41655000, 41637180, 41631240, 41631240, 41631240, 41619360,
+41613420, 41613420, 41613420, 41613420, 41613420, 41613420, 41613420, 41613420, 41613420, 41577780,
+41542140, 41524320, 41518380, 41518380, 41518380, 41488680, 41470860, 41464920, 41464920, 41464920,
+41453040, 41447100, 41447100, 41447100, 41447100, 41447100, 41447100, 41447100, 41447100, 41447100,
+41429280, 41411460, 41405520, 41405520, 41405520, 41393640, 41387700, 41387700, 41387700, 41387700,
+41387700, 41387700, 41387700, 41387700, 41387700, 41381760, 41375820, 41375820, 41375820, 41375820,
+41375820, 41375820, 41375820, 41375820, 41375820, 41375820, 41375820, 41375820, 41375820, 41375820,
+41375820, 41375820, 41375820, 41375820, 41375820, 41167920, 41001600, 40876860, 40787760, 40728360,
+40692720, 40674900, 40674900, 40674900, 40674900, 40508580, 40383840, 40294740, 40235340, 40199700,
+40181880, 40181880, 40181880, 40181880, 40057140, 39968040, 39908640, 39873000, 39855180, 39855180,
+39855180, 39855180, 39766080, 39706680, 39671040, 39653220, 39653220, 39653220, 39653220, 39599760,
+39564120, 39546300, 39546300, 39546300, 39546300, 39516600, 39498780, 39498780, 39498780, 39498780,
+39486900, 39486900, 39486900, 39486900, 39486900, 39486900, 39486900, 39486900, 39486900, 39486900,
+39326520, 39201780, 39112680, 39053280, 39017640, 38999820, 38993880, 38993880, 38993880, 38869140,
+38780040, 38720640, 38685000, 38667180, 38661240, 38661240, 38661240, 38572140, 38512740, 38477100,
+38459280, 38453340, 38453340, 38453340, 38399880, 38364240, 38346420, 38340480, 38340480, 38340480,
+38310780, 38292960, 38287020, 38287020, 38287020, 38275140, 38269200, 38269200, 38269200, 38269200,
+38269200, 38269200, 38269200, 38269200, 38269200, 38150400, 38061300, 38001900, 37966260, 37948440,
+37942500, 37942500, 37942500, 37853400, 37794000, 37758360, 37740540, 37734600, 37734600, 37734600,
+37681140, 37645500, 37627680, 37621740, 37621740, 37621740, 37592040, 37574220, 37568280, 37568280,
+37568280, 37556400, 37550460, 37550460, 37550460, 37550460, 37550460, 37550460, 37550460, 37550460,
+37550460, 37467300, 37407900, 37372260, 37354440, 37348500, 37348500, 37348500, 37295040, 37259400,
+37241580, 37235640, 37235640, 37235640, 37205940, 37188120, 37182180, 37182180, 37182180, 37170300,
+37164360, 37164360, 37164360, 37164360, 37164360, 37164360, 37164360, 37164360, 37164360, 37110900,
+37075260, 37057440, 37051500, 37051500, 37051500, 37021800, 37003980, 36998040, 36998040, 36998040,
+36986160, 36980220, 36980220, 36980220, 36980220, 36980220, 36980220, 36980220, 36980220, 36980220,
+36962400, 36944580, 36938640, 36938640, 36938640, 36926760, 36920820, 36920820, 36920820, 36920820,
+36920820, 36920820, 36920820, 36920820, 36920820, 36914880, 36908940, 36908940, 36908940, 36908940,
+36908940, 36908940, 36908940, 36908940, 36908940, 36908940, 36908940, 36908940, 36908940, 36908940,
+36908940, 36908940, 36908940, 36908940, 36908940, 36701040, 36534720, 36409980, 36320880, 36261480,
+36225840, 36208020, 36208020, 36208020, 36208020, 36041700, 35916960, 35827860, 35768460, 35732820,
+35715000, 35715000, 35715000, 35715000, 35590260, 35501160, 35441760, 35406120, 35388300, 35388300,
+35388300, 35388300, 35299200, 35239800, 35204160, 35186340, 35186340, 35186340, 35186340, 35126940,
+35091300, 35073480, 35073480, 35073480, 35073480, 35043780, 35025960, 35025960, 35025960, 35025960,
+35014080, 35014080, 35014080, 35014080, 35014080, 35014080, 35014080, 35014080, 35014080, 35014080,
+34853700, 34728960, 34639860, 34580460, 34544820, 34527000, 34521060, 34521060, 34521060, 34396320,
+34307220, 34247820, 34212180, 34194360, 34188420, 34188420, 34188420, 34099320, 34039920, 34004280,
+33986460, 33980520, 33980520, 33980520, 33921120, 33885480, 33867660, 33861720, 33861720, 33861720,
+33832020, 33814200, 33808260, 33808260, 33808260, 33796380, 33790440, 33790440, 33790440, 33790440,
+33790440, 33790440, 33790440, 33790440, 33790440, 33671640, 33582540, 33523140, 33487500, 33469680,
+33463740, 33463740, 33463740, 33374640, 33315240, 33279600, 33261780, 33255840, 33255840, 33255840,
+33196440, 33160800, 33142980, 33137040, 33137040, 33137040, 33107340, 33089520, 33083580, 33083580,
+33083580, 33071700, 33065760, 33065760, 33065760, 33065760, 33065760, 33065760, 33065760, 33065760,
+33065760, 32982600, 32923200, 32887560, 32869740, 32863800, 32863800, 32863800, 32804400, 32768760,
+32750940, 32745000, 32745000, 32745000, 32715300, 32697480, 32691540, 32691540, 32691540, 32679660,
+32673720, 32673720, 32673720, 32673720, 32673720, 32673720, 32673720, 32673720, 32673720, 32620260,
+32584620, 32566800, 32560860, 32560860, 32560860, 32531160, 32513340, 32507400, 32507400, 32507400,
+32495520, 32489580, 32489580, 32489580, 32489580, 32489580, 32489580, 32489580, 32489580, 32489580,
+32459880, 32442060, 32436120, 32436120, 32436120, 32424240, 32418300, 32418300, 32418300, 32418300,
+32418300, 32418300, 32418300, 32418300, 32418300, 32412360, 32406420, 32406420, 32406420, 32406420,
+32406420, 32406420, 32406420, 32406420, 32406420, 32406420, 32406420, 32406420, 32406420, 32406420,
+32406420, 32406420, 32406420, 32406420, 32406420, 32198520, 32032200, 31907460, 31818360, 31758960,
+31723320, 31705500, 31705500, 31705500, 31705500, 31539180, 31414440, 31325340, 31265940, 31230300,
+31212480, 31212480, 31212480, 31212480, 31087740, 30998640, 30939240, 30903600, 30885780, 30885780,
+30885780, 30885780, 30796680, 30737280, 30701640, 30683820, 30683820, 30683820, 30683820, 30624420,
+30588780, 30570960, 30570960, 30570960, 30570960, 30535320, 30517500, 30517500, 30517500, 30517500,
+30505620, 30505620, 30505620, 30505620, 30505620, 30505620, 30505620, 30505620, 30505620, 30505620,
+30345240, 30220500, 30131400, 30072000, 30036360, 30018540, 30012600, 30012600, 30012600, 29887860,
+29798760, 29739360, 29703720, 29685900, 29679960, 29679960, 29679960, 29590860, 29531460, 29495820,
+29478000, 29472060, 29472060, 29472060, 29412660, 29377020, 29359200, 29353260, 29353260, 29353260,
+29317620, 29299800, 29293860, 29293860, 29293860, 29281980, 29276040, 29276040, 29276040, 29276040,
+29276040, 29276040, 29276040, 29276040, 29276040, 29157240, 29068140, 29008740, 28973100, 28955280,
+28949340, 28949340, 28949340, 28860240, 28800840, 28765200, 28747380, 28741440, 28741440, 28741440,
+28682040, 28646400, 28628580, 28622640, 28622640, 28622640, 28587000, 28569180, 28563240, 28563240,
+28563240, 28551360, 28545420, 28545420, 28545420, 28545420, 28545420, 28545420, 28545420, 28545420,
+28545420, 28462260, 28402860, 28367220, 28349400, 28343460, 28343460, 28343460, 28284060, 28248420,
+28230600, 28224660, 28224660, 28224660, 28189020, 28171200, 28165260, 28165260, 28165260, 28153380,
+28147440, 28147440, 28147440, 28147440, 28147440, 28147440, 28147440, 28147440, 28147440, 28093980,
+28058340, 28040520, 28034580, 28034580, 28034580, 27998940, 27981120, 27975180, 27975180, 27975180,
+27963300, 27957360, 27957360, 27957360, 27957360, 27957360, 27957360, 27957360, 27957360, 27957360,
+27927660, 27909840, 27903900, 27903900, 27903900, 27892020, 27886080, 27886080, 27886080, 27886080,
+27886080, 27886080, 27886080, 27886080, 27886080, 27874200, 27868260, 27868260, 27868260, 27868260,
+27868260, 27868260, 27868260, 27868260, 27868260, 27868260, 27868260, 27868260, 27868260, 27868260,
+27868260, 27868260, 27868260, 27868260, 27868260, 27660360, 27494040, 27369300, 27280200, 27220800,
+27185160, 27167340, 27167340, 27167340, 27167340, 27001020, 26876280, 26787180, 26727780, 26692140,
+26674320, 26674320, 26674320, 26674320, 26549580, 26460480, 26401080, 26365440, 26347620, 26347620,
+26347620, 26347620, 26258520, 26199120, 26163480, 26145660, 26145660, 26145660, 26145660, 26086260,
+26050620, 26032800, 26032800, 26032800, 26032800, 25997160, 25979340, 25979340, 25979340, 25979340,
+25961520, 25961520, 25961520, 25961520, 25961520, 25961520, 25961520, 25961520, 25961520, 25961520,
+25801140, 25676400, 25587300, 25527900, 25492260, 25474440, 25468500, 25468500, 25468500, 25343760,
+25254660, 25195260, 25159620, 25141800, 25135860, 25135860, 25135860, 25046760, 24987360, 24951720,
+24933900, 24927960, 24927960, 24927960, 24868560, 24832920, 24815100, 24809160, 24809160, 24809160,
+24773520, 24755700, 24749760, 24749760, 24749760, 24731940, 24726000, 24726000, 24726000, 24726000,
+24726000, 24726000, 24726000, 24726000, 24726000, 24607200, 24518100, 24458700, 24423060, 24405240,
+24399300, 24399300, 24399300, 24310200, 24250800, 24215160, 24197340, 24191400, 24191400, 24191400,
+24132000, 24096360, 24078540, 24072600, 24072600, 24072600, 24036960, 24019140, 24013200, 24013200,
+24013200, 23995380, 23989440, 23989440, 23989440, 23989440, 23989440, 23989440, 23989440, 23989440,
+23989440, 23906280, 23846880, 23811240, 23793420, 23787480, 23787480, 23787480, 23728080, 23692440,
+23674620, 23668680, 23668680, 23668680, 23633040, 23615220, 23609280, 23609280, 23609280, 23591460,
+23585520, 23585520, 23585520, 23585520, 23585520, 23585520, 23585520, 23585520, 23585520, 23532060,
+23496420, 23478600, 23472660, 23472660, 23472660, 23437020, 23419200, 23413260, 23413260, 23413260,
+23395440, 23389500, 23389500, 23389500, 23389500, 23389500, 23389500, 23389500, 23389500, 23389500,
+23359800, 23341980, 23336040, 23336040, 23336040, 23318220, 23312280, 23312280, 23312280, 23312280,
+23312280, 23312280, 23312280, 23312280, 23312280, 23300400, 23294460, 23294460, 23294460, 23294460,
+23294460, 23294460, 23294460, 23294460, 23294460, 23294460, 23294460, 23294460, 23294460, 23294460,
+23294460, 23294460, 23294460, 23294460, 23294460, 22968120, 22735020, 22579620, 22486380, 22439760,
+22424220, 22424220, 22424220, 22097880, 21864780, 21709380, 21616140, 21569520, 21553980, 21553980,
+21553980, 21320880, 21165480, 21072240, 21025620, 21010080, 21010080, 21010080, 20854680, 20761440,
+20714820, 20699280, 20699280, 20699280, 20606040, 20559420, 20543880, 20543880, 20543880, 20497260,
+20481720, 20481720, 20481720, 20481720, 20481720, 20481720, 20481720, 20481720, 20481720, 20170920,
+19937820, 19782420, 19689180, 19642560, 19627020, 19627020, 19627020, 19393920, 19238520, 19145280,
+19098660, 19083120, 19083120, 19083120, 18927720, 18834480, 18787860, 18772320, 18772320, 18772320,
+18679080, 18632460, 18616920, 18616920, 18616920, 18570300, 18554760, 18554760, 18554760, 18554760,
+18554760, 18554760, 18554760, 18554760, 18554760, 18337200, 18181800, 18088560, 18041940, 18026400,
+18026400, 18026400, 17871000, 17777760, 17731140, 17715600, 17715600, 17715600, 17622360, 17575740,
+17560200, 17560200, 17560200, 17513580, 17498040, 17498040, 17498040, 17498040, 17498040, 17498040,
+17498040, 17498040, 17498040, 17358180, 17264940, 17218320, 17202780, 17202780, 17202780, 17109540,
+17062920, 17047380, 17047380, 17047380, 17000760, 16985220, 16985220, 16985220, 16985220, 16985220,
+16985220, 16985220, 16985220, 16985220, 16907520, 16860900, 16845360, 16845360, 16845360, 16798740,
+16783200, 16783200, 16783200, 16783200, 16783200, 16783200, 16783200, 16783200, 16783200, 16752120,
+16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580,
+16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16736580, 16503480,
+16270380, 16114980, 16021740, 15975120, 15959580, 15959580, 15959580, 15726480, 15571080, 15477840,
+15431220, 15415680, 15415680, 15415680, 15260280, 15167040, 15120420, 15104880, 15104880, 15104880,
+15011640, 14965020, 14949480, 14949480, 14949480, 14902860, 14887320, 14887320, 14887320, 14887320,
+14887320, 14887320, 14887320, 14887320, 14887320, 14669760, 14514360, 14421120, 14374500, 14358960,
+14358960, 14358960, 14203560, 14110320, 14063700, 14048160, 14048160, 14048160, 13954920, 13908300,
+13892760, 13892760, 13892760, 13846140, 13830600, 13830600, 13830600, 13830600, 13830600, 13830600,
+13830600, 13830600, 13830600, 13690740, 13597500, 13550880, 13535340, 13535340, 13535340, 13442100,
+13395480, 13379940, 13379940, 13379940, 13333320, 13317780, 13317780, 13317780, 13317780, 13317780,
+13317780, 13317780, 13317780, 13317780, 13240080, 13193460, 13177920, 13177920, 13177920, 13131300,
+13115760, 13115760, 13115760, 13115760, 13115760, 13115760, 13115760, 13115760, 13115760, 13084680,
+13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140,
+13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 13069140, 12913740,
+12758340, 12665100, 12618480, 12602940, 12602940, 12602940, 12447540, 12354300, 12307680, 12292140,
+12292140, 12292140, 12198900, 12152280, 12136740, 12136740, 12136740, 12090120, 12074580, 12074580,
+12074580, 12074580, 12074580, 12074580, 12074580, 12074580, 12074580, 11934720, 11841480, 11794860,
+11779320, 11779320, 11779320, 11686080, 11639460, 11623920, 11623920, 11623920, 11577300, 11561760,
+11561760, 11561760, 11561760, 11561760, 11561760, 11561760, 11561760, 11561760, 11484060, 11437440,
+11421900, 11421900, 11421900, 11375280, 11359740, 11359740, 11359740, 11359740, 11359740, 11359740,
+11359740, 11359740, 11359740, 11328660, 11313120, 11313120, 11313120, 11313120, 11313120, 11313120,
+11313120, 11313120, 11313120, 11313120, 11313120, 11313120, 11313120, 11313120, 11313120, 11313120,
+11313120, 11313120, 11313120, 11219880, 11126640, 11080020, 11064480, 11064480, 11064480, 10971240,
+10924620, 10909080, 10909080, 10909080, 10862460, 10846920, 10846920, 10846920, 10846920, 10846920,
+10846920, 10846920, 10846920, 10846920, 10769220, 10722600, 10707060, 10707060, 10707060, 10660440,
+10644900, 10644900, 10644900, 10644900, 10644900, 10644900, 10644900, 10644900, 10644900, 10613820,
+10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280,
+10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10598280, 10551660,
+10505040, 10489500, 10489500, 10489500, 10442880, 10427340, 10427340, 10427340, 10427340, 10427340,
+10427340, 10427340, 10427340, 10427340, 10396260, 10380720, 10380720, 10380720, 10380720, 10380720,
+10380720, 10380720, 10380720, 10380720, 10380720, 10380720, 10380720, 10380720, 10380720, 10380720,
+10380720, 10380720, 10380720, 10380720, 10365180, 10349640, 10349640, 10349640, 10349640, 10349640,
+10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640,
+10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640,
+10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10349640, 10116540, 9961140,
+9867900, 9821280, 9805740, 9805740, 9805740, 9572640, 9417240, 9324000, 9277380, 9261840,
+9261840, 9261840, 9106440, 9013200, 8966580, 8951040, 8951040, 8951040, 8857800, 8811180,
+8795640, 8795640, 8795640, 8749020, 8733480, 8733480, 8733480, 8717940, 8717940, 8717940,
+8717940, 8717940, 8717940, 8500380, 8344980, 8251740, 8205120, 8189580, 8189580, 8189580,
+8034180, 7940940, 7894320, 7878780, 7878780, 7878780, 7785540, 7738920, 7723380, 7723380,
+7723380, 7676760, 7661220, 7661220, 7661220, 7645680, 7645680, 7645680, 7645680, 7645680,
+7645680, 7505820, 7412580, 7365960, 7350420, 7350420, 7350420, 7257180, 7210560, 7195020,
+7195020, 7195020, 7148400, 7132860, 7132860, 7132860, 7117320, 7117320, 7117320, 7117320,
+7117320, 7117320, 7039620, 6993000, 6977460, 6977460, 6977460, 6930840, 6915300, 6915300,
+6915300, 6899760, 6899760, 6899760, 6899760, 6899760, 6899760, 6868680, 6853140, 6853140,
+6853140, 6837600, 6837600, 6837600, 6837600, 6837600, 6837600, 6837600, 6837600, 6837600,
+6837600, 6837600, 6837600, 6837600, 6837600, 6837600, 6837600, 6682200, 6526800, 6433560,
+6386940, 6371400, 6371400, 6371400, 6216000, 6122760, 6076140, 6060600, 6060600, 6060600,
+5967360, 5920740, 5905200, 5905200, 5905200, 5858580, 5843040, 5843040, 5843040, 5827500,
+5827500, 5827500, 5827500, 5827500, 5827500, 5687640, 5594400, 5547780, 5532240, 5532240,
+5532240, 5439000, 5392380, 5376840, 5376840, 5376840, 5330220, 5314680, 5314680, 5314680,
+5299140, 5299140, 5299140, 5299140, 5299140, 5299140, 5221440, 5174820, 5159280, 5159280,
+5159280, 5112660, 5097120, 5097120, 5097120, 5081580, 5081580, 5081580, 5081580, 5081580,
+5081580, 5050500, 5034960, 5034960, 5034960, 5019420, 5019420, 5019420, 5019420, 5019420,
+5019420, 5019420, 5019420, 5019420, 5019420, 5019420, 5019420, 5019420, 5019420, 5019420,
+5019420, 4926180, 4832940, 4786320, 4770780, 4770780, 4770780, 4677540, 4630920, 4615380,
+4615380, 4615380, 4568760, 4553220, 4553220, 4553220, 4537680, 4537680, 4537680, 4537680,
+4537680, 4537680, 4459980, 4413360, 4397820, 4397820, 4397820, 4351200, 4335660, 4335660,
+4335660, 4320120, 4320120, 4320120, 4320120, 4320120, 4320120, 4289040, 4273500, 4273500,
+4273500, 4257960, 4257960, 4257960, 4257960, 4257960, 4257960, 4257960, 4257960, 4257960,
+4257960, 4257960, 4257960, 4257960, 4257960, 4257960, 4257960, 4211340, 4164720, 4149180,
+4149180, 4149180, 4102560, 4087020, 4087020, 4087020, 4071480, 4071480, 4071480, 4071480,
+4071480, 4071480, 4040400, 4024860, 4024860, 4024860, 4009320, 4009320, 4009320, 4009320,
+4009320, 4009320, 4009320, 4009320, 4009320, 4009320, 4009320, 4009320, 4009320, 4009320,
+4009320, 4009320, 3993780, 3978240, 3978240, 3978240, 3962700, 3962700, 3962700, 3962700,
+3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700,
+3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700,
+3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3962700, 3807300, 3714060, 3667440,
+3651900, 3651900, 3651900, 3496500, 3403260, 3356640, 3341100, 3341100, 3341100, 3247860,
+3201240, 3185700, 3185700, 3185700, 3139080, 3123540, 3123540, 3123540, 3108000, 3108000,
+3108000, 3108000, 3108000, 3108000, 2968140, 2874900, 2828280, 2812740, 2812740, 2812740,
+2719500, 2672880, 2657340, 2657340, 2657340, 2610720, 2595180, 2595180, 2595180, 2579640,
+2579640, 2579640, 2579640, 2579640, 2579640, 2501940, 2455320, 2439780, 2439780, 2439780,
+2393160, 2377620, 2377620, 2377620, 2362080, 2362080, 2362080, 2362080, 2362080, 2362080,
+2331000, 2315460, 2315460, 2315460, 2299920, 2299920, 2299920, 2299920, 2299920, 2299920,
+2299920, 2299920, 2299920, 2299920, 2299920, 2299920, 2299920, 2299920, 2299920, 2299920,
+2206680, 2113440, 2066820, 2051280, 2051280, 2051280, 1958040, 1911420, 1895880, 1895880,
+1895880, 1849260, 1833720, 1833720, 1833720, 1818180, 1818180, 1818180, 1818180, 1818180,
+1818180, 1740480, 1693860, 1678320, 1678320, 1678320, 1631700, 1616160, 1616160, 1616160,
+1600620, 1600620, 1600620, 1600620, 1600620, 1600620, 1569540, 1554000, 1554000, 1554000,
+1538460, 1538460, 1538460, 1538460, 1538460, 1538460, 1538460, 1538460, 1538460, 1538460,
+1538460, 1538460, 1538460, 1538460, 1538460, 1538460, 1491840, 1445220, 1429680, 1429680,
+1429680, 1383060, 1367520, 1367520, 1367520, 1351980, 1351980, 1351980, 1351980, 1351980,
+1351980, 1320900, 1305360, 1305360, 1305360, 1289820, 1289820, 1289820, 1289820, 1289820,
+1289820, 1289820, 1289820, 1289820, 1289820, 1289820, 1289820, 1289820, 1289820, 1289820,
+1289820, 1274280, 1258740, 1258740, 1258740, 1243200, 1243200, 1243200, 1243200, 1243200,
+1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200,
+1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1243200,
+1243200, 1243200, 1243200, 1243200, 1243200, 1243200, 1149960, 1103340, 1087800, 1087800,
+1087800, 994560, 947940, 932400, 932400, 932400, 885780, 870240, 870240, 870240,
+854700, 854700, 854700, 854700, 854700, 854700, 777000, 730380, 714840, 714840,
+714840, 668220, 652680, 652680, 652680, 637140, 637140, 637140, 637140, 637140,
+637140, 606060, 590520, 590520, 590520, 574980, 574980, 574980, 574980, 574980,
+574980, 574980, 574980, 574980, 574980, 574980, 574980, 574980, 574980, 574980,
+574980, 528360, 481740, 466200, 466200, 466200, 419580, 404040, 404040, 404040,
+388500, 388500, 388500, 388500, 388500, 388500, 357420, 341880, 341880, 341880,
+326340, 326340, 326340, 326340, 326340, 326340, 326340, 326340, 326340, 326340,
+326340, 326340, 326340, 326340, 326340, 326340, 310800, 295260, 295260, 295260,
+279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720,
+279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720,
+279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720, 279720,
+279720, 233100, 217560, 217560, 217560, 170940, 155400, 155400, 155400, 139860,
+139860, 139860, 139860, 139860, 139860, 108780, 93240, 93240, 93240, 77700,
+77700, 77700, 77700, 77700, 77700, 77700, 77700, 77700, 77700, 77700,
+77700, 77700, 77700, 77700, 77700, 62160, 46620, 46620, 46620, 31080,
+31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080,
+31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080,
+31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080, 31080,
+15540, 15540, 15540, 0, 0, 0, 0, 0, 0, 0,
+0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+0, 0,
+];
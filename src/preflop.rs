@@ -0,0 +1,114 @@
+use crate::preflop_tables::{HEADS_UP, VS_RANDOM};
+use crate::starting_hand::StartingHand;
+
+/// Largest number of random opponents the tables cover
+pub const MAX_OPPONENTS: usize = 9;
+
+/// All-in equity of `hero` against `villain` before the flop, averaged over
+/// every combo of the two classes that can be dealt together.
+///
+/// Looked up from a table enumerated over every board, exact to the nearest
+/// hundredth of a percent.
+pub fn heads_up_equity(hero: StartingHand, villain: StartingHand) -> f64 {
+    let (i, j) = (hero.index(), villain.index());
+    if i <= j {
+        from_basis_points(HEADS_UP[triangle_index(i, j)])
+    } else {
+        1.0 - from_basis_points(HEADS_UP[triangle_index(j, i)])
+    }
+}
+
+/// All-in equity of `hand` against `opponents` players holding any two cards,
+/// or `None` for more than `MAX_OPPONENTS` or no opponents at all.
+///
+/// Exact against one opponent. Against more, the table holds Monte Carlo
+/// estimates of 20,000 deals each, with a standard error of at most 0.35
+/// percentage points.
+pub fn equity_vs_random(hand: StartingHand, opponents: usize) -> Option<f64> {
+    if opponents == 0 || opponents > MAX_OPPONENTS {
+        return None;
+    }
+    let entry = VS_RANDOM[hand.index() * MAX_OPPONENTS + opponents - 1];
+    Some(from_basis_points(entry))
+}
+
+/// Position of the `i <= j` matchup in the upper triangle of the 169x169
+/// matrix, stored row by row.
+fn triangle_index(i: usize, j: usize) -> usize {
+    i * StartingHand::COUNT - i * (i + 1) / 2 + j
+}
+
+fn from_basis_points(value: u16) -> f64 {
+    f64::from(value) / 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(notation: &str) -> StartingHand {
+        notation.parse().unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {} to be about {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn covers_the_whole_triangle() {
+        let n = StartingHand::COUNT;
+        assert_eq!(triangle_index(0, 0), 0);
+        assert_eq!(triangle_index(0, n - 1) + 1, triangle_index(1, 1));
+        assert_eq!(triangle_index(n - 1, n - 1), HEADS_UP.len() - 1);
+        assert_eq!(VS_RANDOM.len(), n * MAX_OPPONENTS);
+    }
+
+    #[test]
+    fn knows_well_known_matchups() {
+        assert_close(heads_up_equity(hand("AA"), hand("KK")), 0.82);
+        assert_close(heads_up_equity(hand("AKo"), hand("QQ")), 0.43);
+        assert_close(heads_up_equity(hand("22"), hand("AKs")), 0.50);
+        assert_close(heads_up_equity(hand("KK"), hand("AKo")), 0.70);
+        assert_eq!(heads_up_equity(hand("T9s"), hand("T9s")), 0.5);
+    }
+
+    #[test]
+    fn matches_published_exact_equities() {
+        assert_eq!(heads_up_equity(hand("AA"), hand("KK")), 0.8195);
+        assert_eq!(equity_vs_random(hand("AA"), 1), Some(0.852));
+        assert_eq!(equity_vs_random(hand("72o"), 1), Some(0.3458));
+    }
+
+    #[test]
+    fn both_sides_of_a_matchup_add_up() {
+        for hero in StartingHand::all() {
+            for villain in StartingHand::all() {
+                let total = heads_up_equity(hero, villain) + heads_up_equity(villain, hero);
+                assert!((total - 1.0).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn loses_equity_with_every_opponent() {
+        assert_close(equity_vs_random(hand("AA"), 1).unwrap(), 0.85);
+        assert_close(equity_vs_random(hand("72o"), 1).unwrap(), 0.35);
+        assert_close(equity_vs_random(hand("AA"), 9).unwrap(), 0.31);
+
+        for hand in StartingHand::all() {
+            for opponents in 1..MAX_OPPONENTS {
+                let fewer = equity_vs_random(hand, opponents).unwrap();
+                let more = equity_vs_random(hand, opponents + 1).unwrap();
+                assert!(more < fewer, "{} against {}", hand, opponents + 1);
+            }
+        }
+
+        assert_eq!(equity_vs_random(hand("AA"), 0), None);
+        assert_eq!(equity_vs_random(hand("AA"), 10), None);
+    }
+}
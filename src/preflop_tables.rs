@@ -0,0 +1,1334 @@
+// Generated by examples/generate_preflop_tables.rs, do not edit.
+// All-in equities in basis points. Heads-up equities are exact,
+// equities against two or more random hands come from 20000 trials per entry.
+
+pub const HEADS_UP: &[u16; 14365] = &[
+5000, 8786, 8746, 8706, 8666, 8843, 8803, 8803, 8823, 8669, 8709, 8749,
+8789, 9317, 8195, 8294, 8253, 8213, 8273, 8392, 8351, 8350, 8376, 8416,
+8457, 8498, 9274, 8712, 8155, 8089, 8048, 8108, 8227, 8351, 8310, 8335,
+8376, 8417, 8457, 9230, 8668, 8493, 8115, 7884, 7944, 8063, 8187, 8310,
+8295, 8336, 8376, 8417, 9186, 8624, 8449, 8274, 8075, 7780, 7899, 8023,
+8145, 8295, 8295, 8336, 8376, 9378, 8687, 8512, 8336, 8161, 8086, 7794,
+7918, 8041, 8190, 8355, 8355, 8396, 9335, 8814, 8639, 8464, 8288, 8178,
+8046, 7754, 7876, 8026, 8191, 8355, 8355, 9335, 8771, 8771, 8595, 8420,
+8310, 8134, 8048, 7753, 7902, 8067, 8231, 8396, 9356, 8770, 8726, 8726,
+8550, 8440, 8265, 8133, 8046, 7750, 7915, 8079, 8244, 9190, 8797, 8754,
+8710, 8710, 8599, 8424, 8293, 8131, 8089, 7891, 8055, 8220, 9234, 8841,
+8798, 8754, 8710, 8775, 8600, 8468, 8306, 8281, 8133, 8096, 8260, 9278,
+8886, 8842, 8798, 8754, 8776, 8776, 8644, 8482, 8457, 8501, 8177, 8301,
+9322, 8930, 8886, 8842, 8799, 8820, 8776, 8820, 8658, 8633, 8677, 8721,
+8222, 5000, 7128, 7101, 7074, 7119, 7094, 7070, 7093, 6982, 7024, 7067,
+7110, 5249, 3411, 7143, 7116, 7089, 7273, 7248, 7225, 7231, 7273, 7318,
+7363, 7409, 7545, 7559, 4605, 6353, 6322, 6445, 6528, 6619, 6583, 6612,
+6646, 6680, 6716, 7515, 7529, 6644, 4596, 6201, 6324, 6407, 6497, 6583,
+6581, 6615, 6650, 6685, 7485, 7499, 6612, 6484, 4594, 6202, 6285, 6376,
+6461, 6581, 6585, 6619, 6655, 7539, 7703, 6741, 6613, 6485, 4742, 6288,
+6260, 6345, 6465, 6590, 6594, 6629, 7510, 7676, 6829, 6702, 6574, 6581,
+4752, 6140, 6225, 6345, 6470, 6596, 6601, 7483, 7649, 6924, 6796, 6668,
+6550, 6424, 4769, 6106, 6226, 6351, 6477, 6603, 7508, 7655, 6886, 6886,
+6758, 6640, 6514, 6388, 4769, 6109, 6235, 6360, 6487, 7386, 7700, 6915,
+6883, 6883, 6765, 6639, 6513, 6392, 4803, 6134, 6260, 6386, 7432, 7748,
+6952, 6919, 6887, 6897, 6770, 6645, 6524, 6416, 4859, 6295, 6421, 7479,
+7797, 6989, 6957, 6924, 6901, 6903, 6778, 6656, 6548, 6586, 4921, 6457,
+7526, 7847, 7027, 6995, 6962, 6939, 6909, 6911, 6790, 6682, 6719, 6757,
+4989, 5000, 7081, 7055, 7066, 7041, 7053, 7042, 6931, 6973, 7016, 7058,
+2988, 3185, 7142, 6177, 6146, 6265, 6348, 6331, 6295, 6324, 6358, 6392,
+6428, 5249, 7558, 3427, 6997, 6970, 7154, 7129, 7244, 7216, 7259, 7304,
+7349, 7395, 7496, 6467, 7400, 4588, 6220, 6340, 6423, 6528, 6611, 6609,
+6644, 6678, 6714, 7466, 6434, 7370, 6503, 4586, 6218, 6302, 6406, 6489,
+6610, 6613, 6648, 6683, 7483, 6560, 7573, 6629, 6501, 4730, 6185, 6290,
+6342, 6462, 6587, 6591, 6627, 7454, 6648, 7546, 6717, 6589, 6471, 4740,
+6170, 6222, 6342, 6468, 6593, 6598, 7468, 6630, 7670, 6828, 6700, 6582,
+6456, 4811, 6145, 6265, 6390, 6515, 6642, 7456, 6592, 7639, 6915, 6787,
+6636, 6510, 6429, 4802, 6118, 6243, 6369, 6495, 7334, 6621, 7684, 6912,
+6912, 6761, 6635, 6554, 6400, 4836, 6142, 6268, 6394, 7380, 6658, 7732,
+6948, 6916, 6893, 6766, 6686, 6532, 6424, 4891, 6303, 6429, 7426, 6695,
+7781, 6986, 6953, 6897, 6899, 6819, 6665, 6557, 6594, 4953, 6465, 7473,
+6733, 7831, 7024, 6991, 6935, 6905, 6952, 6798, 6690, 6727, 6765, 5021,
+5000, 6987, 7006, 6980, 6963, 6989, 6844, 6886, 6928, 6970, 3017, 3221,
+5897, 7127, 6178, 6297, 6380, 6331, 6333, 6332, 6366, 6401, 6436, 3034,
+6181, 3197, 6990, 6065, 6184, 6267, 6351, 6326, 6325, 6359, 6393, 6429,
+5249, 7542, 7392, 3451, 6851, 7035, 7010, 7125, 7235, 7244, 7289, 7334,
+7380, 7394, 6467, 6347, 7240, 4578, 6238, 6321, 6423, 6520, 6638, 6641,
+6676, 6711, 7420, 6592, 6472, 7444, 6520, 4723, 6174, 6276, 6373, 6486,
+6611, 6615, 6650, 7391, 6680, 6560, 7417, 6609, 6457, 4733, 6156, 6253,
+6366, 6491, 6617, 6622, 7372, 6629, 6649, 7540, 6716, 6565, 6439, 4799,
+6175, 6262, 6387, 6513, 6639, 7401, 6633, 6624, 7660, 6818, 6667, 6541,
+6461, 4843, 6156, 6281, 6407, 6534, 7242, 6630, 6621, 7668, 6941, 6785,
+6659, 6550, 6441, 4868, 6150, 6276, 6402, 7288, 6666, 6657, 7716, 6945,
+6917, 6790, 6682, 6573, 6433, 4923, 6311, 6437, 7334, 6703, 6695, 7765,
+6982, 6921, 6923, 6815, 6706, 6565, 6602, 4985, 6473, 7380, 6742, 6733,
+7815, 7020, 6959, 6929, 6948, 6839, 6699, 6736, 6774, 5054, 5000, 6915,
+6889, 6868, 6865, 6756, 6765, 6806, 6848, 3047, 3257, 5905, 5901, 7113,
+6328, 6411, 6358, 6334, 6370, 6374, 6409, 6444, 3063, 6189, 3234, 5805,
+6975, 6215, 6298, 6383, 6326, 6363, 6367, 6402, 6437, 3132, 6184, 6082,
+3217, 6837, 6102, 6185, 6270, 6346, 6356, 6360, 6394, 6430, 5249, 7526,
+7376, 7226, 3480, 6916, 6891, 7006, 7116, 7263, 7275, 7320, 7366, 7322,
+6624, 6504, 6384, 7314, 4715, 6188, 6290, 6384, 6516, 6639, 6643, 6679,
+7293, 6712, 6592, 6472, 7287, 6471, 4725, 6170, 6265, 6397, 6519, 6645,
+6650, 7271, 6656, 6682, 6562, 7410, 6579, 6452, 4791, 6161, 6293, 6411,
+6536, 6663, 7268, 6632, 6623, 6643, 7530, 6678, 6552, 6443, 4831, 6187,
+6279, 6405, 6531, 7150, 6671, 6662, 6653, 7689, 6816, 6690, 6582, 6473,
+4909, 6189, 6315, 6441, 7159, 6675, 6666, 6657, 7700, 6946, 6820, 6706,
+6569, 6474, 4955, 6319, 6445, 7204, 6712, 6703, 6694, 7750, 6950, 6952,
+6839, 6702, 6606, 6611, 5017, 6481, 7250, 6750, 6741, 6732, 7799, 6989,
+6958, 6972, 6835, 6740, 6744, 6782, 5086, 5000, 6670, 6652, 6646, 6508,
+6553, 6561, 6603, 3004, 3195, 5746, 5741, 5738, 7110, 6295, 6242, 6213,
+6223, 6265, 6269, 6305, 3058, 6025, 3172, 5646, 5642, 6973, 6182, 6266,
+6205, 6216, 6258, 6262, 6297, 3119, 6020, 5918, 3156, 5547, 6836, 6070,
+6154, 6231, 6209, 6251, 6255, 6291, 3214, 6015, 5913, 5811, 3146, 6699,
+5958, 6042, 6119, 6230, 6245, 6249, 6285, 5249, 7529, 7379, 7229, 7081,
+3360, 6759, 6873, 6984, 7131, 7280, 7292, 7338, 7060, 6594, 6474, 6354,
+6235, 7144, 4570, 6137, 6231, 6360, 6497, 6621, 6626, 7041, 6538, 6563,
+6443, 6325, 7267, 6418, 4636, 6122, 6251, 6389, 6512, 6638, 7034, 6508,
+6499, 6525, 6406, 7387, 6517, 6403, 4676, 6120, 6257, 6375, 6502, 6885,
+6519, 6510, 6502, 6523, 7546, 6652, 6538, 6401, 4750, 6167, 6259, 6386,
+6934, 6565, 6556, 6548, 6540, 7708, 6797, 6683, 6547, 6451, 4849, 6305,
+6431, 6943, 6569, 6560, 6552, 6545, 7720, 6927, 6814, 6672, 6548, 6598,
+4902, 6436, 6988, 6607, 6599, 6590, 6583, 7770, 6933, 6947, 6805, 6682,
+6731, 6736, 4970, 5000, 6442, 6441, 6300, 6315, 6360, 6368, 3032, 3193,
+5691, 5690, 5687, 5852, 7234, 6265, 6236, 6242, 6257, 6300, 6305, 3086,
+5970, 3204, 5619, 5616, 5781, 7097, 6290, 6232, 6238, 6254, 6296, 6301,
+3147, 5968, 5891, 3193, 5525, 5690, 6960, 6181, 6258, 6231, 6247, 6290,
+6294, 3242, 5963, 5886, 5789, 3183, 5595, 6823, 6069, 6146, 6257, 6241,
+6284, 6288, 3475, 6137, 6060, 5962, 5861, 3241, 6729, 6001, 6078, 6189,
+6306, 6321, 6326, 5249, 7663, 7513, 7364, 7215, 7114, 3405, 6754, 6865,
+7012, 7161, 7311, 7323, 6815, 6561, 6587, 6471, 6352, 6281, 7138, 4628,
+6142, 6271, 6405, 6543, 6667, 6814, 6531, 6527, 6553, 6434, 6363, 7258,
+6422, 4669, 6134, 6268, 6406, 6530, 6661, 6537, 6532, 6524, 6551, 6480,
+7416, 6557, 6415, 4742, 6152, 6290, 6409, 6678, 6555, 6550, 6542, 6535,
+6604, 7578, 6699, 6557, 6434, 4837, 6336, 6429, 6728, 6602, 6597, 6589,
+6581, 6621, 7741, 6845, 6703, 6580, 6629, 4943, 6475, 6737, 6607, 6602,
+6594, 6587, 6627, 7754, 6976, 6834, 6706, 6727, 6777, 5002, 5000, 6153,
+6019, 6032, 6047, 6091, 3059, 3229, 5699, 5698, 5695, 5860, 5974, 7220,
+6267, 6273, 6284, 6300, 6343, 3074, 5978, 3188, 5544, 5545, 5710, 5824,
+7201, 6229, 6234, 6245, 6261, 6305, 3167, 5976, 5815, 3206, 5474, 5640,
+5753, 7064, 6254, 6231, 6242, 6259, 6302, 3266, 5971, 5814, 5738, 3202,
+5549, 5663, 6928, 6146, 6257, 6236, 6253, 6296, 3496, 6145, 5988, 5912,
+5815, 3260, 5612, 6833, 6078, 6189, 6306, 6290, 6333, 3720, 6264, 6106,
+6030, 5933, 5880, 3264, 6698, 5967, 6079, 6195, 6313, 6329, 5249, 7647,
+7627, 7477, 7328, 7227, 7080, 3442, 6760, 6907, 7056, 7206, 7356, 6504,
+6564, 6523, 6549, 6434, 6363, 6245, 7144, 4629, 6145, 6280, 6414, 6553,
+6359, 6570, 6529, 6524, 6551, 6480, 6362, 7302, 6426, 4702, 6158, 6293,
+6432, 6373, 6582, 6541, 6537, 6529, 6604, 6486, 7464, 6568, 6439, 4798,
+6313, 6451, 6391, 6601, 6560, 6556, 6548, 6588, 6611, 7627, 6711, 6582,
+6604, 4899, 6498, 6441, 6648, 6607, 6603, 6595, 6636, 6629, 7791, 6857,
+6729, 6750, 6801, 5012, 5000, 5674, 5696, 5713, 5757, 3034, 3193, 5699,
+5660, 5660, 5825, 5939, 5937, 7216, 6272, 6283, 6294, 6310, 3086, 5978,
+3188, 5544, 5545, 5710, 5824, 5937, 7182, 6242, 6252, 6264, 6280, 3140,
+5937, 5815, 3154, 5391, 5560, 5674, 5788, 7164, 6204, 6214, 6226, 6242,
+3271, 5936, 5814, 5654, 3179, 5491, 5605, 5718, 7028, 6229, 6212, 6224,
+6239, 3505, 6109, 5988, 5831, 5756, 3242, 5558, 5672, 6934, 6165, 6282,
+6261, 6277, 3722, 6228, 6106, 5950, 5874, 5825, 3246, 5578, 6798, 6055,
+6171, 6288, 6272, 4030, 6225, 6225, 6068, 5993, 5944, 5844, 3263, 6697,
+5975, 6092, 6209, 6330, 5249, 7643, 7606, 7586, 7438, 7336, 7189, 7078,
+3450, 6769, 6918, 7068, 7218, 5987, 6569, 6536, 6496, 6522, 6455, 6338,
+6253, 7153, 4640, 6140, 6275, 6413, 6012, 6582, 6549, 6508, 6505, 6579,
+6461, 6377, 7315, 6420, 4735, 6290, 6428, 6031, 6595, 6562, 6522, 6518,
+6559, 6586, 6502, 7477, 6562, 6579, 4837, 6447, 6080, 6613, 6581, 6540,
+6537, 6577, 6571, 6630, 7641, 6709, 6725, 6747, 4949, 5000, 5509, 5532,
+5543, 3154, 3334, 5747, 5717, 5679, 5848, 5961, 5960, 5962, 7243, 6400,
+6412, 6423, 3206, 6028, 3329, 5601, 5563, 5732, 5846, 5960, 5932, 7210,
+6370, 6381, 6393, 3297, 5995, 5873, 3331, 5448, 5617, 5731, 5845, 5933,
+7178, 6340, 6351, 6363, 3389, 5955, 5833, 5712, 3304, 5465, 5582, 5696,
+5784, 7160, 6302, 6314, 6325, 3655, 6132, 6010, 5889, 5729, 3396, 5556,
+5670, 5758, 7066, 6372, 6355, 6367, 3876, 6251, 6129, 6008, 5852, 5824,
+3406, 5581, 5669, 6931, 6265, 6383, 6362, 4176, 6248, 6248, 6126, 5971,
+5943, 5847, 3423, 5607, 6829, 6186, 6303, 6420, 4548, 6251, 6218, 6219,
+6063, 6035, 5939, 5873, 3438, 6698, 6104, 6221, 6338, 5250, 7672, 7636,
+7600, 7581, 7480, 7332, 7222, 7080, 3647, 6920, 7069, 7218, 5805, 6701,
+6669, 6637, 6597, 6671, 6558, 6473, 6386, 7312, 4800, 6447, 6582, 5832,
+6715, 6682, 6650, 6610, 6655, 6682, 6598, 6510, 7474, 6741, 4901, 6598,
+5845, 6728, 6696, 6664, 6624, 6668, 6662, 6723, 6635, 7636, 6884, 6901,
+5010, 5000, 5276, 5302, 3108, 3297, 5739, 5709, 5679, 5809, 5927, 5925,
+5928, 6048, 7258, 6404, 6415, 3161, 6019, 3293, 5593, 5563, 5694, 5811,
+5925, 5897, 6018, 7225, 6373, 6385, 3252, 5987, 5865, 3295, 5448, 5579,
+5697, 5810, 5898, 5988, 7192, 6343, 6355, 3381, 5955, 5833, 5712, 3304,
+5465, 5582, 5696, 5784, 5989, 7160, 6314, 6325, 3607, 6093, 5971, 5850,
+5729, 3344, 5473, 5591, 5679, 5884, 7185, 6320, 6332, 3861, 6215, 6094,
+5972, 5852, 5740, 3383, 5522, 5610, 5815, 7050, 6347, 6331, 4164, 6212,
+6212, 6091, 5970, 5862, 5788, 3405, 5552, 5757, 6948, 6272, 6389, 4525,
+6215, 6183, 6183, 6063, 5955, 5880, 5818, 3420, 5675, 6817, 6189, 6306,
+4721, 6338, 6305, 6273, 6275, 6167, 6092, 6030, 5942, 3369, 6843, 6102,
+6219, 5250, 7689, 7652, 7616, 7581, 7610, 7462, 7351, 7210, 7232, 3690,
+7084, 7233, 5555, 6706, 6674, 6642, 6610, 6618, 6646, 6565, 6478, 6385,
+7490, 4869, 6590, 5585, 6720, 6687, 6655, 6624, 6632, 6629, 6690, 6603,
+6510, 7653, 6893, 4978, 5000, 5272, 3062, 3261, 5731, 5700, 5670, 5809,
+5889, 5891, 5893, 6014, 6048, 7272, 6407, 3115, 6011, 3256, 5585, 5555,
+5694, 5773, 5891, 5863, 5983, 6018, 7239, 6376, 3207, 5978, 5856, 3259,
+5440, 5579, 5658, 5776, 5864, 5953, 5988, 7207, 6346, 3336, 5946, 5824,
+5703, 3268, 5465, 5544, 5661, 5749, 5954, 5959, 7175, 6317, 3599, 6093,
+5971, 5850, 5729, 3344, 5473, 5591, 5679, 5884, 6004, 7185, 6332, 3813,
+6176, 6054, 5933, 5813, 5740, 3331, 5439, 5527, 5736, 5856, 7169, 6295,
+4149, 6177, 6177, 6056, 5935, 5862, 5704, 3382, 5490, 5699, 5819, 7067,
+6353, 4508, 6180, 6147, 6148, 6027, 5954, 5796, 5755, 3397, 5617, 5736,
+6936, 6271, 4696, 6303, 6270, 6238, 6239, 6166, 6012, 5971, 5883, 3351,
+5653, 6962, 6188, 4974, 6340, 6307, 6275, 6244, 6293, 6138, 6097, 6009,
+5920, 3413, 7000, 6224, 5251, 7705, 7669, 7633, 7598, 7610, 7592, 7482,
+7340, 7362, 7404, 3740, 7248, 5553, 6712, 6679, 6647, 6615, 6632, 6593,
+6654, 6567, 6478, 6516, 7669, 4945, 5000, 3016, 3225, 5723, 5692, 5662,
+5801, 5889, 5852, 5855, 5979, 6014, 6048, 7287, 3069, 6002, 3220, 5577,
+5547, 5685, 5773, 5852, 5825, 5949, 5983, 6018, 7254, 3161, 5970, 5848,
+3222, 5432, 5571, 5658, 5737, 5825, 5919, 5953, 5988, 7221, 3290, 5938,
+5816, 5695, 3231, 5456, 5544, 5623, 5711, 5920, 5924, 5959, 7189, 3554,
+6084, 5963, 5841, 5721, 3308, 5473, 5553, 5640, 5849, 5969, 5973, 7200,
+3805, 6176, 6054, 5933, 5813, 5740, 3331, 5439, 5527, 5736, 5856, 5976,
+7169, 4101, 6137, 6137, 6016, 5896, 5823, 5704, 3330, 5386, 5620, 5739,
+5859, 7186, 4461, 6140, 6108, 6108, 5988, 5915, 5796, 5649, 3345, 5537,
+5657, 5777, 7055, 4686, 6267, 6235, 6203, 6204, 6131, 6012, 5890, 5803,
+3333, 5598, 5718, 7081, 4947, 6304, 6272, 6240, 6208, 6257, 6138, 6016,
+5929, 5865, 3395, 5754, 7119, 4979, 6342, 6309, 6277, 6245, 6262, 6264,
+6143, 6056, 5992, 6030, 3465, 7157, 5251, 7722, 7686, 7650, 7615, 7627,
+7593, 7612, 7470, 7493, 7534, 7576, 3796, 5000, 3012, 7042, 7013, 6983,
+7175, 7149, 7123, 7129, 7176, 7224, 7273, 7323, 7439, 7477, 4324, 6107,
+6075, 6200, 6284, 6379, 6341, 6373, 6409, 6446, 6483, 7407, 7445, 6483,
+4315, 5949, 6074, 6158, 6253, 6341, 6340, 6377, 6413, 6451, 7374, 7412,
+6448, 6312, 4312, 5948, 6032, 6127, 6214, 6341, 6344, 6381, 6418, 7424,
+7624, 6581, 6445, 6309, 4473, 6033, 6003, 6091, 6217, 6347, 6351, 6389,
+7393, 7594, 6671, 6535, 6399, 6406, 4484, 5879, 5967, 6093, 6223, 6353,
+6358, 7363, 7565, 6773, 6637, 6501, 6373, 6239, 4502, 5843, 5969, 6099,
+6230, 6361, 7389, 7571, 6731, 6731, 6595, 6467, 6333, 6200, 4501, 5847,
+5977, 6107, 6239, 7258, 7620, 6765, 6730, 6730, 6602, 6468, 6334, 6203,
+4538, 5876, 6006, 6138, 7308, 7672, 6804, 6769, 6734, 6742, 6608, 6474,
+6343, 6235, 4597, 6043, 6175, 7358, 7724, 6844, 6809, 6774, 6746, 6748,
+6615, 6484, 6376, 6415, 4662, 6212, 7408, 7777, 6885, 6850, 6815, 6787,
+6754, 6757, 6626, 6517, 6557, 6598, 4735, 5000, 8613, 8573, 8533, 8710,
+8842, 8802, 8802, 8800, 8840, 8880, 8920, 7179, 9130, 8193, 8230, 8189,
+8220, 8259, 8378, 8337, 8335, 8375, 8416, 8456, 7139, 9087, 8644, 8154,
+8025, 8055, 8094, 8213, 8337, 8294, 8335, 8375, 8416, 7100, 9043, 8600,
+8425, 8114, 7891, 7930, 8049, 8173, 8294, 8294, 8335, 8376, 7164, 9234,
+8632, 8456, 8281, 8082, 7825, 7906, 8030, 8151, 8316, 8316, 8356, 7167,
+9377, 8672, 8496, 8321, 8211, 8087, 7793, 7917, 8039, 8203, 8368, 8368,
+7127, 9333, 8799, 8624, 8448, 8296, 8178, 8047, 7753, 7874, 8039, 8204,
+8368, 7167, 9334, 8755, 8755, 8580, 8428, 8309, 8134, 8049, 7751, 7915,
+8080, 8244, 7015, 9331, 8753, 8709, 8709, 8557, 8438, 8263, 8131, 8048,
+7748, 7913, 8077, 7055, 9375, 8797, 8753, 8709, 8733, 8614, 8438, 8307,
+8129, 8092, 7954, 8118, 7095, 9419, 8841, 8797, 8754, 8733, 8790, 8614,
+8483, 8305, 8349, 8137, 8159, 7135, 9463, 8885, 8842, 8798, 8778, 8790,
+8790, 8659, 8481, 8525, 8569, 8181, 5000, 7085, 7058, 7068, 7105, 7080,
+7055, 7061, 7103, 7146, 7189, 2959, 5249, 3534, 7084, 7057, 7101, 7242,
+7218, 7195, 7203, 7247, 7292, 7337, 4368, 7496, 7492, 4633, 6334, 6432,
+6524, 6608, 6699, 6669, 6703, 6738, 6773, 4359, 7465, 7462, 6620, 4631,
+6311, 6402, 6486, 6577, 6669, 6673, 6707, 6743, 4527, 7481, 7514, 6724,
+6596, 4744, 6286, 6342, 6402, 6494, 6619, 6623, 6658, 4588, 7525, 7671,
+6820, 6692, 6574, 4872, 6327, 6299, 6390, 6515, 6641, 6646, 4579, 7496,
+7644, 6909, 6781, 6633, 6620, 4887, 6179, 6271, 6396, 6522, 6648, 4579,
+7469, 7618, 7004, 6876, 6696, 6590, 6464, 4909, 6152, 6278, 6403, 6530,
+4527, 7475, 7626, 6972, 6972, 6791, 6686, 6560, 6435, 4948, 6163, 6288,
+6415, 4537, 7521, 7674, 7009, 6976, 6923, 6818, 6692, 6567, 6446, 5001,
+6323, 6450, 4546, 7567, 7722, 7046, 7014, 6928, 6950, 6825, 6700, 6579,
+6616, 5060, 6485, 4556, 7614, 7772, 7084, 7052, 6966, 6956, 6958, 6833,
+6712, 6749, 6787, 5126, 5000, 6991, 7009, 7017, 6991, 7003, 6976, 7018,
+7060, 7102, 4082, 3025, 3208, 7076, 6159, 6277, 6365, 6448, 6431, 6402,
+6436, 6470, 6506, 2975, 5249, 7485, 3557, 6938, 6982, 7123, 7099, 7213,
+7188, 7232, 7277, 7323, 4362, 7394, 6443, 7332, 4623, 6330, 6419, 6503,
+6608, 6697, 6701, 6736, 6771, 4530, 7419, 6567, 7385, 6615, 4737, 6271,
+6327, 6433, 6517, 6642, 6647, 6682, 4587, 7431, 6660, 7541, 6708, 6557,
+4860, 6224, 6329, 6387, 6513, 6638, 6643, 4578, 7402, 6748, 7514, 6797,
+6616, 6510, 4875, 6210, 6268, 6393, 6519, 6646, 4620, 7416, 6731, 7638,
+6908, 6727, 6621, 6496, 4950, 6191, 6316, 6442, 6568, 4560, 7385, 6699,
+7610, 7001, 6815, 6682, 6556, 6476, 4980, 6171, 6297, 6423, 4569, 7430,
+6736, 7658, 7005, 6947, 6814, 6688, 6608, 6454, 5033, 6331, 6458, 4578,
+7476, 6773, 7706, 7043, 6952, 6946, 6821, 6741, 6587, 6624, 5092, 6494,
+4588, 7523, 6811, 7756, 7081, 6990, 6952, 6954, 6874, 6720, 6758, 6795,
+5158, 5000, 6919, 6933, 6907, 6889, 6898, 6907, 6948, 6990, 4114, 3055,
+3245, 5879, 7062, 6308, 6396, 6480, 6432, 6440, 6444, 6479, 6514, 4079,
+3124, 6157, 3228, 6924, 6195, 6283, 6367, 6452, 6433, 6437, 6471, 6507,
+2990, 5249, 7469, 7319, 3586, 6863, 7004, 6980, 7094, 7207, 7218, 7263,
+7308, 4533, 7323, 6600, 6480, 7255, 4729, 6286, 6342, 6444, 6548, 6671,
+6675, 6710, 4590, 7341, 6692, 6572, 7412, 6571, 4852, 6212, 6315, 6418,
+6536, 6662, 6667, 4581, 7312, 6781, 6661, 7385, 6630, 6496, 4868, 6195,
+6299, 6417, 6543, 6669, 4619, 7293, 6730, 6751, 7508, 6738, 6604, 6479,
+4938, 6222, 6314, 6439, 6566, 4600, 7304, 6740, 6732, 7630, 6847, 6713,
+6588, 6508, 5022, 6209, 6335, 6462, 4600, 7312, 6744, 6735, 7642, 6976,
+6838, 6712, 6604, 6495, 5065, 6340, 6466, 4610, 7358, 6781, 6773, 7690,
+6981, 6970, 6845, 6737, 6628, 6632, 5125, 6502, 4620, 7404, 6820, 6811,
+7740, 7019, 6976, 6978, 6870, 6761, 6766, 6804, 5190, 5000, 6733, 6707,
+6686, 6666, 6711, 6719, 6761, 3993, 3051, 3183, 5719, 5716, 7060, 6280,
+6364, 6311, 6293, 6335, 6339, 6374, 3958, 3111, 5993, 3167, 5621, 6923,
+6167, 6251, 6336, 6286, 6328, 6332, 6368, 3924, 3205, 5988, 5886, 3157,
+6786, 6055, 6139, 6224, 6307, 6322, 6326, 6361, 2999, 5249, 7471, 7322,
+7173, 3463, 6870, 6846, 6961, 7073, 7222, 7233, 7279, 4420, 7128, 6573,
+6454, 6334, 7267, 4697, 6174, 6276, 6377, 6514, 6637, 6642, 4411, 7099,
+6662, 6543, 6423, 7240, 6456, 4712, 6157, 6257, 6395, 6518, 6644, 4449,
+7076, 6607, 6632, 6513, 7364, 6564, 6438, 4783, 6154, 6291, 6410, 6536,
+4426, 7055, 6588, 6580, 6601, 7486, 6670, 6544, 6436, 4862, 6182, 6275,
+6401, 4468, 7105, 6634, 6626, 6618, 7647, 6815, 6689, 6581, 6467, 4959,
+6320, 6447, 4469, 7113, 6639, 6631, 6622, 7660, 6945, 6819, 6706, 6565,
+6614, 5009, 6452, 4478, 7159, 6677, 6669, 6661, 7709, 6951, 6953, 6840,
+6698, 6747, 6753, 5074, 5000, 6438, 6422, 6399, 6414, 6459, 6467, 3908,
+3016, 3142, 5594, 5591, 5756, 7056, 6279, 6226, 6203, 6219, 6262, 6266,
+3874, 3108, 5864, 3126, 5496, 5661, 6919, 6167, 6251, 6197, 6213, 6255,
+6260, 3839, 3195, 5859, 5758, 3116, 5566, 6782, 6055, 6140, 6223, 6207,
+6249, 6254, 3961, 3408, 6033, 5931, 5830, 3173, 6688, 5955, 6040, 6123,
+6239, 6255, 6259, 2864, 5249, 7471, 7322, 7173, 7072, 3387, 6716, 6830,
+6943, 7091, 7241, 7253, 4291, 6810, 6576, 6456, 6337, 6232, 7099, 4594,
+6131, 6232, 6366, 6504, 6628, 4329, 6794, 6520, 6546, 6427, 6322, 7223,
+6411, 4664, 6124, 6258, 6395, 6519, 4306, 6769, 6497, 6489, 6515, 6410,
+7345, 6517, 6404, 4744, 6122, 6260, 6379, 4344, 6786, 6515, 6506, 6499,
+6534, 7506, 6659, 6546, 6404, 4837, 6306, 6399, 4386, 6836, 6561, 6553,
+6545, 6552, 7669, 6805, 6692, 6550, 6599, 4940, 6445, 4387, 6845, 6567,
+6558, 6551, 6557, 7682, 6936, 6823, 6676, 6697, 6748, 4996, 5000, 6153,
+6137, 6149, 6165, 6209, 3927, 3044, 3140, 5539, 5539, 5704, 5836, 7180,
+6249, 6227, 6238, 6254, 6297, 3925, 3136, 5809, 3158, 5469, 5634, 5765,
+7043, 6275, 6224, 6235, 6251, 6294, 3896, 3224, 5808, 5731, 3153, 5544,
+5675, 6906, 6167, 6250, 6229, 6245, 6288, 4018, 3437, 5981, 5905, 5808,
+3210, 5624, 6778, 6068, 6151, 6267, 6252, 6295, 3993, 3724, 6118, 6042,
+5945, 5892, 3267, 6680, 5995, 6078, 6195, 6312, 6328, 2880, 5249, 7605,
+7456, 7307, 7169, 7063, 3438, 6711, 6824, 6972, 7122, 7272, 4330, 6504,
+6544, 6570, 6455, 6351, 6275, 7093, 4657, 6143, 6277, 6412, 6550, 4307,
+6487, 6520, 6516, 6543, 6439, 6363, 7215, 6423, 4736, 6137, 6272, 6410,
+4345, 6502, 6533, 6529, 6521, 6563, 6487, 7377, 6565, 6418, 4829, 6291,
+6430, 4383, 6519, 6552, 6548, 6540, 6547, 6612, 7539, 6708, 6561, 6582,
+4928, 6476, 4426, 6569, 6599, 6595, 6587, 6595, 6630, 7702, 6854, 6707,
+6729, 6779, 5037, 5000, 5811, 5833, 5845, 5861, 3965, 3071, 3176, 5547,
+5548, 5713, 5844, 5958, 7165, 6258, 6269, 6280, 6297, 3924, 3124, 5817,
+3142, 5394, 5563, 5694, 5809, 7147, 6220, 6231, 6242, 6259, 3923, 3244,
+5816, 5655, 3166, 5494, 5625, 5739, 7011, 6246, 6229, 6240, 6257, 4050,
+3461, 5989, 5833, 5757, 3229, 5578, 5661, 6883, 6151, 6267, 6247, 6264,
+4025, 3743, 6127, 5970, 5895, 5846, 3286, 5607, 6785, 6078, 6195, 6312,
+6297, 3991, 4030, 6246, 6090, 6014, 5932, 5874, 3296, 6650, 5968, 6085,
+6202, 6320, 2885, 5249, 7589, 7570, 7421, 7283, 7177, 7030, 3480, 6719,
+6868, 7017, 7167, 4303, 6136, 6553, 6512, 6539, 6439, 6363, 6246, 7102,
+4696, 6148, 6283, 6418, 4341, 6160, 6565, 6525, 6521, 6563, 6487, 6370,
+7263, 6429, 4789, 6298, 6433, 4379, 6175, 6579, 6538, 6535, 6542, 6611,
+6494, 7425, 6571, 6588, 4888, 6453, 4422, 6192, 6598, 6558, 6554, 6561,
+6597, 6620, 7589, 6715, 6731, 6753, 4993, 5000, 5520, 5544, 5556, 3933,
+3065, 3179, 5553, 5515, 5684, 5815, 5929, 5928, 7163, 6295, 6307, 6319,
+3924, 3154, 5823, 3181, 5400, 5569, 5700, 5814, 5929, 7130, 6265, 6277,
+6289, 3883, 3236, 5782, 5661, 3153, 5416, 5551, 5666, 5780, 7113, 6228,
+6239, 6251, 4038, 3484, 5960, 5839, 5679, 3245, 5525, 5609, 5723, 6985,
+6266, 6250, 6262, 4019, 3770, 6097, 5976, 5820, 5792, 3307, 5558, 5672,
+6887, 6198, 6315, 6295, 3984, 4049, 6216, 6095, 5939, 5879, 5825, 3317,
+5579, 6752, 6088, 6205, 6323, 3985, 4399, 6214, 6214, 6059, 5998, 5944,
+5845, 3339, 6651, 6009, 6126, 6244, 2854, 5250, 7587, 7551, 7532, 7394,
+7288, 7141, 7031, 3532, 6730, 6879, 7029, 4212, 5821, 6592, 6560, 6520,
+6562, 6490, 6373, 6289, 7112, 4746, 6312, 6448, 4251, 5848, 6606, 6574,
+6534, 6545, 6614, 6497, 6414, 7274, 6602, 4845, 6463, 4289, 5863, 6620,
+6588, 6548, 6559, 6595, 6623, 6539, 7438, 6745, 6762, 4951, 5000, 5287,
+5314, 3897, 3019, 3142, 5545, 5515, 5645, 5780, 5895, 5894, 5925, 7177,
+6298, 6310, 3888, 3109, 5814, 3145, 5400, 5531, 5665, 5780, 5894, 5895,
+7145, 6268, 6280, 3879, 3227, 5782, 5661, 3153, 5416, 5551, 5666, 5780,
+5897, 7113, 6239, 6251, 3995, 3437, 5920, 5799, 5679, 3193, 5442, 5530,
+5644, 5761, 7104, 6214, 6226, 4003, 3755, 6062, 5941, 5820, 5708, 3284,
+5499, 5614, 5731, 7006, 6280, 6264, 3974, 4037, 6181, 6060, 5939, 5798,
+5766, 3299, 5525, 5642, 6871, 6174, 6291, 3974, 4376, 6178, 6179, 6058,
+5917, 5885, 5790, 3321, 5580, 6770, 6095, 6213, 3849, 4713, 6211, 6179,
+6180, 6039, 6006, 5911, 5846, 3349, 6655, 5987, 6104, 2838, 5250, 7603,
+7568, 7532, 7524, 7418, 7271, 7161, 7035, 3572, 6894, 7044, 4214, 5571,
+6597, 6565, 6534, 6509, 6578, 6465, 6381, 6266, 7291, 4813, 6455, 4253,
+5601, 6612, 6579, 6548, 6523, 6562, 6591, 6507, 6392, 7454, 6754, 4919,
+5000, 5283, 3860, 2974, 3106, 5537, 5507, 5645, 5742, 5860, 5859, 5891,
+5926, 7192, 6302, 3852, 3064, 5806, 3108, 5392, 5531, 5627, 5745, 5860,
+5861, 5896, 7159, 6272, 3843, 3182, 5774, 5653, 3117, 5416, 5513, 5631,
+5745, 5862, 5866, 7127, 6243, 3991, 3428, 5920, 5799, 5679, 3192, 5442,
+5530, 5644, 5761, 5880, 7104, 6226, 3960, 3707, 6022, 5901, 5781, 5708,
+3232, 5416, 5535, 5651, 5771, 7125, 6228, 3958, 4022, 6145, 6024, 5904,
+5798, 5681, 3276, 5467, 5583, 5703, 6990, 6256, 3963, 4364, 6143, 6144,
+6023, 5917, 5804, 5731, 3303, 5526, 5646, 6889, 6181, 3838, 4688, 6176,
+6144, 6145, 6039, 5926, 5852, 5790, 3331, 5558, 6774, 6073, 3847, 4966,
+6213, 6181, 6149, 6165, 6052, 5978, 5917, 5822, 3391, 6812, 6108, 2823,
+5251, 7620, 7584, 7549, 7524, 7548, 7401, 7291, 7165, 7206, 3619, 7059,
+4216, 5568, 6603, 6571, 6540, 6523, 6526, 6554, 6475, 6359, 6397, 7470,
+4886, 5000, 3823, 2928, 3070, 5528, 5498, 5637, 5742, 5822, 5825, 5856,
+5891, 5926, 7206, 3814, 3018, 5797, 3072, 5383, 5522, 5627, 5707, 5825,
+5826, 5861, 5896, 7174, 3805, 3137, 5765, 5644, 3081, 5408, 5513, 5593,
+5711, 5828, 5832, 5867, 7142, 3953, 3383, 5912, 5791, 5670, 3156, 5442,
+5491, 5609, 5726, 5846, 5851, 7118, 3954, 3699, 6022, 5901, 5781, 5708,
+3232, 5416, 5535, 5651, 5771, 5891, 7125, 3913, 3975, 6106, 5985, 5864,
+5759, 5681, 3224, 5384, 5504, 5624, 5744, 7109, 3948, 4349, 6108, 6108,
+5988, 5882, 5804, 5646, 3280, 5468, 5587, 5708, 7008, 3827, 4677, 6140,
+6108, 6109, 6003, 5926, 5772, 5731, 3313, 5503, 5624, 6893, 3836, 4939,
+6177, 6145, 6114, 6130, 6052, 5898, 5858, 5767, 3373, 5659, 6931, 3846,
+4972, 6215, 6183, 6152, 6135, 6179, 6025, 5985, 5894, 5932, 3440, 6970,
+2808, 5251, 7637, 7601, 7566, 7541, 7549, 7531, 7422, 7295, 7337, 7379,
+3674, 5000, 7452, 3030, 6885, 6855, 7047, 7021, 7143, 7113, 7160, 7209,
+7257, 7307, 7391, 6288, 7306, 4308, 5973, 6095, 6179, 6286, 6372, 6372,
+6408, 6445, 6482, 7358, 6253, 7273, 6338, 4305, 5968, 6053, 6160, 6246,
+6372, 6376, 6413, 6450, 7368, 6382, 7485, 6468, 6332, 4461, 5929, 6036,
+6089, 6215, 6346, 6350, 6387, 7337, 6472, 7455, 6558, 6422, 6294, 4472,
+5912, 5965, 6091, 6221, 6352, 6357, 7350, 6453, 7587, 6671, 6535, 6407,
+6273, 4547, 5884, 6010, 6140, 6271, 6402, 7336, 6411, 7553, 6764, 6628,
+6464, 6330, 6244, 4536, 5856, 5986, 6116, 6248, 7205, 6445, 7603, 6763,
+6763, 6599, 6465, 6379, 6213, 4573, 5885, 6015, 6147, 7254, 6484, 7654,
+6802, 6767, 6739, 6605, 6519, 6353, 6244, 4632, 6052, 6184, 7304, 6524,
+7707, 6842, 6807, 6744, 6746, 6659, 6494, 6385, 6425, 4698, 6221, 7354,
+6565, 7760, 6883, 6848, 6785, 6751, 6801, 6635, 6526, 6566, 6607, 4770,
+5000, 3146, 6987, 6956, 6997, 7145, 7119, 7093, 7102, 7149, 7198, 7247,
+4024, 7396, 7414, 4354, 6092, 6192, 6286, 6371, 6466, 6435, 6471, 6507,
+6545, 4014, 7362, 7380, 6462, 4351, 6066, 6160, 6245, 6340, 6435, 6438,
+6475, 6512, 4189, 7372, 7429, 6570, 6434, 4475, 6036, 6092, 6155, 6249,
+6379, 6384, 6421, 4255, 7412, 7592, 6668, 6532, 6404, 4613, 6074, 6045,
+6140, 6270, 6400, 6405, 4245, 7381, 7562, 6759, 6623, 6464, 6449, 4629,
+5922, 6016, 6146, 6277, 6408, 4245, 7351, 7533, 6861, 6725, 6531, 6416,
+6283, 4652, 5893, 6023, 6154, 6285, 4192, 7357, 7542, 6827, 6827, 6632,
+6517, 6384, 6251, 4695, 5904, 6035, 6166, 4201, 7406, 7593, 6866, 6831,
+6772, 6657, 6524, 6391, 6263, 4751, 6071, 6203, 4211, 7455, 7645, 6906,
+6871, 6777, 6798, 6665, 6532, 6403, 6443, 4813, 6240, 4221, 7505, 7697,
+6947, 6912, 6817, 6804, 6806, 6674, 6545, 6585, 6625, 4882, 5000, 8417,
+8377, 8554, 8686, 8817, 8778, 8775, 8815, 8855, 8895, 7165, 7154, 8918,
+8197, 8167, 8193, 8207, 8246, 8365, 8322, 8362, 8403, 8443, 7126, 7115,
+8874, 8577, 8157, 8029, 8042, 8081, 8200, 8322, 8322, 8362, 8403, 7190,
+7179, 9065, 8604, 8429, 8119, 7899, 7938, 8052, 8174, 8338, 8338, 8379,
+7155, 7220, 9208, 8618, 8442, 8291, 8087, 7826, 7907, 8028, 8192, 8357,
+8357, 7172, 7223, 9350, 8658, 8482, 8330, 8211, 8092, 7794, 7916, 8080,
+8245, 8409, 7172, 7183, 9307, 8785, 8610, 8452, 8297, 8178, 8053, 7751,
+7916, 8080, 8245, 7020, 7181, 9304, 8739, 8739, 8581, 8426, 8307, 8132,
+8052, 7749, 7913, 8078, 7060, 7221, 9348, 8783, 8739, 8757, 8602, 8483,
+8308, 8130, 8096, 7954, 8119, 7100, 7260, 9392, 8827, 8783, 8757, 8778,
+8659, 8483, 8306, 8350, 8140, 8159, 7140, 7301, 9436, 8871, 8828, 8802,
+8778, 8835, 8659, 8481, 8526, 8570, 8185, 5000, 6980, 6997, 7004, 7041,
+7016, 6988, 7030, 7072, 7115, 3123, 3021, 5249, 3699, 7030, 7040, 7076,
+7218, 7194, 7168, 7212, 7257, 7302, 4460, 4382, 7379, 7431, 4699, 6447,
+6518, 6610, 6695, 6784, 6787, 6822, 6857, 4628, 4550, 7402, 7446, 6735,
+4809, 6343, 6435, 6516, 6600, 6726, 6730, 6765, 4659, 4681, 7413, 7489,
+6810, 6629, 4905, 6332, 6388, 6447, 6572, 6697, 6702, 4740, 4742, 7457,
+7646, 6907, 6726, 6620, 5038, 6374, 6344, 6469, 6594, 6721, 4740, 4734,
+7428, 7619, 6996, 6812, 6680, 6668, 5057, 6225, 6350, 6476, 6602, 4679,
+4727, 7398, 7590, 7089, 6900, 6741, 6636, 6511, 5085, 6205, 6330, 6457,
+4689, 4737, 7443, 7638, 7093, 7032, 6872, 6767, 6643, 6489, 5136, 6365,
+6492, 4698, 4746, 7489, 7686, 7131, 7036, 7005, 6900, 6775, 6621, 6658,
+5192, 6527, 4708, 4756, 7535, 7735, 7169, 7074, 7011, 7034, 6909, 6755,
+6792, 6830, 5255, 5000, 6908, 6921, 6931, 6905, 6914, 6922, 6964, 7006,
+4195, 4096, 3131, 3275, 7017, 6296, 6383, 6471, 6556, 6537, 6541, 6575,
+6611, 3139, 3037, 5249, 7417, 3728, 6921, 6957, 7099, 7075, 7187, 7198,
+7242, 7287, 4631, 4553, 7307, 6583, 7317, 4801, 6358, 6447, 6528, 6631,
+6754, 6758, 6793, 4661, 4684, 7324, 6674, 7359, 6643, 4898, 6317, 6374,
+6477, 6595, 6721, 6726, 4739, 4741, 7338, 6767, 7516, 6737, 6603, 5026,
+6271, 6374, 6466, 6592, 6718, 4739, 4732, 7309, 6857, 7489, 6822, 6663,
+6558, 5045, 6256, 6348, 6473, 6600, 4720, 4768, 7320, 6837, 7611, 6931,
+6772, 6667, 6542, 5127, 6243, 6369, 6495, 4720, 4768, 7329, 6841, 7622,
+7061, 6896, 6764, 6639, 6530, 5168, 6374, 6500, 4730, 4778, 7374, 6879,
+7670, 7065, 7029, 6896, 6771, 6662, 6667, 5225, 6536, 4739, 4787, 7420,
+6917, 7719, 7104, 7035, 7030, 6905, 6796, 6800, 6838, 5287, 5000, 6723,
+6740, 6714, 6693, 6738, 6746, 6788, 4074, 3975, 3119, 3213, 5701, 7015,
+6267, 6356, 6440, 6390, 6432, 6436, 6472, 4039, 3941, 3212, 5969, 3203,
+6878, 6155, 6243, 6328, 6411, 6426, 6430, 6465, 3147, 3046, 5249, 7420,
+7271, 3603, 6822, 6964, 6940, 7052, 7201, 7212, 7257, 4492, 4514, 7113,
+6556, 6437, 7213, 4742, 6278, 6335, 6435, 6572, 6696, 6700, 4569, 4571,
+7134, 6649, 6529, 7370, 6562, 4870, 6205, 6306, 6443, 6562, 6688, 4569,
+4562, 7105, 6738, 6619, 7343, 6622, 6489, 4889, 6187, 6325, 6443, 6570,
+4546, 4594, 7084, 6686, 6707, 7465, 6728, 6595, 6470, 4967, 6215, 6308,
+6434, 4588, 4636, 7133, 6732, 6724, 7626, 6873, 6740, 6615, 6501, 5062,
+6354, 6480, 4588, 4636, 7142, 6737, 6728, 7638, 7003, 6865, 6740, 6598,
+6648, 5108, 6485, 4598, 4646, 7188, 6775, 6767, 7687, 7009, 6999, 6874,
+6732, 6781, 6786, 5171, 5000, 6498, 6472, 6448, 6464, 6508, 6516, 3990,
+3886, 3117, 3173, 5576, 5741, 7011, 6271, 6355, 6301, 6317, 6359, 6364,
+3955, 3851, 3204, 5840, 3163, 5646, 6875, 6159, 6244, 6327, 6310, 6353,
+6358, 4077, 3974, 3415, 6013, 5913, 3219, 6746, 6058, 6139, 6222, 6339,
+6354, 6359, 3013, 3054, 5249, 7420, 7271, 7132, 3524, 6832, 6808, 6920,
+7069, 7218, 7229, 4449, 4436, 6876, 6563, 6444, 6338, 7228, 4751, 6175,
+6275, 6409, 6547, 6671, 4449, 4427, 6846, 6652, 6533, 6423, 7201, 6456,
+4771, 6157, 6291, 6428, 6552, 4426, 4459, 6821, 6595, 6621, 6511, 7322,
+6562, 6437, 4848, 6155, 6293, 6412, 4464, 4497, 6839, 6612, 6605, 6635,
+7483, 6704, 6579, 6437, 4939, 6339, 6432, 4506, 4539, 6889, 6659, 6651,
+6653, 7645, 6850, 6725, 6583, 6633, 5039, 6478, 4507, 4540, 6898, 6664,
+6657, 6658, 7658, 6981, 6856, 6709, 6731, 6781, 5092, 5000, 6134, 6119,
+6131, 6146, 6191, 3904, 3801, 3082, 3132, 5451, 5616, 5747, 7007, 6271,
+6216, 6227, 6243, 6286, 3869, 3766, 3198, 5712, 3122, 5521, 5652, 6871,
+6159, 6242, 6221, 6237, 6280, 3992, 3888, 3402, 5885, 5784, 3178, 5570,
+6743, 6055, 6138, 6255, 6239, 6282, 3967, 3977, 3660, 6022, 5921, 5834,
+3234, 6645, 5956, 6039, 6155, 6272, 6288, 2900, 2920, 5249, 7420, 7271,
+7133, 7027, 3454, 6678, 6790, 6938, 7087, 7237, 4330, 4306, 6484, 6566,
+6447, 6337, 6233, 7060, 4652, 6131, 6265, 6400, 6538, 4307, 4338, 6467,
+6508, 6535, 6425, 6321, 7181, 6410, 4730, 6125, 6260, 6398, 4345, 4376,
+6481, 6521, 6513, 6549, 6444, 7342, 6552, 6405, 4820, 6279, 6417, 4383,
+4414, 6499, 6539, 6532, 6534, 6569, 7504, 6695, 6548, 6569, 4916, 6464,
+4426, 4457, 6549, 6587, 6579, 6581, 6588, 7667, 6842, 6695, 6716, 6766,
+5023, 5000, 5811, 5833, 5845, 5861, 3931, 3819, 3110, 3130, 5396, 5565,
+5696, 5827, 7131, 6239, 6250, 6262, 6279, 3929, 3817, 3227, 5656, 3154,
+5495, 5626, 5758, 6995, 6265, 6248, 6259, 6276, 4057, 3945, 3430, 5833,
+5757, 3215, 5548, 5680, 6862, 6165, 6282, 6261, 6278, 4028, 4033, 3689,
+5970, 5895, 5813, 3271, 5625, 6735, 6067, 6183, 6300, 6285, 4032, 4009,
+4051, 6108, 6033, 5951, 5893, 3333, 6637, 5995, 6112, 6229, 6347, 2921,
+2935, 5249, 7554, 7405, 7262, 7124, 7019, 3509, 6671, 6819, 6968, 7118,
+4335, 4338, 6135, 6532, 6558, 6453, 6349, 6274, 7052, 4722, 6144, 6279,
+6414, 4373, 4376, 6160, 6544, 6541, 6577, 6473, 6398, 7213, 6424, 4812,
+6294, 6429, 4411, 4414, 6174, 6558, 6554, 6556, 6598, 6523, 7375, 6567,
+6583, 4909, 6449, 4454, 4453, 6192, 6577, 6574, 6576, 6583, 6648, 7537,
+6710, 6727, 6749, 5011, 5000, 5520, 5544, 5556, 3931, 3850, 3140, 3169,
+5402, 5570, 5701, 5833, 5948, 7114, 6285, 6296, 6308, 3890, 3809, 3218,
+5662, 3141, 5418, 5553, 5684, 5799, 7097, 6247, 6259, 6271, 4045, 3964,
+3454, 5839, 5679, 3231, 5496, 5627, 5738, 6964, 6281, 6264, 6276, 4021,
+4058, 3716, 5976, 5820, 5759, 3292, 5576, 5660, 6837, 6186, 6303, 6283,
+4026, 4034, 4069, 6114, 5958, 5897, 5843, 3354, 5606, 6739, 6115, 6232,
+6350, 4017, 3999, 4399, 6234, 6078, 6012, 5930, 5873, 3369, 6605, 6005,
+6122, 6240, 2890, 2938, 5250, 7536, 7516, 7373, 7235, 7130, 6984, 3559,
+6681, 6830, 6979, 4245, 4341, 5821, 6580, 6540, 6576, 6476, 6401, 6285,
+7062, 4770, 6308, 6444, 4283, 4380, 5848, 6593, 6553, 6559, 6601, 6526,
+6409, 7224, 6597, 4866, 6459, 4321, 4418, 5863, 6607, 6568, 6574, 6581,
+6651, 6535, 7386, 6741, 6758, 4968, 5000, 5287, 5314, 3895, 3814, 3095,
+3132, 5402, 5532, 5667, 5799, 5914, 5915, 7129, 6288, 6300, 3886, 3805,
+3209, 5662, 3141, 5418, 5553, 5684, 5799, 5916, 7097, 6258, 6271, 4002,
+3921, 3406, 5799, 5679, 3179, 5413, 5548, 5658, 5775, 7083, 6229, 6241,
+4006, 4043, 3700, 5941, 5820, 5675, 3269, 5518, 5602, 5719, 6956, 6268,
+6252, 4015, 4023, 4058, 6079, 5958, 5817, 5784, 3336, 5552, 5668, 6858,
+6200, 6318, 4006, 3989, 4376, 6199, 6078, 5932, 5871, 5818, 3351, 5577,
+6724, 6091, 6209, 3881, 3960, 4713, 6199, 6200, 6053, 5993, 5940, 5841,
+3377, 6609, 5983, 6100, 2874, 2923, 5250, 7552, 7517, 7503, 7365, 7260,
+7113, 6987, 3596, 6845, 6994, 4246, 4343, 5571, 6585, 6553, 6523, 6565,
+6493, 6377, 6262, 7240, 4834, 6451, 4285, 4381, 5601, 6599, 6568, 6537,
+6549, 6619, 6503, 6387, 7403, 6750, 4936, 5000, 5283, 3858, 3778, 3050,
+3096, 5393, 5532, 5628, 5764, 5879, 5880, 5915, 7143, 6292, 3849, 3769,
+3164, 5653, 3104, 5418, 5514, 5650, 5765, 5882, 5886, 7111, 6262, 3997,
+3917, 3398, 5799, 5679, 3179, 5413, 5548, 5658, 5775, 5895, 7083, 6241,
+3962, 3999, 3653, 5901, 5781, 5675, 3217, 5435, 5523, 5640, 5759, 7075,
+6217, 3999, 4007, 4042, 6043, 5923, 5817, 5700, 3313, 5493, 5610, 5730,
+6977, 6283, 3995, 3978, 4364, 6163, 6043, 5932, 5791, 5759, 3333, 5522,
+5642, 6843, 6177, 3870, 3949, 4688, 6163, 6164, 6053, 5913, 5881, 5786,
+3359, 5554, 6728, 6069, 3880, 3958, 4966, 6200, 6169, 6179, 6039, 6007,
+5912, 5818, 3416, 6766, 6104, 2859, 2908, 5251, 7568, 7533, 7503, 7495,
+7390, 7244, 7117, 7159, 3641, 7009, 4248, 4344, 5568, 6591, 6559, 6537,
+6513, 6583, 6470, 6355, 6393, 7419, 4904, 5000, 3821, 3740, 3004, 3060,
+5385, 5524, 5628, 5726, 5845, 5846, 5880, 5916, 7158, 3812, 3731, 3119,
+5645, 3068, 5409, 5514, 5611, 5730, 5847, 5851, 5886, 7126, 3960, 3879,
+3353, 5791, 5670, 3143, 5413, 5510, 5624, 5741, 5860, 5865, 7098, 3957,
+3994, 3645, 5901, 5781, 5675, 3217, 5435, 5523, 5640, 5759, 5880, 7075,
+3955, 3963, 3995, 6004, 5883, 5778, 5700, 3261, 5410, 5531, 5651, 5771,
+7096, 3980, 3961, 4349, 6128, 6007, 5896, 5791, 5675, 3310, 5464, 5583,
+5704, 6962, 3859, 3937, 4677, 6128, 6129, 6018, 5912, 5800, 5727, 3341,
+5500, 5620, 6847, 3869, 3947, 4939, 6165, 6133, 6144, 6039, 5926, 5853,
+5763, 3398, 5655, 6885, 3878, 3956, 4972, 6203, 6171, 6149, 6166, 6053,
+5980, 5890, 5928, 3461, 6924, 2844, 2893, 5251, 7585, 7550, 7520, 7496,
+7520, 7374, 7248, 7289, 7332, 3692, 5000, 7435, 7274, 3055, 6727, 6919,
+6892, 7015, 7133, 7145, 7193, 7242, 7291, 7285, 6289, 6165, 7135, 4298,
+5992, 6077, 6180, 6279, 6404, 6407, 6444, 6481, 7304, 6418, 6294, 7347,
+6359, 4454, 5920, 6024, 6122, 6242, 6372, 6376, 6414, 7272, 6508, 6384,
+7317, 6448, 6284, 4465, 5899, 5998, 6118, 6248, 6378, 6383, 7250, 6453,
+6475, 7448, 6558, 6394, 6260, 4535, 5917, 6009, 6139, 6269, 6401, 7280,
+6455, 6447, 7576, 6662, 6498, 6364, 6278, 4581, 5897, 6027, 6157, 6289,
+7109, 6454, 6446, 7585, 6796, 6626, 6492, 6376, 6257, 4608, 5893, 6024,
+6155, 7158, 6493, 6485, 7637, 6800, 6766, 6632, 6516, 6397, 6253, 4667,
+6061, 6192, 7207, 6533, 6525, 7689, 6840, 6771, 6773, 6657, 6538, 6394,
+6434, 4733, 6230, 7257, 6574, 6566, 7743, 6881, 6812, 6779, 6798, 6680,
+6536, 6576, 6616, 4805, 5000, 7381, 3171, 6828, 6869, 7017, 6991, 7114,
+7086, 7134, 7182, 7231, 4019, 7290, 6268, 7241, 4344, 6090, 6180, 6266,
+6373, 6466, 6470, 6507, 6544, 4194, 7308, 6396, 7290, 6460, 4468, 6024,
+6080, 6188, 6276, 6406, 6410, 6447, 4255, 7315, 6491, 7453, 6555, 6391,
+4601, 5971, 6078, 6138, 6268, 6399, 6404, 4246, 7283, 6581, 7424, 6646,
+6451, 6337, 4618, 5954, 6015, 6145, 6275, 6407, 4290, 7296, 6563, 7555,
+6760, 6565, 6450, 6317, 4697, 5934, 6064, 6195, 6326, 4227, 7263, 6528,
+7524, 6860, 6659, 6515, 6381, 6296, 4730, 5913, 6043, 6175, 4237, 7311,
+6567, 7575, 6864, 6799, 6655, 6521, 6436, 6272, 4786, 6080, 6212, 4246,
+7360, 6607, 7627, 6904, 6804, 6796, 6662, 6577, 6413, 6452, 4848, 6249,
+4256, 7410, 6648, 7680, 6945, 6845, 6801, 6804, 6718, 6554, 6594, 6635,
+4918, 5000, 3327, 6936, 6940, 6974, 7122, 7096, 7068, 7115, 7163, 7212,
+4125, 4044, 7278, 7356, 4424, 6214, 6285, 6380, 6465, 6559, 6563, 6599,
+6637, 4300, 4219, 7296, 7364, 6588, 4545, 6101, 6195, 6277, 6365, 6495,
+6499, 6537, 4334, 4355, 7301, 7404, 6664, 6470, 4650, 6085, 6142, 6203,
+6333, 6464, 6469, 4419, 4421, 7342, 7567, 6764, 6569, 6455, 4793, 6125,
+6094, 6224, 6355, 6486, 4419, 4412, 7310, 7538, 6855, 6656, 6515, 6501,
+4814, 5971, 6101, 6232, 6363, 4356, 4405, 7277, 7506, 6955, 6751, 6580,
+6466, 6333, 4844, 5949, 6080, 6211, 4366, 4415, 7326, 7557, 6959, 6891,
+6720, 6606, 6473, 6309, 4897, 6117, 6248, 4376, 4425, 7375, 7609, 6999,
+6896, 6861, 6747, 6614, 6450, 6490, 4957, 6286, 4386, 4434, 7424, 7661,
+7040, 6937, 6866, 6888, 6756, 6592, 6632, 6672, 5023, 5000, 8218, 8394,
+8525, 8657, 8789, 8746, 8786, 8826, 8866, 7144, 7133, 7083, 8703, 8199,
+8170, 8179, 8193, 8232, 8348, 8348, 8389, 8429, 7208, 7197, 7147, 8893,
+8580, 8161, 8031, 8045, 8084, 8200, 8364, 8365, 8405, 7167, 7238, 7188,
+9035, 8589, 8432, 8123, 7899, 7938, 8049, 8214, 8378, 8378, 7153, 7204,
+7230, 9177, 8603, 8445, 8290, 8091, 7825, 7903, 8068, 8233, 8397, 7209,
+7221, 7233, 9320, 8643, 8485, 8330, 8211, 8097, 7791, 7956, 8120, 8285,
+7018, 7178, 7190, 9273, 8767, 8609, 8449, 8294, 8175, 8054, 7748, 7913,
+8077, 7058, 7218, 7230, 9317, 8768, 8785, 8625, 8470, 8351, 8129, 8098,
+7953, 8118, 7098, 7258, 7270, 9361, 8812, 8786, 8800, 8646, 8527, 8305,
+8349, 8142, 8159, 7138, 7298, 7310, 9405, 8856, 8830, 8801, 8821, 8703,
+8481, 8525, 8569, 8187, 5000, 6897, 6909, 6918, 6955, 6927, 6935, 6977,
+7019, 3288, 3186, 3078, 5249, 3869, 7011, 7018, 7054, 7196, 7170, 7180,
+7224, 7268, 4728, 4650, 4564, 7291, 7412, 4877, 6470, 6541, 6634, 6717,
+6840, 6844, 6879, 4755, 4781, 4695, 7308, 7422, 6758, 4969, 6388, 6481,
+6560, 6678, 6803, 6808, 4809, 4812, 4826, 7320, 7465, 6833, 6675, 5070,
+6378, 6433, 6524, 6650, 6776, 4900, 4893, 4888, 7364, 7622, 6931, 6772,
+6667, 5206, 6418, 6422, 6548, 6674, 4839, 4887, 4882, 7333, 7593, 7018,
+6856, 6725, 6713, 5232, 6277, 6402, 6529, 4839, 4887, 4882, 7341, 7604,
+7148, 6980, 6821, 6717, 6563, 5271, 6407, 6533, 4849, 4897, 4891, 7387,
+7652, 7153, 7113, 6954, 6850, 6696, 6700, 5325, 6569, 4858, 4906, 4901,
+7433, 7700, 7191, 7118, 7088, 6983, 6829, 6834, 6872, 5384, 5000, 6712,
+6728, 6739, 6711, 6756, 6764, 6806, 4155, 4057, 3950, 3219, 3249, 6956,
+6248, 6336, 6425, 6507, 6522, 6526, 6562, 3295, 3194, 3086, 5249, 7354,
+3744, 6868, 6905, 7046, 7020, 7168, 7179, 7224, 4585, 4611, 4525, 7097,
+6532, 7262, 4813, 6343, 6433, 6512, 6649, 6773, 6777, 4639, 4642, 4657,
+7117, 6624, 7304, 6628, 4914, 6304, 6359, 6496, 6615, 6741, 4725, 4719,
+4714, 7133, 6718, 7461, 6722, 6589, 5046, 6256, 6394, 6486, 6612, 4665,
+4713, 4708, 7102, 6805, 7432, 6806, 6647, 6543, 5072, 6243, 6336, 6462,
+4707, 4755, 4750, 7152, 6822, 7593, 6951, 6792, 6688, 6529, 5164, 6381,
+6508, 4707, 4755, 4750, 7160, 6827, 7604, 7081, 6917, 6785, 6626, 6675,
+5208, 6513, 4717, 4765, 4760, 7206, 6865, 7653, 7087, 7051, 6918, 6759,
+6809, 6814, 5267, 5000, 6487, 6510, 6481, 6497, 6541, 6549, 4071, 3967,
+3861, 3211, 3209, 5720, 6952, 6252, 6341, 6423, 6407, 6450, 6454, 4192,
+4089, 3983, 3422, 5987, 3264, 6822, 6148, 6237, 6320, 6436, 6452, 6457,
+3161, 3202, 3094, 5249, 7354, 7213, 3663, 6771, 6913, 6887, 7035, 7184,
+7195, 4519, 4507, 4522, 6860, 6538, 6430, 7160, 4794, 6272, 6327, 6461,
+6599, 6723, 4605, 4584, 4579, 6888, 6632, 6523, 7317, 6556, 4927, 6199,
+6333, 6471, 6590, 4545, 4578, 4573, 6856, 6719, 6611, 7288, 6614, 6482,
+4953, 6182, 6320, 6439, 4583, 4616, 4611, 6874, 6703, 6734, 7449, 6756,
+6624, 6465, 5041, 6366, 6459, 4625, 4658, 4653, 6923, 6750, 6752, 7611,
+6902, 6769, 6610, 6660, 5138, 6505, 4626, 4659, 4654, 6932, 6755, 6758,
+7623, 7033, 6895, 6736, 6758, 6808, 5188, 5000, 6204, 6175, 6187, 6203,
+6247, 3985, 3882, 3771, 3207, 3168, 5595, 5725, 6949, 6256, 6339, 6318,
+6334, 6377, 4107, 4004, 3893, 3410, 5859, 3224, 5640, 6818, 6153, 6236,
+6352, 6337, 6380, 4078, 4092, 3981, 3667, 5996, 5905, 3278, 6691, 6053,
+6132, 6248, 6366, 6382, 3048, 3068, 3103, 5249, 7354, 7213, 7075, 3589,
+6781, 6755, 6903, 7052, 7201, 4486, 4463, 4443, 6560, 6545, 6437, 6332,
+7175, 4808, 6168, 6302, 6437, 6575, 4426, 4457, 4437, 6527, 6633, 6525,
+6415, 7146, 6449, 4834, 6152, 6286, 6425, 4464, 4495, 4475, 6542, 6612,
+6649, 6539, 7306, 6591, 6432, 4922, 6306, 6444, 4502, 4533, 4513, 6559,
+6630, 6633, 6664, 7468, 6734, 6575, 6596, 5015, 6491, 4545, 4576, 4556,
+6609, 6678, 6681, 6682, 7630, 6880, 6721, 6743, 6793, 5119, 5000, 5793,
+5815, 5827, 5842, 3908, 3796, 3685, 3173, 3127, 5470, 5600, 5732, 6945,
+6254, 6233, 6244, 6261, 4030, 3917, 3807, 3403, 5730, 3183, 5515, 5646,
+6815, 6151, 6268, 6247, 6264, 4001, 4006, 3895, 3648, 5867, 5777, 3237,
+5564, 6687, 6048, 6164, 6282, 6267, 4005, 3981, 3984, 3976, 6005, 5915,
+5828, 3298, 6590, 5949, 6065, 6183, 6301, 2941, 2955, 2969, 5249, 7354,
+7213, 7075, 6970, 3523, 6625, 6773, 6921, 7070, 4334, 4337, 4315, 6115,
+6547, 6438, 6329, 6225, 7005, 4716, 6126, 6261, 6396, 4372, 4375, 4353,
+6140, 6525, 6562, 6453, 6349, 7165, 6405, 4803, 6275, 6411, 4411, 4414,
+4392, 6154, 6539, 6542, 6578, 6474, 7327, 6548, 6564, 4897, 6431, 4453,
+4452, 4430, 6172, 6558, 6561, 6563, 6599, 7489, 6691, 6707, 6730, 4996,
+5000, 5520, 5544, 5556, 3896, 3816, 3705, 3203, 3128, 5412, 5547, 5678,
+5811, 7066, 6259, 6271, 6283, 4051, 3970, 3860, 3434, 5672, 3217, 5486,
+5618, 5750, 6936, 6294, 6277, 6289, 4027, 4064, 3954, 3679, 5813, 5748,
+3277, 5540, 5673, 6804, 6194, 6312, 6292, 4027, 4035, 4042, 4007, 5951,
+5886, 5804, 3337, 5618, 6677, 6097, 6214, 6332, 4057, 4040, 4018, 4419,
+6089, 6024, 5942, 5885, 3404, 6580, 6025, 6143, 6261, 2925, 2974, 2987,
+5250, 7485, 7344, 7201, 7064, 6960, 3585, 6620, 6768, 6917, 4276, 4373,
+4351, 5821, 6552, 6589, 6484, 6381, 6306, 6999, 4793, 6298, 6433, 4314,
+4411, 4389, 5848, 6565, 6572, 6609, 6506, 6431, 7160, 6587, 4886, 6449,
+4353, 4450, 4428, 5863, 6580, 6586, 6589, 6631, 6557, 7322, 6730, 6747,
+4985, 5000, 5287, 5314, 3892, 3812, 3701, 3195, 3128, 5412, 5547, 5678,
+5811, 5928, 7066, 6271, 6283, 4008, 3927, 3816, 3386, 5672, 3165, 5403,
+5538, 5671, 5788, 7055, 6242, 6254, 4012, 4049, 3938, 3664, 5813, 5663,
+3254, 5482, 5614, 5727, 6923, 6276, 6260, 4017, 4025, 4032, 3995, 5951,
+5805, 5745, 3320, 5564, 5650, 6796, 6182, 6300, 4047, 4029, 4007, 4397,
+6089, 5944, 5883, 5830, 3386, 5597, 6699, 6111, 6229, 3913, 3991, 3970,
+4713, 6212, 6066, 6001, 5920, 5863, 3404, 6551, 5972, 6090, 2910, 2958,
+2972, 5250, 7486, 7474, 7331, 7194, 7090, 6927, 3620, 6783, 6932, 4278,
+4374, 4352, 5571, 6565, 6536, 6572, 6473, 6399, 6251, 7176, 4854, 6441,
+4316, 4413, 4391, 5601, 6580, 6550, 6557, 6599, 6524, 6376, 7339, 6739,
+4953, 5000, 5283, 3856, 3775, 3665, 3150, 3091, 5412, 5508, 5644, 5776,
+5894, 5898, 7081, 6274, 4003, 3923, 3812, 3378, 5672, 3165, 5403, 5538,
+5671, 5788, 5908, 7055, 6254, 3968, 4005, 3894, 3617, 5773, 5663, 3202,
+5399, 5535, 5648, 5768, 7042, 6225, 4001, 4009, 4016, 3980, 5915, 5805,
+5661, 3297, 5506, 5592, 5712, 6915, 6265, 4036, 4018, 3996, 4385, 6054,
+5944, 5803, 5771, 3368, 5542, 5662, 6818, 6198, 3902, 3981, 3959, 4688,
+6176, 6066, 5920, 5860, 5808, 3386, 5544, 6670, 6059, 3911, 3990, 3968,
+4966, 6181, 6192, 6047, 5987, 5934, 5807, 3440, 6708, 6094, 2895, 2943,
+2957, 5251, 7502, 7475, 7461, 7324, 7220, 7057, 7099, 3662, 6947, 4280,
+4376, 4354, 5568, 6571, 6550, 6520, 6563, 6492, 6344, 6382, 7355, 4921,
+5000, 3818, 3738, 3627, 3105, 3055, 5404, 5508, 5605, 5742, 5859, 5863,
+5899, 7095, 3966, 3885, 3775, 3333, 5663, 3129, 5403, 5500, 5636, 5754,
+5873, 5878, 7070, 3963, 4000, 3889, 3608, 5773, 5663, 3202, 5399, 5535,
+5648, 5767, 5888, 7042, 3956, 3964, 3971, 3933, 5876, 5766, 5660, 3245,
+5423, 5513, 5633, 5753, 7034, 4020, 4001, 3979, 4370, 6018, 5908, 5803,
+5687, 3345, 5484, 5604, 5724, 6937, 3891, 3969, 3947, 4677, 6141, 6031,
+5920, 5780, 5749, 3368, 5489, 5610, 6789, 3900, 3978, 3956, 4938, 6145,
+6157, 6046, 5906, 5875, 5752, 3422, 5645, 6827, 3910, 3988, 3966, 4972,
+6183, 6162, 6173, 6033, 6002, 5879, 5917, 3482, 6866, 2880, 2928, 2942,
+5251, 7519, 7492, 7462, 7455, 7350, 7187, 7229, 7271, 3709, 5000, 7417,
+7257, 7096, 3087, 6790, 6764, 6887, 7005, 7165, 7177, 7226, 7275, 7203,
+6455, 6331, 6206, 7208, 4447, 5939, 6042, 6138, 6274, 6403, 6408, 6445,
+7171, 6545, 6420, 6296, 7178, 6305, 4458, 5918, 6013, 6150, 6279, 6410,
+6415, 7145, 6484, 6511, 6387, 7310, 6415, 6281, 4528, 5905, 6041, 6165,
+6296, 6427, 7140, 6456, 6448, 6470, 7437, 6516, 6382, 6265, 4570, 5929,
+6025, 6156, 6287, 7013, 6498, 6490, 6482, 7608, 6660, 6526, 6410, 6291,
+4654, 5935, 6065, 6197, 7021, 6502, 6494, 6486, 7620, 6800, 6665, 6543,
+6394, 6298, 4702, 6070, 6201, 7070, 6542, 6534, 6526, 7672, 6804, 6806,
+6684, 6535, 6439, 6443, 4768, 6239, 7119, 6583, 6575, 6567, 7725, 6845,
+6812, 6826, 6677, 6580, 6585, 6625, 4840, 5000, 7364, 7203, 3202, 6741,
+6889, 6863, 6985, 7106, 7118, 7166, 7215, 4198, 7208, 6433, 6308, 7152,
+4461, 6042, 6099, 6203, 6308, 6437, 6442, 6479, 4260, 7222, 6527, 6403,
+7315, 6412, 4594, 5962, 6066, 6171, 6295, 6425, 6430, 4250, 7190, 6618,
+6493, 7285, 6471, 6327, 4611, 5942, 6047, 6171, 6302, 6433, 4290, 7168,
+6563, 6585, 7417, 6582, 6437, 6304, 4686, 5967, 6063, 6194, 6325, 4271,
+7178, 6573, 6565, 7546, 6693, 6549, 6415, 6330, 4775, 5954, 6085, 6216,
+4271, 7186, 6577, 6569, 7558, 6832, 6682, 6549, 6433, 6316, 4821, 6089,
+6220, 4281, 7235, 6617, 6609, 7610, 6837, 6823, 6690, 6574, 6457, 6462,
+4883, 6258, 4291, 7284, 6658, 6649, 7663, 6878, 6829, 6831, 6715, 6599,
+6603, 6644, 4953, 5000, 7317, 3357, 6812, 6846, 6994, 6968, 7088, 7099,
+7147, 7196, 4304, 4223, 7196, 6419, 7226, 4537, 6119, 6210, 6292, 6398,
+6526, 6531, 6568, 4338, 4360, 7209, 6513, 7265, 6490, 4643, 6073, 6130,
+6236, 6359, 6490, 6495, 4419, 4421, 7217, 6608, 7429, 6587, 6442, 4781,
+6021, 6127, 6223, 6353, 6484, 4419, 4411, 7186, 6699, 7399, 6674, 6502,
+6389, 4802, 6004, 6100, 6230, 6361, 4400, 4449, 7196, 6679, 7528, 6785,
+6614, 6500, 6367, 4889, 5990, 6121, 6252, 4401, 4449, 7204, 6683, 7540,
+6924, 6747, 6603, 6471, 6354, 4932, 6126, 6257, 4410, 4459, 7253, 6723,
+7591, 6929, 6888, 6744, 6611, 6495, 6499, 4992, 6295, 4420, 4469, 7302,
+6763, 7644, 6970, 6894, 6886, 6753, 6636, 6641, 6682, 5058, 5000, 3512,
+6916, 6918, 6953, 7101, 7073, 7083, 7131, 7179, 4409, 4328, 4239, 7184,
+7336, 4618, 6239, 6311, 6406, 6489, 6618, 6623, 6660, 4439, 4465, 4376,
+7196, 7341, 6614, 4718, 6149, 6244, 6324, 6448, 6578, 6583, 4497, 4498,
+4512, 7203, 7381, 6690, 6520, 4828, 6135, 6190, 6286, 6417, 6548, 4592,
+4584, 4578, 7244, 7545, 6790, 6620, 6506, 4975, 6173, 6178, 6308, 6440,
+4529, 4578, 4571, 7210, 7513, 6880, 6705, 6564, 6551, 5003, 6026, 6157,
+6288, 4529, 4578, 4572, 7219, 7524, 7019, 6838, 6668, 6555, 6391, 5044,
+6162, 6293, 4539, 4588, 4581, 7267, 7575, 7024, 6979, 6808, 6695, 6531,
+6536, 5101, 6331, 4549, 4598, 4591, 7316, 7627, 7064, 6985, 6950, 6837,
+6673, 6678, 6718, 5163, 5000, 8231, 8362, 8493, 8625, 8754, 8754, 8794,
+8834, 7219, 7208, 7159, 7109, 8717, 8200, 8170, 8179, 8193, 8229, 8388,
+8389, 8429, 7178, 7250, 7200, 7151, 8858, 8580, 8162, 8028, 8042, 8078,
+8238, 8402, 8402, 7158, 7209, 7242, 7192, 9000, 8589, 8429, 8125, 7896,
+7932, 8087, 8251, 8416, 7183, 7195, 7207, 7234, 9142, 8603, 8442, 8287,
+8093, 7820, 7941, 8106, 8270, 7048, 7209, 7221, 7234, 9282, 8640, 8479,
+8324, 8205, 8096, 7786, 7950, 8115, 7048, 7209, 7221, 7234, 9282, 8811,
+8651, 8490, 8335, 8170, 8098, 7950, 8115, 7088, 7249, 7261, 7274, 9326,
+8812, 8826, 8666, 8511, 8346, 8346, 8142, 8156, 7128, 7289, 7301, 7314,
+9370, 8856, 8827, 8842, 8687, 8522, 8522, 8566, 8187, 5000, 6701, 6716,
+6727, 6761, 6769, 6777, 6818, 3443, 3341, 3234, 3141, 5249, 3885, 6932,
+6941, 6978, 7117, 7127, 7138, 7182, 4681, 4708, 4622, 4544, 7081, 7329,
+4888, 6445, 6517, 6608, 6724, 6847, 6852, 4732, 4734, 4753, 4675, 7101,
+7343, 6732, 4984, 6365, 6456, 6568, 6687, 6813, 4796, 4789, 4784, 4806,
+7116, 7386, 6807, 6650, 5088, 6353, 6442, 6534, 6661, 4825, 4873, 4868,
+4870, 7156, 7540, 6903, 6746, 6641, 5230, 6396, 6400, 6526, 4825, 4873,
+4868, 4871, 7165, 7551, 7026, 6865, 6735, 6689, 5267, 6404, 6531, 4826,
+4874, 4869, 4871, 7173, 7562, 7157, 6990, 6832, 6694, 6698, 5308, 6536,
+4835, 4884, 4879, 4881, 7219, 7610, 7162, 7124, 6966, 6827, 6832, 6837,
+5364, 5000, 6476, 6498, 6509, 6516, 6561, 6569, 4307, 4204, 4098, 3999,
+3429, 3309, 6877, 6232, 6320, 6407, 6523, 6539, 6543, 3308, 3350, 3242,
+3149, 5249, 7271, 3802, 6796, 6833, 6972, 6982, 7130, 7141, 4612, 4599,
+4618, 4540, 6844, 6514, 7186, 4864, 6329, 6417, 6529, 6667, 6791, 4675,
+4654, 4649, 4672, 6871, 6607, 7229, 6612, 4969, 6288, 6377, 6514, 6634,
+4701, 4734, 4729, 4731, 6886, 6699, 7384, 6705, 6572, 5107, 6242, 6380,
+6473, 4701, 4734, 4729, 4732, 6894, 6822, 7394, 6824, 6666, 6528, 5143,
+6384, 6478, 4743, 4776, 4771, 4774, 6944, 6840, 7555, 6970, 6812, 6673,
+6678, 5238, 6524, 4744, 4777, 4772, 4775, 6952, 6845, 7567, 7101, 6938,
+6771, 6776, 6826, 5284, 5000, 6193, 6221, 6228, 6243, 6288, 4221, 4119,
+4008, 3910, 3418, 3269, 5703, 6873, 6236, 6323, 6439, 6423, 6467, 4193,
+4207, 4096, 3998, 3674, 5970, 3322, 6743, 6133, 6220, 6336, 6453, 6469,
+3196, 3215, 3251, 3158, 5249, 7271, 7130, 3726, 6697, 6837, 6847, 6995,
+7143, 4557, 4533, 4513, 4536, 6544, 6521, 6413, 7083, 4849, 6256, 6344,
+6479, 6617, 4582, 4613, 4593, 4596, 6577, 6613, 6504, 7238, 6538, 4987,
+6184, 6318, 6457, 4582, 4613, 4593, 4596, 6585, 6736, 6628, 7248, 6632,
+6466, 5024, 6323, 6461, 4620, 4651, 4631, 4634, 6602, 6721, 6753, 7409,
+6775, 6608, 6613, 5114, 6508, 4663, 4694, 4674, 4677, 6652, 6768, 6771,
+7571, 6921, 6755, 6759, 6810, 5214, 5000, 5869, 5876, 5888, 5903, 4144,
+4033, 3922, 3819, 3412, 3228, 5578, 5709, 6869, 6238, 6354, 6334, 6351,
+4115, 4121, 4010, 3907, 3656, 5841, 3281, 5624, 6740, 6136, 6252, 6369,
+6354, 4115, 4092, 4099, 3995, 3983, 5979, 5889, 3340, 6612, 6036, 6149,
+6266, 6384, 3088, 3103, 3116, 3167, 5249, 7271, 7131, 6993, 3656, 6705,
+6715, 6863, 7012, 4491, 4493, 4472, 4459, 6199, 6526, 6418, 6314, 7095,
+4869, 6153, 6288, 6423, 4491, 4494, 4472, 4459, 6206, 6650, 6542, 6433,
+7105, 6433, 4905, 6292, 6427, 4529, 4532, 4510, 4498, 6220, 6629, 6667,
+6558, 7266, 6576, 6580, 4995, 6448, 4572, 4570, 4548, 4536, 6237, 6649,
+6652, 6683, 7428, 6719, 6724, 6746, 5091, 5000, 5501, 5525, 5537, 4028,
+3948, 3838, 3735, 3380, 3190, 5450, 5582, 5714, 6863, 6273, 6252, 6264,
+3999, 4036, 3926, 3823, 3650, 5710, 3243, 5497, 5629, 6733, 6170, 6288,
+6268, 4000, 4008, 4014, 3912, 3958, 5847, 5757, 3302, 5548, 6607, 6068,
+6185, 6303, 4029, 4012, 3990, 4000, 4336, 5986, 5896, 5810, 3366, 6510,
+5969, 6087, 6204, 2944, 2993, 3006, 3035, 5250, 7268, 7128, 6991, 6886,
+3596, 6550, 6699, 6847, 4275, 4372, 4350, 4335, 5800, 6566, 6459, 6350,
+6247, 6927, 4784, 6270, 6405, 4313, 4410, 4388, 4373, 5828, 6546, 6583,
+6475, 6372, 7088, 6557, 4874, 6420, 4352, 4448, 4426, 4412, 5842, 6560,
+6564, 6601, 6497, 7250, 6700, 6717, 4970, 5000, 5287, 5314, 4013, 3933,
+3822, 3719, 3372, 3151, 5387, 5522, 5655, 5790, 7002, 6244, 6256, 4017,
+4054, 3943, 3840, 3642, 5646, 3239, 5462, 5594, 5729, 6872, 6279, 6263,
+4022, 4030, 4037, 3934, 3951, 5787, 5722, 3303, 5518, 5653, 6740, 6180,
+6298, 4048, 4030, 4008, 4023, 4329, 5926, 5861, 5780, 3367, 5599, 6614,
+6083, 6201, 3953, 4031, 4009, 3995, 4734, 6067, 6002, 5921, 5864, 3436,
+6503, 5983, 6101, 2945, 2993, 3007, 3035, 5250, 7419, 7278, 7136, 6999,
+6878, 3644, 6699, 6848, 4309, 4405, 4383, 4369, 5571, 6537, 6575, 6470,
+6368, 6262, 7088, 4874, 6420, 4347, 4444, 4422, 4407, 5601, 6551, 6559,
+6596, 6493, 6387, 7250, 6717, 4970, 5000, 5283, 4008, 3928, 3818, 3715,
+3364, 3151, 5387, 5522, 5655, 5789, 5910, 7002, 6256, 3973, 4010, 3899,
+3796, 3595, 5646, 3187, 5379, 5515, 5650, 5771, 6991, 6228, 4006, 4014,
+4021, 3918, 3935, 5787, 5638, 3280, 5460, 5594, 5710, 6859, 6263, 4037,
+4019, 3997, 4012, 4318, 5926, 5781, 5721, 3349, 5545, 5634, 6733, 6169,
+3942, 4020, 3998, 3984, 4709, 6067, 5922, 5862, 5809, 3418, 5554, 6622,
+6069, 3942, 4021, 3999, 3984, 4966, 6194, 6049, 5984, 5903, 5818, 3464,
+6627, 6074, 2930, 2978, 2991, 3020, 5251, 7419, 7408, 7266, 7130, 7009,
+7014, 3682, 6863, 4310, 4406, 4385, 4370, 5568, 6551, 6523, 6560, 6461,
+6355, 6360, 7267, 4938, 5000, 3971, 3891, 3781, 3678, 3319, 3115, 5387,
+5484, 5620, 5755, 5875, 5880, 7016, 3968, 4005, 3894, 3791, 3586, 5645,
+3187, 5379, 5515, 5650, 5771, 5891, 6991, 3961, 3970, 3976, 3873, 3888,
+5748, 5638, 3228, 5377, 5515, 5631, 5751, 6978, 4021, 4003, 3981, 3995,
+4302, 5890, 5781, 5637, 3326, 5486, 5576, 5696, 6852, 3931, 4009, 3987,
+3972, 4697, 6031, 5922, 5782, 5750, 3401, 5500, 5620, 6741, 3931, 4009,
+3987, 3973, 4938, 6158, 6049, 5903, 5844, 5763, 3446, 5625, 6746, 3940,
+4018, 3997, 3982, 4972, 6164, 6176, 6030, 5971, 5890, 5895, 3503, 6785,
+2915, 2963, 2977, 3005, 5251, 7436, 7409, 7397, 7260, 7139, 7144, 7186,
+3726, 5000, 7414, 7253, 7093, 6933, 2955, 6620, 6743, 6862, 7021, 7182,
+7195, 7244, 6923, 6419, 6294, 6171, 6048, 7024, 4290, 5885, 5981, 6114,
+6255, 6385, 6390, 6900, 6358, 6385, 6262, 6139, 7156, 6248, 4361, 5867,
+6000, 6141, 6271, 6402, 6892, 6324, 6316, 6344, 6221, 7283, 6349, 6227,
+4402, 5861, 6001, 6126, 6257, 6729, 6336, 6328, 6321, 6344, 7454, 6490,
+6368, 6219, 4482, 5911, 6007, 6138, 6782, 6384, 6376, 6369, 6362, 7627,
+6641, 6519, 6370, 6273, 4587, 6054, 6186, 6790, 6389, 6381, 6374, 6367,
+7640, 6781, 6659, 6504, 6377, 6429, 4643, 6191, 6839, 6430, 6422, 6414,
+6407, 7693, 6786, 6800, 6646, 6519, 6571, 6576, 4715, 5000, 7360, 7200,
+7040, 3067, 6743, 6717, 6840, 6961, 7121, 7133, 7182, 4079, 6996, 6401,
+6278, 6154, 7159, 4427, 5923, 6027, 6130, 6270, 6400, 6405, 4070, 6964,
+6492, 6368, 6244, 7129, 6288, 4443, 5904, 6006, 6147, 6276, 6408, 4109,
+6938, 6432, 6460, 6336, 7261, 6399, 6265, 4518, 5898, 6039, 6163, 6294,
+4086, 6913, 6411, 6403, 6426, 7391, 6507, 6373, 6258, 4603, 5924, 6021,
+6152, 4130, 6965, 6459, 6451, 6444, 7564, 6657, 6524, 6408, 6285, 4705,
+6068, 6199, 4130, 6974, 6464, 6456, 6449, 7576, 6797, 6664, 6542, 6389,
+6441, 4758, 6205, 4140, 7022, 6504, 6497, 6489, 7629, 6803, 6805, 6684,
+6531, 6583, 6588, 4827, 5000, 7314, 7154, 3221, 6699, 6847, 6821, 6942,
+7102, 7113, 7162, 4157, 4179, 6984, 6387, 6264, 7109, 4474, 6034, 6091,
+6194, 6334, 6464, 6469, 4239, 4240, 7001, 6483, 6359, 7272, 6403, 4613,
+5955, 6057, 6198, 6322, 6453, 4239, 4231, 6969, 6574, 6450, 7242, 6463,
+6319, 4634, 5934, 6075, 6199, 6330, 4215, 4264, 6944, 6517, 6540, 7371,
+6571, 6427, 6295, 4716, 5960, 6057, 6188, 4259, 4308, 6996, 6565, 6558,
+7544, 6722, 6578, 6445, 6322, 4817, 6104, 6235, 4260, 4309, 7005, 6570,
+6563, 7556, 6861, 6712, 6579, 6427, 6478, 4866, 6241, 4270, 4319, 7054,
+6611, 6603, 7608, 6867, 6854, 6721, 6568, 6620, 6625, 4932, 5000, 7252,
+3375, 6757, 6791, 6939, 6911, 7071, 7082, 7130, 4258, 4284, 4195, 6972,
+6366, 7168, 4550, 6105, 6196, 6276, 6417, 6546, 6551, 4316, 4318, 4332,
+6988, 6460, 7208, 6474, 4660, 6059, 6115, 6255, 6380, 6511, 4407, 4399,
+4393, 6999, 6556, 7372, 6571, 6427, 4802, 6006, 6147, 6244, 6375, 4344,
+4393, 4387, 6964, 6645, 7340, 6656, 6485, 6372, 4830, 5990, 6087, 6218,
+4388, 4437, 4431, 7016, 6664, 7512, 6806, 6636, 6523, 6353, 4928, 6134,
+6265, 4389, 4437, 4431, 7025, 6668, 7523, 6946, 6770, 6627, 6457, 6509,
+4974, 6271, 4399, 4447, 4441, 7074, 6709, 7575, 6952, 6911, 6768, 6599,
+6650, 6656, 5037, 5000, 3528, 6834, 6839, 6873, 7019, 7030, 7041, 7089,
+4362, 4388, 4300, 4219, 6960, 7250, 4629, 6212, 6285, 6378, 6497, 6627,
+6632, 4416, 4418, 4436, 4355, 6975, 7258, 6585, 4734, 6125, 6218, 6333,
+6458, 6589, 4484, 4476, 4470, 4492, 6985, 7298, 6662, 6493, 4848, 6109,
+6200, 6297, 6428, 4516, 4565, 4559, 4560, 7022, 7459, 6760, 6591, 6478,
+5001, 6150, 6155, 6286, 4516, 4565, 4559, 4561, 7031, 7469, 6888, 6715,
+6575, 6525, 5039, 6159, 6291, 4517, 4566, 4559, 4561, 7040, 7481, 7028,
+6849, 6679, 6530, 6534, 5083, 6296, 4527, 4576, 4569, 4571, 7088, 7532,
+7034, 6991, 6821, 6671, 6676, 6681, 5142, 5000, 8217, 8348, 8479, 8608,
+8780, 8780, 8820, 7118, 7190, 7141, 7093, 7045, 8702, 8194, 8162, 8171,
+8182, 8262, 8421, 8421, 7097, 7150, 7183, 7135, 7086, 8843, 8572, 8156,
+8021, 8032, 8111, 8270, 8435, 7116, 7129, 7143, 7176, 7128, 8985, 8581,
+8421, 8118, 7886, 7965, 8120, 8284, 6950, 7112, 7125, 7139, 7167, 9125,
+8592, 8431, 8276, 8084, 7810, 7931, 8096, 7007, 7169, 7182, 7195, 7209,
+9311, 8676, 8515, 8360, 8195, 8131, 7983, 8148, 7007, 7169, 7182, 7196,
+7209, 9312, 8847, 8687, 8526, 8325, 8381, 8134, 8148, 7047, 7209, 7222,
+7236, 7249, 9356, 8848, 8862, 8702, 8501, 8557, 8558, 8178, 5000, 6465,
+6486, 6496, 6566, 6573, 6581, 3411, 3452, 3382, 3292, 3224, 5249, 3900,
+6817, 6829, 6864, 7039, 7049, 7059, 4663, 4651, 4704, 4629, 4561, 6829,
+7209, 4900, 6402, 6471, 6596, 6713, 6837, 4723, 4701, 4729, 4760, 4693,
+6855, 7225, 6686, 4999, 6321, 6446, 6559, 6678, 4726, 4759, 4787, 4794,
+4827, 6869, 7265, 6759, 6604, 5109, 6311, 6401, 6494, 4816, 4849, 4877,
+4884, 4897, 6948, 7459, 6891, 6736, 6597, 5261, 6509, 6514, 4817, 4850,
+4878, 4885, 4898, 6957, 7470, 7015, 6856, 6692, 6809, 5300, 6519, 4818,
+4851, 4878, 4885, 4898, 6965, 7481, 7146, 6982, 6790, 6815, 6820, 5343,
+5000, 6182, 6209, 6258, 6265, 6309, 4262, 4311, 4200, 4105, 4016, 3682,
+3324, 6755, 6185, 6272, 6393, 6509, 6525, 3298, 3354, 3390, 3300, 3232,
+5249, 7143, 3822, 6681, 6716, 6891, 6901, 7049, 4604, 4614, 4594, 4624,
+4557, 6528, 6465, 7065, 4880, 6282, 6404, 6517, 6655, 4607, 4671, 4651,
+4658, 4691, 6561, 6556, 7106, 6563, 4990, 6243, 6333, 6471, 4693, 4757,
+4737, 4744, 4757, 6617, 6684, 7300, 6691, 6525, 5137, 6353, 6491, 4694,
+4758, 4738, 4745, 4758, 6625, 6808, 7310, 6812, 6620, 6644, 5176, 6497,
+4736, 4800, 4780, 4787, 4800, 6675, 6827, 7472, 6958, 6766, 6791, 6796,
+5273, 5000, 5859, 5932, 5939, 5954, 4185, 4224, 4118, 4014, 3926, 3664,
+3283, 5657, 6752, 6188, 6308, 6425, 6410, 4185, 4194, 4206, 4102, 4014,
+3990, 5921, 3341, 6622, 6085, 6206, 6323, 6441, 3190, 3242, 3260, 3309,
+3241, 5249, 7143, 7003, 3750, 6578, 6753, 6763, 6912, 4516, 4552, 4535,
+4521, 4555, 6183, 6470, 6362, 6957, 4871, 6210, 6299, 6434, 4602, 4638,
+4621, 4608, 4621, 6267, 6598, 6490, 7151, 6490, 5018, 6294, 6429, 4602,
+4638, 4622, 4608, 4621, 6274, 6722, 6614, 7162, 6584, 6581, 5057, 6434,
+4645, 4677, 4660, 4647, 4660, 6292, 6708, 6740, 7323, 6728, 6725, 6730,
+5149, 5000, 5582, 5589, 5601, 4069, 4140, 4034, 3930, 3838, 3659, 3245,
+5529, 5661, 6746, 6227, 6344, 6324, 4069, 4110, 4122, 4018, 3926, 3966,
+5789, 3302, 5577, 6616, 6125, 6242, 6360, 4095, 4110, 4093, 4107, 4014,
+4343, 5928, 5838, 3365, 6490, 6026, 6139, 6257, 3047, 3132, 3151, 3177,
+3252, 5250, 7141, 7001, 6864, 3686, 6588, 6598, 6746, 4386, 4516, 4499,
+4483, 4482, 5889, 6514, 6407, 6303, 6971, 4897, 6266, 6401, 4387, 4516,
+4499, 4484, 4482, 5896, 6639, 6531, 6423, 6982, 6552, 4935, 6406, 4425,
+4555, 4538, 4522, 4521, 5910, 6619, 6657, 6549, 7143, 6696, 6701, 5028,
+5000, 5269, 5295, 3949, 4020, 3914, 3811, 3718, 3589, 3168, 5396, 5528,
+5663, 6756, 6227, 6208, 3949, 3990, 4002, 3899, 3806, 3919, 5652, 3226,
+5444, 5579, 6627, 6126, 6243, 3975, 3991, 3974, 3987, 3895, 4269, 5790,
+5701, 3288, 5498, 6501, 6024, 6141, 3880, 3991, 3975, 3959, 3980, 4646,
+5932, 5842, 5757, 3355, 6389, 5896, 6014, 2919, 3004, 3023, 3049, 3102,
+5250, 7157, 7017, 6880, 6759, 3611, 6586, 6735, 4262, 4392, 4375, 4360,
+4356, 5551, 6520, 6413, 6305, 6170, 6971, 4824, 6361, 4301, 4430, 4414,
+4398, 4395, 5581, 6500, 6538, 6431, 6296, 7132, 6655, 4917, 5000, 5283,
+3933, 4004, 3898, 3794, 3702, 3581, 3130, 5333, 5469, 5603, 5741, 6895,
+6199, 3966, 4007, 4019, 3915, 3823, 3912, 5588, 3221, 5409, 5544, 5682,
+6766, 6235, 3997, 4012, 3996, 4009, 3917, 4262, 5730, 5666, 3289, 5468,
+5606, 6635, 6137, 3898, 4009, 3993, 3977, 4002, 4639, 5871, 5807, 5727,
+3355, 5526, 6494, 6010, 3937, 4048, 4032, 4016, 4013, 4987, 6017, 5953,
+5873, 5788, 3452, 6536, 6054, 2919, 3005, 3023, 3050, 3103, 5251, 7307,
+7167, 7026, 6873, 6920, 3661, 6735, 4296, 4425, 4409, 4393, 4389, 5568,
+6492, 6530, 6426, 6292, 6339, 7133, 4917, 5000, 3928, 3999, 3893, 3789,
+3697, 3572, 3130, 5333, 5469, 5603, 5741, 5862, 6895, 3922, 3963, 3975,
+3871, 3779, 3864, 5588, 3169, 5326, 5465, 5602, 5723, 6885, 3982, 3996,
+3979, 3992, 3900, 4247, 5730, 5582, 3266, 5410, 5547, 5664, 6754, 3887,
+3998, 3981, 3966, 3991, 4627, 5871, 5727, 5668, 3338, 5471, 5561, 6613,
+3926, 4037, 4020, 4005, 4001, 4959, 6017, 5873, 5813, 5732, 3434, 5605,
+6655, 3926, 4037, 4021, 4005, 4001, 4972, 6145, 6000, 5936, 5827, 5874,
+3483, 6661, 2905, 2990, 3008, 3035, 3088, 5251, 7308, 7298, 7156, 7003,
+7050, 7056, 3702, 5000, 7559, 7398, 7238, 7078, 6969, 3003, 6615, 6733,
+6893, 7054, 7215, 7228, 6661, 6384, 6411, 6292, 6168, 6096, 7017, 4353,
+5890, 6024, 6162, 6303, 6433, 6658, 6350, 6346, 6374, 6251, 6178, 7144,
+6253, 4395, 5879, 6017, 6158, 6288, 6492, 6356, 6352, 6345, 6374, 6301,
+7315, 6395, 6240, 4475, 5898, 6040, 6165, 6510, 6374, 6370, 6363, 6356,
+6429, 7488, 6542, 6387, 6260, 4576, 6087, 6184, 6562, 6423, 6419, 6412,
+6405, 6448, 7662, 6693, 6538, 6411, 6463, 4688, 6232, 6571, 6428, 6425,
+6417, 6410, 6454, 7676, 6833, 6679, 6546, 6568, 6620, 4750, 5000, 7355,
+7195, 7035, 6926, 2984, 6576, 6699, 6819, 6980, 7141, 7153, 3941, 6656,
+6401, 6277, 6154, 6044, 6978, 4316, 5879, 5981, 6119, 6261, 6391, 3980,
+6636, 6341, 6369, 6246, 6135, 7109, 6241, 4391, 5868, 6006, 6147, 6277,
+3957, 6607, 6314, 6306, 6335, 6225, 7239, 6349, 6228, 4475, 5864, 6005,
+6130, 3997, 6625, 6332, 6324, 6317, 6353, 7412, 6496, 6375, 6222, 4574,
+6052, 6150, 4041, 6677, 6381, 6373, 6366, 6372, 7585, 6647, 6526, 6373,
+6425, 4683, 6197, 4042, 6686, 6386, 6379, 6372, 6378, 7598, 6788, 6667,
+6508, 6530, 6583, 4742, 5000, 7308, 7149, 6999, 3134, 6704, 6678, 6799,
+6959, 7119, 7131, 4110, 4097, 6726, 6392, 6268, 6158, 7118, 4485, 5925,
+6027, 6164, 6306, 6436, 4110, 4088, 6694, 6483, 6360, 6245, 7089, 6289,
+4506, 5904, 6041, 6183, 6313, 4086, 4121, 6665, 6421, 6449, 6334, 7218,
+6397, 6265, 4589, 5899, 6041, 6166, 4126, 4161, 6682, 6438, 6431, 6462,
+7390, 6544, 6412, 6259, 4685, 6088, 6185, 4171, 4205, 6734, 6487, 6480,
+6481, 7563, 6695, 6563, 6410, 6462, 4791, 6233, 4171, 4206, 6743, 6493,
+6486, 6487, 7576, 6836, 6703, 6545, 6567, 6619, 4847, 5000, 7246, 7093,
+3286, 6647, 6795, 6767, 6926, 7086, 7098, 4187, 4174, 4189, 6714, 6370,
+6256, 7054, 4532, 6029, 6084, 6222, 6363, 6493, 4278, 4256, 4250, 6737,
+6466, 6352, 7217, 6396, 4674, 5948, 6086, 6227, 6352, 4215, 4250, 4243,
+6702, 6555, 6441, 7185, 6455, 6312, 4702, 5929, 6070, 6195, 4255, 4290,
+4283, 6720, 6537, 6569, 7357, 6602, 6459, 6289, 4795, 6117, 6215, 4299,
+4334, 4328, 6772, 6586, 6588, 7529, 6753, 6610, 6440, 6492, 4899, 6263,
+4300, 4334, 4328, 6781, 6592, 6594, 7542, 6893, 6745, 6575, 6597, 6649,
+4951, 5000, 7165, 3438, 6682, 6716, 6862, 6873, 7033, 7044, 4288, 4275,
+4293, 4212, 6702, 6347, 7090, 4605, 6090, 6179, 6295, 6436, 6566, 4355,
+4333, 4327, 4349, 6724, 6442, 7130, 6458, 4719, 6043, 6134, 6275, 6400,
+4383, 4418, 4411, 4413, 6734, 6536, 7291, 6552, 6409, 4868, 5992, 6133,
+6230, 4383, 4418, 4412, 4413, 6742, 6664, 7301, 6676, 6506, 6356, 4906,
+6138, 6235, 4428, 4462, 4456, 4458, 6795, 6683, 7473, 6827, 6657, 6507,
+6512, 5007, 6283, 4428, 4463, 4456, 4458, 6803, 6688, 7485, 6968, 6792,
+6612, 6617, 6669, 5056, 5000, 3545, 6714, 6721, 6753, 6938, 6949, 6960,
+4342, 4329, 4385, 4307, 4238, 6690, 7123, 4642, 6166, 6237, 6366, 6486,
+6616, 4405, 4383, 4413, 4444, 4374, 6712, 7133, 6535, 4750, 6079, 6207,
+6323, 6448, 4410, 4444, 4474, 4480, 4514, 6721, 7171, 6611, 6444, 4870,
+6065, 6157, 6254, 4505, 4540, 4569, 4576, 4589, 6801, 7374, 6748, 6580,
+6430, 5033, 6267, 6273, 4506, 4540, 4570, 4576, 4589, 6809, 7385, 6876,
+6705, 6528, 6652, 5074, 6278, 4506, 4541, 4570, 4577, 4590, 6818, 7397,
+7017, 6840, 6633, 6658, 6663, 5120, 5000, 8197, 8327, 8456, 8628, 8799,
+8799, 7093, 7090, 7125, 7078, 7031, 7029, 8681, 8182, 8150, 8156, 8210,
+8290, 8449, 7112, 7070, 7084, 7119, 7072, 7071, 8822, 8559, 8144, 8006,
+8060, 8139, 8299, 6940, 7047, 7061, 7076, 7111, 7110, 8961, 8565, 8405,
+8104, 7871, 7950, 8105, 6965, 7072, 7086, 7101, 7116, 7191, 9147, 8622,
+8462, 8261, 8114, 8003, 8124, 7022, 7129, 7143, 7158, 7173, 7233, 9333,
+8706, 8546, 8345, 8401, 8162, 8176, 7023, 7129, 7144, 7158, 7173, 7234,
+9334, 8878, 8718, 8511, 8531, 8588, 8165, 5000, 6172, 6198, 6245, 6315,
+6322, 3444, 3461, 3497, 3439, 3374, 3351, 5249, 3924, 6661, 6673, 6743,
+6919, 6929, 4699, 4670, 4650, 4709, 4645, 4610, 6513, 7043, 4919, 6328,
+6431, 6557, 6674, 4698, 4723, 4703, 4737, 4779, 4744, 6544, 7059, 6607,
+5024, 6250, 6375, 6489, 4762, 4787, 4767, 4801, 4819, 4884, 6600, 7139,
+6717, 6529, 5143, 6396, 6485, 4853, 4877, 4857, 4892, 4909, 4955, 6679,
+7333, 6849, 6661, 6686, 5297, 6595, 4854, 4878, 4858, 4893, 4910, 4956,
+6688, 7344, 6975, 6782, 6781, 6900, 5337, 5000, 5848, 5920, 5970, 5978,
+4298, 4268, 4309, 4206, 4121, 4065, 3997, 3346, 6591, 6106, 6226, 6348,
+6465, 3336, 3349, 3399, 3447, 3382, 3360, 5249, 6969, 3849, 6520, 6590,
+6766, 6776, 4607, 4603, 4614, 4601, 4643, 4608, 6167, 6381, 6894, 4904,
+6206, 6328, 6442, 4671, 4667, 4678, 4665, 4682, 4748, 6250, 6508, 6974,
+6482, 5023, 6322, 6412, 4761, 4754, 4765, 4751, 4769, 4815, 6308, 6636,
+7168, 6611, 6608, 5173, 6433, 4762, 4755, 4766, 4752, 4770, 4816, 6316,
+6762, 7179, 6732, 6704, 6729, 5213, 5000, 5572, 5658, 5665, 4182, 4184,
+4225, 4126, 4032, 3977, 3974, 3308, 5578, 6585, 6145, 6267, 6384, 4208,
+4184, 4195, 4214, 4120, 4065, 4351, 5836, 3368, 6456, 6043, 6165, 6282,
+3193, 3239, 3289, 3321, 3394, 3371, 5250, 6966, 6827, 3782, 6416, 6592,
+6602, 4455, 4545, 4556, 4546, 4543, 4609, 5874, 6425, 6318, 6785, 4901,
+6292, 6381, 4541, 4632, 4643, 4632, 4630, 4676, 5971, 6554, 6446, 6980,
+6575, 5051, 6371, 4542, 4632, 4643, 4633, 4630, 4676, 5979, 6679, 6572,
+6991, 6671, 6663, 5091, 5000, 5353, 5359, 4063, 4064, 4105, 4006, 3913,
+3853, 3928, 3231, 5445, 5579, 6595, 6150, 6268, 4089, 4064, 4075, 4094,
+4001, 3941, 4277, 5699, 3291, 5495, 6467, 6049, 6167, 3989, 4061, 4072,
+4062, 4086, 4026, 4653, 5840, 5752, 3356, 6326, 5921, 6035, 3065, 3111,
+3161, 3193, 3243, 3365, 5250, 6982, 6843, 6690, 3702, 6579, 6589, 4417,
+4507, 4518, 4508, 4504, 4534, 5642, 6435, 6328, 6193, 6967, 4940, 6326,
+4418, 4508, 4519, 4509, 4504, 4535, 5649, 6560, 6453, 6314, 6978, 6617,
+4980, 5000, 5264, 3942, 3944, 3985, 3886, 3792, 3733, 3858, 3154, 5311,
+5446, 5583, 6606, 6152, 3968, 3944, 3955, 3974, 3881, 3821, 4228, 5562,
+3214, 5362, 5500, 6478, 6051, 3869, 3941, 3952, 3942, 3966, 3906, 4565,
+5703, 5614, 3279, 5393, 6337, 5919, 3908, 3980, 3991, 3981, 3976, 4030,
+4896, 5849, 5760, 5646, 3372, 6379, 5935, 2937, 2983, 3034, 3065, 3116,
+3215, 5251, 6999, 6859, 6707, 6753, 3630, 6578, 4294, 4383, 4394, 4384,
+4380, 4408, 5548, 6441, 6335, 6196, 6213, 6967, 4869, 5000, 3926, 3927,
+3968, 3869, 3776, 3716, 3850, 3116, 5248, 5386, 5524, 5662, 6744, 3986,
+3960, 3971, 3990, 3897, 3837, 4221, 5498, 3210, 5327, 5465, 5603, 6616,
+3891, 3962, 3973, 3963, 3987, 3928, 4558, 5643, 5580, 3280, 5363, 5501,
+6471, 3926, 3997, 4008, 3998, 3993, 4051, 4889, 5789, 5725, 5616, 3373,
+5544, 6484, 3965, 4037, 4048, 4037, 4033, 4062, 4992, 5935, 5872, 5763,
+5810, 3472, 6526, 2938, 2984, 3034, 3066, 3117, 3216, 5251, 7149, 7010,
+6852, 6867, 6915, 3683, 5000, 7542, 7519, 7359, 7200, 7090, 6932, 3042,
+6621, 6780, 6941, 7102, 7264, 6328, 6386, 6342, 6370, 6251, 6178, 6056,
+7023, 4353, 5894, 6032, 6170, 6312, 6171, 6393, 6348, 6345, 6374, 6301,
+6179, 7193, 6257, 4432, 5908, 6047, 6189, 6184, 6405, 6360, 6357, 6350,
+6429, 6307, 7367, 6404, 6271, 4533, 6066, 6208, 6201, 6423, 6379, 6376,
+6369, 6412, 6436, 7540, 6552, 6419, 6441, 4641, 6256, 6254, 6473, 6428,
+6425, 6418, 6462, 6456, 7715, 6703, 6571, 6592, 6645, 4760, 5000, 7500,
+7340, 7180, 7031, 6916, 3038, 6570, 6691, 6851, 7012, 7174, 3983, 6328,
+6366, 6394, 6275, 6167, 6089, 6971, 4384, 5892, 6030, 6168, 6310, 3959,
+6308, 6340, 6336, 6365, 6256, 6178, 7100, 6254, 4468, 5882, 6021, 6163,
+3999, 6321, 6352, 6348, 6342, 6385, 6307, 7273, 6401, 6243, 4567, 6040,
+6182, 4039, 6339, 6370, 6367, 6360, 6368, 6436, 7446, 6549, 6391, 6412,
+4671, 6230, 4084, 6391, 6420, 6417, 6410, 6417, 6456, 7621, 6701, 6542,
+6564, 6617, 4787, 5000, 7303, 7143, 6994, 6879, 3056, 6537, 6657, 6817,
+6978, 7139, 3983, 3958, 6306, 6392, 6269, 6154, 6045, 6937, 4379, 5879,
+6017, 6155, 6297, 3959, 3991, 6286, 6329, 6358, 6244, 6134, 7066, 6241,
+4461, 5870, 6008, 6150, 3999, 4031, 6299, 6342, 6335, 6372, 6262, 7238,
+6388, 6229, 4557, 6027, 6169, 4039, 4071, 6317, 6360, 6353, 6355, 6392,
+7411, 6535, 6377, 6399, 4659, 6217, 4084, 4116, 6369, 6410, 6403, 6405,
+6411, 7585, 6687, 6529, 6551, 6603, 4772, 5000, 7241, 7088, 6939, 3204,
+6652, 6624, 6783, 6943, 7104, 4151, 4127, 4106, 6387, 6375, 6262, 6151,
+7064, 4547, 5918, 6056, 6194, 6336, 4088, 4120, 4100, 6351, 6464, 6351,
+6236, 7032, 6282, 4574, 5899, 6037, 6179, 4128, 4160, 4139, 6365, 6440,
+6479, 6365, 7203, 6428, 6259, 4668, 6056, 6199, 4168, 4200, 4179, 6382,
+6459, 6462, 6494, 7376, 6576, 6407, 6428, 4767, 6246, 4212, 4244, 4224,
+6435, 6509, 6511, 6513, 7549, 6728, 6558, 6580, 6633, 4876, 5000, 7160,
+7007, 3354, 6569, 6715, 6726, 6886, 7046, 4228, 4204, 4183, 4205, 6375,
+6352, 6238, 6973, 4591, 6011, 6102, 6241, 6383, 4256, 4288, 4267, 4269,
+6405, 6445, 6332, 7133, 6377, 4740, 5932, 6071, 6213, 4256, 4288, 4268,
+4270, 6412, 6573, 6460, 7144, 6474, 6294, 4778, 6075, 6217, 4296, 4328,
+4308, 4310, 6429, 6556, 6589, 7316, 6622, 6442, 6447, 4874, 6265, 4341,
+4373, 4352, 4354, 6482, 6606, 6609, 7489, 6774, 6594, 6598, 6651, 4980,
+5000, 7030, 3459, 6561, 6593, 6778, 6789, 6949, 4278, 4289, 4269, 4300,
+4231, 6363, 6294, 6962, 4622, 6041, 6166, 6282, 6424, 4283, 4351, 4330,
+4337, 4370, 6392, 6387, 6999, 6405, 4741, 5996, 6087, 6229, 4374, 4442,
+4421, 4428, 4441, 6446, 6520, 7203, 6538, 6358, 4901, 6106, 6249, 4374,
+4442, 4422, 4428, 4441, 6454, 6648, 7214, 6662, 6456, 6480, 4941, 6254,
+4419, 4487, 4466, 4473, 4486, 6506, 6668, 7386, 6814, 6608, 6632, 6637,
+5044, 5000, 3570, 6548, 6556, 6627, 6812, 6823, 4381, 4349, 4328, 4391,
+4325, 4290, 6351, 6947, 4662, 6088, 6195, 6324, 6444, 4382, 4406, 4385,
+4422, 4464, 4430, 6379, 6958, 6451, 4776, 6004, 6133, 6249, 4449, 4473,
+4453, 4490, 4508, 4576, 6432, 7038, 6565, 6362, 4905, 6152, 6244, 4545,
+4569, 4549, 4585, 4603, 4651, 6512, 7242, 6703, 6500, 6524, 5071, 6355,
+4546, 4570, 4549, 4586, 4604, 4652, 6521, 7253, 6832, 6625, 6623, 6747,
+5113, 5000, 8172, 8300, 8471, 8643, 8815, 7095, 7060, 7020, 7056, 7011,
+7011, 7006, 8655, 8164, 8129, 8179, 8233, 8312, 6923, 7037, 6996, 7013,
+7050, 7050, 7045, 8793, 8536, 8124, 7985, 8039, 8118, 6943, 7056, 7016,
+7032, 7049, 7131, 7126, 8979, 8590, 8383, 8128, 8058, 8137, 6968, 7082,
+7041, 7057, 7074, 7136, 7208, 9165, 8647, 8440, 8461, 8139, 8189, 7025,
+7138, 7098, 7114, 7131, 7193, 7250, 9352, 8731, 8524, 8545, 8602, 8187,
+5000, 5837, 5909, 5958, 6027, 3446, 3494, 3505, 3554, 3521, 3501, 3526,
+5249, 3948, 6445, 6495, 6565, 6741, 4669, 4698, 4670, 4656, 4727, 4695,
+4694, 6152, 6812, 4941, 6204, 6308, 6434, 4729, 4758, 4730, 4716, 4761,
+4836, 4834, 6234, 6870, 6475, 5054, 6282, 6408, 4797, 4822, 4794, 4780,
+4825, 4876, 4975, 6291, 6950, 6586, 6562, 5175, 6429, 4911, 4913, 4885,
+4871, 4916, 4967, 5046, 6371, 7145, 6719, 6695, 6721, 5330, 5000, 5561,
+5646, 5693, 4289, 4297, 4268, 4316, 4223, 4171, 4149, 4358, 3371, 6364,
+6010, 6130, 6252, 3302, 3384, 3395, 3459, 3532, 3512, 3537, 5250, 6727,
+3878, 6300, 6370, 6546, 4513, 4636, 4607, 4625, 4622, 4696, 4695, 5858,
+6279, 6659, 4932, 6234, 6357, 4577, 4700, 4671, 4689, 4686, 4736, 4836,
+5955, 6407, 6739, 6511, 5053, 6347, 4664, 4787, 4758, 4776, 4773, 4823,
+4903, 6010, 6536, 6934, 6641, 6633, 5203, 5000, 5342, 5443, 4169, 4177,
+4149, 4196, 4108, 4047, 4025, 4285, 3294, 5442, 6375, 6015, 6137, 4070,
+4174, 4146, 4163, 4193, 4131, 4110, 4661, 5692, 3356, 6231, 5884, 6006,
+3174, 3256, 3267, 3331, 3387, 3505, 3530, 5250, 6743, 6587, 3795, 6347,
+6523, 4453, 4576, 4547, 4565, 4565, 4595, 4695, 5627, 6288, 6150, 6716,
+4941, 6297, 4540, 4662, 4634, 4652, 4652, 4682, 4762, 5741, 6418, 6279,
+6910, 6582, 5092, 5000, 5348, 4049, 4057, 4028, 4076, 3987, 3927, 3900,
+4237, 3217, 5308, 5446, 6386, 6021, 3949, 4054, 4025, 4043, 4072, 4012,
+3985, 4573, 5555, 3279, 5336, 6243, 5890, 3984, 4089, 4060, 4078, 4078,
+4135, 4109, 4903, 5700, 5583, 3370, 6255, 5906, 3046, 3129, 3140, 3203,
+3259, 3355, 3524, 5251, 6759, 6604, 6618, 3718, 6511, 4416, 4538, 4509,
+4527, 4527, 4555, 4620, 5639, 6299, 6161, 6178, 6898, 4981, 5000, 3925,
+3936, 3907, 3955, 3866, 3806, 3780, 4167, 3140, 5175, 5313, 5451, 6397,
+3829, 3933, 3904, 3922, 3951, 3891, 3865, 4527, 5418, 3202, 5202, 5341,
+6253, 3864, 3968, 3939, 3957, 3957, 4014, 3988, 4799, 5563, 5446, 3294,
+5357, 6267, 3903, 4007, 3978, 3996, 3997, 4025, 4112, 4902, 5709, 5592,
+5610, 3389, 6309, 2919, 3001, 3012, 3075, 3131, 3228, 3374, 5251, 6776,
+6620, 6635, 6683, 3647, 5000, 7537, 7497, 7476, 7316, 7207, 7048, 6930,
+3050, 6629, 6790, 6951, 7113, 5770, 6393, 6358, 6314, 6343, 6274, 6152,
+6066, 7031, 4365, 5893, 6031, 6173, 5795, 6405, 6370, 6326, 6324, 6403,
+6281, 6195, 7205, 6257, 4466, 6045, 6187, 5814, 6418, 6383, 6339, 6337,
+6380, 6410, 6324, 7378, 6405, 6421, 4574, 6206, 5866, 6436, 6401, 6357,
+6354, 6398, 6392, 6457, 7553, 6557, 6573, 6594, 4693, 5000, 7483, 7461,
+7302, 7152, 7038, 6880, 3083, 6579, 6739, 6900, 7061, 3955, 5931, 6376,
+6332, 6361, 6256, 6178, 6057, 6979, 4426, 5897, 6036, 6175, 3995, 5956,
+6388, 6344, 6342, 6385, 6307, 6186, 7152, 6260, 4524, 6050, 6189, 4035,
+5970, 6401, 6357, 6354, 6362, 6436, 6315, 7325, 6407, 6423, 4629, 6209,
+4079, 5987, 6420, 6376, 6374, 6381, 6420, 6445, 7499, 6556, 6572, 6594,
+4741, 5000, 7448, 7289, 7133, 6984, 6871, 3115, 6529, 6689, 6849, 7010,
+3990, 3993, 5931, 6355, 6384, 6273, 6165, 6088, 6927, 4454, 5893, 6032,
+6171, 4030, 4033, 5956, 6367, 6364, 6402, 6294, 6216, 7100, 6255, 4550,
+6046, 6185, 4070, 4073, 5970, 6380, 6377, 6379, 6423, 6346, 7273, 6403,
+6419, 4652, 6205, 4114, 4113, 5987, 6399, 6397, 6399, 6407, 6475, 7446,
+6552, 6568, 6590, 4760, 5000, 7236, 7083, 6934, 6820, 3130, 6482, 6642,
+6802, 6962, 3989, 3992, 3969, 5909, 6373, 6259, 6146, 6037, 6880, 4447,
+5874, 6012, 6151, 4029, 4032, 4009, 5934, 6349, 6388, 6274, 6165, 7052,
+6235, 4540, 6027, 6166, 4069, 4072, 4049, 5948, 6362, 6365, 6403, 6294,
+7224, 6383, 6399, 4639, 6186, 4114, 4112, 4089, 5965, 6382, 6384, 6387,
+6424, 7397, 6531, 6547, 6569, 4744, 5000, 7154, 7002, 6853, 3276, 6572,
+6583, 6743, 6903, 4157, 4160, 4137, 4125, 5998, 6354, 6242, 6132, 6980,
+4612, 5902, 6040, 6179, 4157, 4160, 4138, 4125, 6006, 6482, 6369, 6256,
+6990, 6264, 4650, 6045, 6184, 4197, 4200, 4177, 4165, 6019, 6459, 6499,
+6385, 7163, 6412, 6417, 4747, 6204, 4242, 4241, 4218, 4205, 6036, 6479,
+6482, 6515, 7335, 6560, 6565, 6587, 4848, 5000, 7025, 6873, 3378, 6444,
+6629, 6640, 6800, 4184, 4223, 4206, 4192, 4226, 5987, 6297, 6184, 6840,
+4613, 5963, 6054, 6193, 4275, 4314, 4297, 4283, 4297, 6071, 6429, 6317,
+7043, 6325, 4772, 6046, 6185, 4275, 4314, 4297, 4284, 4297, 6078, 6558,
+6445, 7054, 6423, 6417, 4813, 6190, 4320, 4354, 4337, 4324, 4337, 6096,
+6542, 6575, 7226, 6571, 6566, 6571, 4911, 5000, 6845, 3487, 6390, 6461,
+6646, 6657, 4283, 4278, 4291, 4277, 4320, 4286, 5975, 6204, 6780, 4647,
+5960, 6085, 6202, 4351, 4346, 4359, 4345, 4363, 4432, 6058, 6335, 6860,
+6317, 4777, 6077, 6169, 4446, 4437, 4450, 4436, 4454, 4503, 6115, 6469,
+7064, 6451, 6445, 4938, 6189, 4447, 4437, 4450, 4437, 4455, 4503, 6122,
+6598, 7075, 6576, 6544, 6569, 4980, 5000, 3596, 6317, 6366, 6437, 6623,
+4350, 4380, 4349, 4336, 4410, 4379, 4381, 5963, 6700, 4685, 5957, 6064,
+6193, 4414, 4444, 4413, 4400, 4448, 4525, 4527, 6045, 6756, 6308, 4808,
+6035, 6165, 4486, 4512, 4481, 4468, 4516, 4569, 4674, 6101, 6837, 6423,
+6396, 4939, 6184, 4605, 4608, 4577, 4563, 4611, 4664, 4749, 6181, 7041,
+6561, 6534, 6559, 5106, 5000, 8141, 8312, 8483, 8655, 6907, 7015, 6982,
+6944, 6983, 6985, 6982, 6980, 8622, 8136, 8101, 8150, 8204, 6926, 7034,
+7001, 6963, 6982, 7066, 7063, 7061, 8807, 8507, 8140, 8164, 8218, 6951,
+7053, 7021, 6983, 7001, 7066, 7145, 7142, 8993, 8560, 8575, 8145, 8237,
+7008, 7079, 7046, 7008, 7027, 7091, 7150, 7224, 9179, 8618, 8633, 8653,
+8156, 5000, 5550, 5634, 5680, 3445, 3493, 3540, 3564, 3637, 3650, 3678,
+3768, 5250, 3974, 6153, 6205, 6276, 4597, 4698, 4701, 4680, 4677, 4780,
+4782, 4838, 5842, 6498, 4966, 6156, 6261, 4661, 4758, 4761, 4740, 4737,
+4815, 4923, 4979, 5938, 6558, 6424, 5081, 6232, 4747, 4822, 4826, 4804,
+4801, 4879, 4963, 5120, 5992, 6639, 6535, 6508, 5202, 5000, 5331, 5431,
+4153, 4254, 4258, 4236, 4294, 4234, 4215, 4251, 4668, 3357, 6061, 5766,
+5887, 3317, 3365, 3412, 3436, 3524, 3642, 3670, 3761, 5250, 6401, 3889,
+6154, 6225, 4537, 4633, 4637, 4615, 4644, 4674, 4781, 4838, 5611, 6021,
+6504, 4969, 6156, 4623, 4698, 4702, 4680, 4708, 4738, 4822, 4979, 5724,
+6149, 6585, 6428, 5090, 5000, 5337, 4033, 4133, 4137, 4116, 4174, 4118,
+4090, 4127, 4581, 3280, 5197, 6072, 5771, 4068, 4168, 4172, 4150, 4179,
+4241, 4213, 4250, 4911, 5433, 3368, 6082, 5784, 3189, 3237, 3284, 3308,
+3396, 3498, 3664, 3754, 5251, 6417, 6429, 3807, 6200, 4499, 4573, 4577,
+4555, 4583, 4616, 4680, 4838, 5624, 6031, 6045, 6559, 4979, 5000, 3912,
+4012, 4016, 3995, 4053, 3997, 3970, 4002, 4537, 3203, 5064, 5202, 6083,
+3947, 4047, 4051, 4029, 4058, 4120, 4093, 4124, 4807, 5296, 3291, 5215,
+6093, 3986, 4083, 4086, 4065, 4093, 4126, 4216, 4248, 4909, 5442, 5457,
+3384, 6107, 3062, 3110, 3157, 3181, 3269, 3370, 3514, 3748, 5251, 6434,
+6446, 6461, 3731, 5000, 7569, 7530, 7491, 7470, 7361, 7203, 7084, 6931,
+3263, 6798, 6959, 7119, 5581, 6535, 6500, 6465, 6422, 6501, 6383, 6297,
+6208, 7208, 4534, 6211, 6350, 5608, 6548, 6513, 6478, 6435, 6483, 6512,
+6426, 6337, 7381, 6593, 4642, 6365, 5620, 6561, 6526, 6491, 6448, 6496,
+6490, 6556, 6467, 7555, 6742, 6759, 4756, 5000, 7480, 7441, 7420, 7271,
+7156, 6999, 6881, 3137, 6589, 6750, 6911, 3861, 5592, 6418, 6384, 6341,
+6384, 6310, 6189, 6104, 6989, 4479, 6070, 6208, 3901, 5620, 6431, 6397,
+6354, 6365, 6439, 6318, 6233, 7163, 6446, 4584, 6223, 3942, 5634, 6445,
+6410, 6367, 6379, 6417, 6448, 6363, 7337, 6594, 6611, 4695, 5000, 7428,
+7407, 7252, 7103, 6989, 6832, 3167, 6539, 6699, 6860, 3897, 3996, 5592,
+6407, 6364, 6401, 6297, 6220, 6099, 6938, 4505, 6066, 6205, 3937, 4036,
+5620, 6420, 6376, 6383, 6426, 6349, 6229, 7111, 6441, 4607, 6220, 3977,
+4077, 5634, 6433, 6390, 6396, 6404, 6479, 6358, 7284, 6590, 6607, 4715,
+5000, 7378, 7225, 7071, 6922, 6809, 3197, 6476, 6636, 6797, 3931, 4031,
+4008, 5592, 6378, 6417, 6307, 6200, 6123, 6872, 4530, 6055, 6194, 3971,
+4071, 4048, 5620, 6391, 6398, 6436, 6329, 6252, 7045, 6430, 4629, 6209,
+4011, 4111, 4088, 5634, 6405, 6412, 6415, 6459, 6382, 7218, 6579, 6595,
+4734, 5000, 7146, 6994, 6846, 6733, 3209, 6404, 6564, 6724, 3930, 4030,
+4007, 3992, 5570, 6394, 6282, 6169, 6061, 6798, 4520, 6025, 6164, 3970,
+4070, 4047, 4032, 5598, 6372, 6411, 6298, 6190, 6970, 6398, 4616, 6179,
+4010, 4110, 4087, 4072, 5612, 6385, 6389, 6428, 6319, 7143, 6546, 6563,
+4718, 5000, 7017, 6865, 6717, 3307, 6448, 6459, 6619, 4047, 4183, 4166,
+4150, 4149, 5665, 6342, 6230, 6121, 6849, 4642, 6021, 6160, 4048, 4184,
+4166, 4151, 4150, 5672, 6470, 6358, 6245, 6860, 6392, 4682, 6165, 4088,
+4224, 4206, 4191, 4190, 5686, 6448, 6488, 6375, 7032, 6541, 6546, 4780,
+5000, 6837, 6686, 3412, 6272, 6457, 6468, 4123, 4215, 4228, 4218, 4216,
+4285, 5654, 6248, 6136, 6657, 4646, 6048, 6140, 4214, 4306, 4319, 4309,
+4307, 4356, 5753, 6382, 6270, 6861, 6417, 4807, 6127, 4215, 4307, 4320,
+4310, 4308, 4356, 5760, 6511, 6399, 6872, 6515, 6505, 4849, 5000, 6587,
+3518, 6155, 6226, 6411, 4186, 4313, 4282, 4302, 4300, 4378, 4380, 5641,
+6093, 6529, 4677, 5987, 6114, 4254, 4381, 4350, 4370, 4368, 4421, 4527,
+5740, 6225, 6610, 6347, 4808, 6101, 4346, 4473, 4442, 4462, 4460, 4513,
+4598, 5793, 6359, 6814, 6482, 6471, 4970, 5000, 3624, 6003, 6054, 6126,
+4275, 4380, 4384, 4360, 4358, 4468, 4473, 4538, 5630, 6363, 4712, 5904,
+6012, 4343, 4444, 4448, 4424, 4422, 4505, 4619, 4684, 5727, 6422, 6251,
+4836, 5980, 4435, 4512, 4516, 4492, 4490, 4573, 4663, 4831, 5779, 6503,
+6366, 6335, 4968, 5000, 8106, 8277, 8448, 6985, 7005, 6975, 6945, 6910,
+6997, 6996, 6996, 6996, 8585, 8148, 8301, 8351, 7004, 7024, 6994, 6964,
+6929, 6996, 7078, 7077, 7078, 8770, 8722, 8152, 8364, 7024, 7044, 7014,
+6984, 6949, 7016, 7077, 7159, 7159, 8956, 8775, 8790, 8157, 5000, 5318,
+5418, 3283, 3490, 3537, 3597, 3645, 3764, 3824, 3917, 4094, 5250, 4000,
+5930, 5980, 4621, 4722, 4726, 4736, 4725, 4755, 4892, 4952, 5099, 5592,
+6257, 5020, 5996, 4682, 4782, 4786, 4797, 4786, 4815, 4927, 5093, 5240,
+5704, 6315, 6253, 5135, 5000, 5325, 4156, 4278, 4282, 4293, 4281, 4372,
+4346, 4385, 4511, 4919, 3381, 5826, 5569, 3155, 3362, 3409, 3469, 3518,
+3651, 3817, 3910, 4088, 5251, 6147, 3914, 5917, 4557, 4658, 4661, 4672,
+4661, 4722, 4785, 4951, 5098, 5606, 5812, 6247, 5023, 5000, 4035, 4157,
+4161, 4172, 4160, 4251, 4229, 4260, 4386, 4816, 3304, 4977, 5837, 4071,
+4192, 4196, 4207, 4196, 4256, 4352, 4383, 4509, 4916, 5200, 3394, 5847,
+3028, 3235, 3282, 3342, 3390, 3524, 3673, 3904, 4082, 5251, 6163, 6175,
+3834, 5000, 7586, 7547, 7508, 7470, 7500, 7341, 7223, 7070, 7101, 3308,
+6975, 7135, 5309, 6538, 6503, 6468, 6434, 6442, 6471, 6390, 6301, 6205,
+7399, 4606, 6356, 5339, 6551, 6516, 6481, 6448, 6455, 6453, 6519, 6430,
+6335, 7572, 6749, 4721, 5000, 7497, 7459, 7421, 7410, 7295, 7138, 7020,
+6884, 3179, 6765, 6927, 3862, 5321, 6422, 6387, 6353, 6324, 6398, 6281,
+6196, 6078, 7180, 4548, 6214, 3902, 5352, 6435, 6401, 6367, 6338, 6381,
+6411, 6326, 6208, 7354, 6601, 4660, 5000, 7445, 7407, 7391, 7242, 7128,
+6971, 6836, 3206, 6715, 6876, 3897, 3997, 5321, 6410, 6376, 6342, 6385,
+6312, 6192, 6074, 7128, 4571, 6210, 3937, 4037, 5352, 6424, 6390, 6355,
+6368, 6442, 6322, 6204, 7301, 6597, 4679, 5000, 7378, 7364, 7209, 7061,
+6948, 6773, 3233, 6652, 6812, 3931, 4031, 4008, 5321, 6391, 6357, 6395,
+6292, 6216, 6063, 7062, 4594, 6200, 3971, 4071, 4048, 5352, 6405, 6371,
+6378, 6422, 6346, 6192, 7235, 6586, 4698, 5000, 7308, 7156, 7002, 6855,
+6724, 3259, 6564, 6724, 3965, 4065, 4042, 4027, 5321, 6361, 6400, 6291,
+6184, 6075, 6970, 4616, 6179, 4005, 4105, 4082, 4067, 5352, 6374, 6382,
+6421, 6314, 6205, 7143, 6563, 4717, 5000, 7029, 6877, 6730, 6599, 3223,
+6446, 6606, 3914, 4049, 4032, 4016, 4013, 5299, 6342, 6230, 6118, 5977,
+6847, 4562, 6116, 3954, 4089, 4072, 4056, 4053, 5330, 6321, 6360, 6248,
+6107, 7019, 6496, 4660, 5000, 6849, 6698, 6532, 3324, 6442, 6453, 4080,
+4172, 4185, 4175, 4171, 4205, 5397, 6254, 6142, 6001, 6846, 4686, 6078,
+4081, 4172, 4185, 4175, 4171, 4205, 5404, 6383, 6272, 6126, 6857, 6454,
+4728, 5000, 6599, 6430, 3426, 6200, 6385, 4120, 4247, 4216, 4236, 4238,
+4271, 4376, 5385, 6098, 5953, 6583, 4687, 6047, 4211, 4338, 4307, 4328,
+4329, 4362, 4447, 5502, 6232, 6088, 6787, 6415, 4849, 5000, 6236, 3528,
+5998, 6069, 4209, 4309, 4314, 4290, 4321, 4354, 4468, 4534, 5373, 5812,
+6362, 4714, 5897, 4300, 4378, 4382, 4358, 4390, 4423, 4512, 4680, 5489,
+5945, 6443, 6248, 4846, 5000, 3651, 5763, 5811, 4301, 4406, 4410, 4421,
+4409, 4442, 4587, 4656, 4819, 5359, 6103, 4768, 5727, 4364, 4469, 4474,
+4485, 4473, 4506, 4625, 4802, 4965, 5475, 6159, 6055, 4892, 5000, 8262,
+8432, 6938, 6961, 6934, 6907, 6881, 6894, 6978, 6981, 6984, 6971, 8755,
+8101, 8319, 6958, 6981, 6954, 6927, 6900, 6913, 6978, 7063, 7066, 7052,
+8940, 8742, 8106, 5000, 5325, 3114, 3320, 3368, 3428, 3512, 3606, 3773,
+3898, 4078, 4349, 5251, 3861, 5405, 4519, 4620, 4624, 4634, 4655, 4677,
+4741, 4935, 5085, 5338, 5606, 5689, 4973, 5000, 4033, 4155, 4159, 4169,
+4190, 4212, 4336, 4368, 4497, 4726, 4917, 3340, 5286, 2986, 3193, 3240,
+3300, 3385, 3479, 3660, 3891, 4071, 4342, 5251, 5562, 3777, 5000, 7604,
+7564, 7526, 7488, 7500, 7480, 7362, 7209, 7239, 7284, 3359, 7151, 5305,
+6541, 6506, 6472, 6438, 6454, 6412, 6478, 6389, 6298, 6339, 7589, 4685,
+5000, 7514, 7476, 7438, 7410, 7434, 7276, 7159, 7023, 7068, 3229, 6943,
+3862, 5317, 6426, 6391, 6357, 6338, 6340, 6370, 6289, 6171, 6212, 7371,
+4624, 5000, 7463, 7425, 7391, 7381, 7267, 7110, 6974, 7019, 3252, 6892,
+3897, 3996, 5317, 6414, 6380, 6355, 6327, 6401, 6285, 6167, 6207, 7318,
+4643, 5000, 7395, 7364, 7348, 7200, 7087, 6912, 6957, 3275, 6828, 3931,
+4030, 4008, 5317, 6395, 6371, 6337, 6381, 6309, 6156, 6196, 7252, 4663,
+5000, 7309, 7295, 7141, 6994, 6863, 6868, 3298, 6740, 3965, 4064, 4041,
+4027, 5317, 6374, 6341, 6380, 6277, 6168, 6173, 7160, 4682, 5000, 7191,
+7040, 6886, 6721, 6770, 3276, 6605, 3949, 4084, 4067, 4051, 4048, 5317,
+6309, 6349, 6241, 6101, 6151, 7019, 4659, 5000, 6861, 6710, 6545, 6594,
+3242, 6440, 3946, 4037, 4050, 4040, 4036, 4068, 5294, 6255, 6144, 5999,
+6016, 6844, 4607, 5000, 6611, 6443, 6457, 3339, 6371, 4077, 4203, 4172,
+4193, 4194, 4225, 4295, 5393, 6104, 5960, 5977, 6773, 4728, 5000, 6248,
+6259, 3437, 6041, 4166, 4243, 4247, 4223, 4254, 4291, 4360, 4529, 5381,
+5818, 5832, 6414, 4725, 5000, 5962, 3555, 5741, 4229, 4334, 4339, 4350,
+4338, 4404, 4474, 4651, 4814, 5367, 5577, 6084, 4770, 5000, 3498, 5182,
+4189, 4294, 4298, 4309, 4332, 4355, 4424, 4633, 4800, 5079, 5367, 5477,
+4717, 5000, 8413, 6884, 6911, 6887, 6863, 6840, 6862, 6873, 6962, 6968,
+6958, 7016, 8920, 8043, 5000, 2945, 3151, 3198, 3258, 3343, 3473, 3614,
+3846, 4058, 4333, 4946, 5252, 3721, 5000, 7621, 7581, 7543, 7505, 7517,
+7480, 7500, 7348, 7378, 7423, 7468, 3418, 5000, 7532, 7493, 7455, 7427,
+7434, 7415, 7297, 7162, 7207, 7252, 3285, 5000, 7480, 7442, 7408, 7381,
+7406, 7249, 7113, 7158, 7204, 3305, 5000, 7412, 7381, 7348, 7338, 7225,
+7051, 7095, 7141, 3324, 5000, 7326, 7295, 7280, 7132, 7001, 7007, 7052,
+3344, 5000, 7191, 7178, 7025, 6860, 6909, 6915, 3318, 5000, 7023, 6872,
+6701, 6716, 6766, 3297, 5000, 6623, 6454, 6469, 6519, 3259, 5000, 6260,
+6272, 6287, 3351, 5000, 5974, 5985, 3464, 5000, 5318, 3404, 5000, 3342,
+5000,
+];
+
+pub const VS_RANDOM: &[u16; 1521] = &[
+8520, 7381, 6398, 5646, 4801, 4397, 3849, 3505, 3087, 6704, 5070, 4088,
+3553, 3094, 2797, 2481, 2293, 2115, 6621, 4927, 3987, 3348, 2908, 2572,
+2291, 2100, 1916, 6539, 4771, 3937, 3153, 2790, 2433, 2203, 1937, 1798,
+6460, 4786, 3732, 3109, 2663, 2349, 2081, 1888, 1720, 6278, 4429, 3463,
+2828, 2428, 2143, 1885, 1673, 1555, 6194, 4368, 3365, 2744, 2382, 2032,
+1766, 1626, 1481, 6098, 4248, 3205, 2621, 2280, 1958, 1746, 1525, 1459,
+5991, 4094, 3123, 2570, 2151, 1891, 1673, 1538, 1395, 5992, 4117, 3184,
+2599, 2253, 1959, 1748, 1562, 1459, 5903, 4037, 3133, 2551, 2158, 1914,
+1732, 1539, 1406, 5822, 3928, 3008, 2409, 2170, 1885, 1660, 1545, 1376,
+5738, 3952, 2967, 2395, 2084, 1815, 1601, 1455, 1381, 6532, 4892, 3836,
+3204, 2805, 2482, 2134, 1896, 1703, 8240, 6900, 5828, 4889, 4226, 3812,
+3227, 2936, 2574, 6340, 4738, 3833, 3251, 2838, 2537, 2267, 1958, 1842,
+6257, 4594, 3681, 3083, 2712, 2393, 2126, 1953, 1738, 6179, 4428, 3558,
+2990, 2619, 2225, 2005, 1889, 1708, 5999, 4232, 3329, 2765, 2343, 2034,
+1791, 1665, 1482, 5831, 4044, 3091, 2527, 2160, 1883, 1655, 1487, 1372,
+5754, 3928, 2980, 2399, 2027, 1791, 1627, 1386, 1325, 5664, 3779, 2899,
+2408, 1991, 1741, 1578, 1413, 1284, 5579, 3712, 2827, 2245, 1940, 1711,
+1540, 1383, 1229, 5488, 3645, 2763, 2251, 1891, 1649, 1466, 1296, 1247,
+5405, 3540, 2692, 2130, 1873, 1611, 1455, 1301, 1223, 5321, 3508, 2602,
+2136, 1854, 1610, 1432, 1261, 1205, 6443, 4728, 3653, 3057, 2606, 2289,
+1990, 1732, 1518, 6146, 4445, 3494, 2907, 2529, 2160, 1916, 1710, 1537,
+7993, 6480, 5344, 4479, 3816, 3222, 2854, 2504, 2232, 6026, 4439, 3566,
+3069, 2595, 2304, 2088, 1909, 1708, 5947, 4296, 3491, 2867, 2510, 2159,
+2014, 1810, 1617, 5766, 4041, 3178, 2666, 2231, 1919, 1774, 1654, 1465,
+5602, 3835, 2994, 2401, 2129, 1788, 1610, 1468, 1353, 5430, 3628, 2760,
+2209, 1900, 1666, 1443, 1348, 1200, 5361, 3566, 2752, 2203, 1845, 1576,
+1405, 1299, 1177, 5277, 3514, 2660, 2144, 1816, 1587, 1441, 1277, 1129,
+5186, 3365, 2530, 2066, 1737, 1516, 1386, 1274, 1102, 5102, 3278, 2544,
+1999, 1739, 1477, 1356, 1210, 1120, 5017, 3235, 2466, 1985, 1598, 1414,
+1317, 1221, 1106, 6356, 4536, 3517, 2907, 2434, 2073, 1835, 1592, 1443,
+6057, 4268, 3352, 2764, 2304, 2026, 1741, 1557, 1434, 5813, 4170, 3226,
+2724, 2273, 1929, 1735, 1586, 1386, 7747, 6150, 4977, 4084, 3310, 2814,
+2484, 2168, 2020, 5753, 4157, 3341, 2853, 2489, 2199, 2022, 1822, 1681,
+5566, 3908, 3119, 2596, 2184, 1965, 1732, 1601, 1403, 5402, 3701, 2988,
+2394, 2003, 1795, 1617, 1470, 1310, 5232, 3520, 2669, 2285, 1916, 1610,
+1471, 1334, 1219, 5061, 3351, 2549, 2062, 1709, 1508, 1350, 1217, 1142,
+4999, 3268, 2468, 1977, 1669, 1489, 1310, 1187, 1061, 4907, 3157, 2451,
+1941, 1671, 1389, 1298, 1160, 1063, 4823, 3150, 2338, 1905, 1628, 1355,
+1255, 1140, 1061, 4738, 3044, 2249, 1826, 1596, 1378, 1222, 1105, 1012,
+6272, 4438, 3438, 2783, 2282, 1957, 1708, 1481, 1340, 5974, 4202, 3287,
+2651, 2193, 1947, 1671, 1442, 1324, 5729, 4014, 3120, 2550, 2177, 1897,
+1641, 1410, 1252, 5525, 3875, 3055, 2567, 2168, 1927, 1610, 1452, 1316,
+7501, 5718, 4530, 3674, 3055, 2542, 2158, 1904, 1693, 5403, 3941, 3093,
+2636, 2261, 1972, 1788, 1639, 1512, 5233, 3739, 2904, 2390, 2037, 1839,
+1590, 1499, 1363, 5064, 3513, 2645, 2252, 1887, 1636, 1512, 1366, 1192,
+4894, 3250, 2523, 2032, 1765, 1538, 1369, 1194, 1157, 4722, 3106, 2299,
+1865, 1645, 1370, 1269, 1082, 1047, 4653, 2989, 2246, 1784, 1555, 1366,
+1197, 1078, 1023, 4569, 2948, 2184, 1814, 1538, 1324, 1201, 1083, 1004,
+4484, 2881, 2168, 1777, 1481, 1360, 1160, 1045, 969, 6077, 4191, 3087,
+2456, 2037, 1714, 1437, 1284, 1079, 5781, 3959, 2973, 2299, 1964, 1638,
+1397, 1223, 1122, 5536, 3756, 2873, 2280, 1869, 1579, 1377, 1235, 1049,
+5325, 3641, 2802, 2263, 1899, 1622, 1407, 1240, 1077, 5153, 3529, 2748,
+2243, 1857, 1611, 1400, 1286, 1125, 7206, 5358, 4115, 3284, 2701, 2233,
+1930, 1703, 1526, 5080, 3517, 2855, 2384, 2043, 1756, 1531, 1451, 1354,
+4912, 3424, 2693, 2190, 1859, 1647, 1504, 1381, 1212, 4743, 3190, 2508,
+2028, 1730, 1464, 1413, 1234, 1161, 4572, 3085, 2292, 1896, 1567, 1392,
+1288, 1093, 1038, 4386, 2826, 2181, 1726, 1411, 1267, 1158, 1047, 940,
+4326, 2759, 2072, 1699, 1445, 1229, 1110, 1051, 905, 4242, 2755, 2020,
+1647, 1388, 1230, 1102, 987, 911, 5987, 3967, 2990, 2336, 1913, 1594,
+1374, 1204, 1085, 5602, 3737, 2706, 2064, 1704, 1429, 1246, 1079, 918,
+5360, 3486, 2656, 2065, 1672, 1416, 1181, 1036, 921, 5149, 3363, 2561,
+2022, 1704, 1427, 1212, 1102, 935, 4972, 3360, 2568, 2096, 1705, 1438,
+1258, 1100, 991, 4810, 3336, 2503, 2000, 1704, 1430, 1233, 1109, 984,
+6916, 4920, 3764, 2965, 2426, 2093, 1751, 1589, 1478, 4794, 3365, 2643,
+2225, 1905, 1722, 1462, 1390, 1290, 4624, 3199, 2493, 2039, 1802, 1549,
+1387, 1284, 1218, 4454, 3033, 2348, 1947, 1647, 1416, 1289, 1185, 1103,
+4270, 2817, 2197, 1721, 1495, 1268, 1148, 1086, 1021, 4087, 2688, 1987,
+1606, 1398, 1170, 1063, 965, 918, 4027, 2515, 1959, 1575, 1378, 1175,
+1086, 964, 889, 5884, 3939, 2885, 2243, 1856, 1540, 1320, 1132, 999,
+5519, 3602, 2658, 1999, 1708, 1361, 1184, 1045, 899, 5177, 3342, 2354,
+1835, 1512, 1255, 1075, 914, 775, 4968, 3191, 2332, 1855, 1446, 1266,
+1077, 929, 812, 4791, 3144, 2368, 1870, 1516, 1268, 1103, 959, 868,
+4630, 3089, 2277, 1844, 1497, 1275, 1133, 969, 892, 4505, 2997, 2261,
+1896, 1486, 1282, 1138, 974, 935, 6624, 4678, 3483, 2675, 2212, 1862,
+1663, 1493, 1408, 4537, 3177, 2446, 2071, 1777, 1599, 1434, 1326, 1240,
+4368, 2952, 2346, 1959, 1675, 1474, 1355, 1252, 1145, 4185, 2804, 2197,
+1769, 1556, 1362, 1226, 1148, 1059, 4004, 2654, 1965, 1620, 1389, 1283,
+1116, 1039, 935, 3816, 2455, 1846, 1488, 1311, 1156, 1092, 953, 819,
+5768, 3858, 2756, 2125, 1744, 1465, 1263, 1051, 952, 5422, 3496, 2519,
+1953, 1612, 1299, 1144, 997, 848, 5102, 3180, 2341, 1789, 1455, 1177,
+992, 904, 780, 4784, 3008, 2138, 1621, 1339, 1083, 910, 817, 692,
+4609, 2907, 2131, 1630, 1313, 1105, 956, 811, 769, 4449, 2845, 2146,
+1658, 1352, 1117, 996, 870, 797, 4324, 2870, 2133, 1662, 1391, 1186,
+1005, 904, 866, 4232, 2833, 2111, 1722, 1440, 1184, 1085, 961, 889,
+6328, 4381, 3134, 2411, 1993, 1700, 1551, 1382, 1369, 4313, 3004, 2350,
+1939, 1685, 1485, 1376, 1262, 1191, 4133, 2851, 2182, 1808, 1592, 1437,
+1325, 1203, 1125, 3953, 2653, 2044, 1700, 1495, 1256, 1163, 1058, 982,
+3767, 2504, 1891, 1534, 1320, 1182, 1053, 963, 917, 5770, 3837, 2776,
+2176, 1812, 1533, 1361, 1146, 1027, 5331, 3397, 2425, 1912, 1543, 1277,
+1050, 952, 827, 5012, 3118, 2246, 1733, 1361, 1142, 961, 820, 728,
+4718, 2964, 2056, 1632, 1301, 1007, 886, 778, 705, 4425, 2723, 1971,
+1478, 1191, 984, 880, 733, 634, 4267, 2641, 1916, 1481, 1213, 999,
+852, 740, 636, 4143, 2636, 1944, 1522, 1220, 1081, 928, 775, 755,
+4051, 2601, 1976, 1564, 1319, 1123, 935, 872, 798, 3994, 2653, 1971,
+1612, 1353, 1171, 1036, 905, 837, 6032, 4014, 2851, 2264, 1853, 1648,
+1431, 1351, 1243, 4145, 2860, 2265, 1828, 1609, 1466, 1355, 1238, 1181,
+3969, 2792, 2112, 1753, 1534, 1360, 1211, 1167, 1043, 3785, 2530, 1971,
+1646, 1415, 1298, 1131, 1039, 1011, 5673, 3759, 2734, 2089, 1776, 1471,
+1248, 1113, 975, 5233, 3294, 2328, 1776, 1483, 1233, 1050, 929, 783,
+4913, 3011, 2175, 1655, 1405, 1102, 956, 787, 706, 4619, 2813, 2042,
+1537, 1197, 982, 880, 732, 696, 4350, 2682, 1875, 1424, 1128, 966,
+822, 688, 612, 4067, 2441, 1716, 1264, 1021, 860, 731, 639, 537,
+3945, 2397, 1736, 1355, 1099, 939, 769, 656, 623, 3855, 2437, 1810,
+1427, 1150, 974, 841, 738, 713, 3801, 2497, 1862, 1491, 1186, 1063,
+860, 819, 773, 3816, 2578, 1895, 1500, 1268, 1104, 998, 890, 830,
+5702, 3666, 2627, 2066, 1759, 1538, 1375, 1248, 1206, 3864, 2620, 2043,
+1677, 1478, 1309, 1162, 1128, 1030, 3683, 2433, 1909, 1611, 1358, 1190,
+1108, 1060, 957, 5584, 3618, 2649, 2036, 1683, 1437, 1237, 1072, 999,
+5143, 3220, 2266, 1790, 1424, 1159, 1002, 873, 801, 4822, 2980, 2028,
+1587, 1308, 1067, 907, 781, 682, 4528, 2694, 1955, 1447, 1192, 973,
+806, 734, 626, 4259, 2580, 1740, 1370, 1089, 928, 783, 669, 591,
+4002, 2411, 1628, 1279, 983, 816, 700, 611, 538, 3748, 2251, 1539,
+1185, 939, 795, 683, 590, 507, 3660, 2296, 1618, 1235, 985, 860,
+757, 642, 560, 3608, 2296, 1640, 1309, 1035, 901, 817, 731, 660,
+3626, 2343, 1735, 1370, 1187, 954, 908, 776, 734, 3515, 2212, 1623,
+1273, 1089, 901, 834, 750, 699, 5369, 3375, 2435, 1934, 1613, 1446,
+1330, 1257, 1201, 3598, 2372, 1858, 1521, 1283, 1137, 1075, 975, 931,
+5493, 3488, 2550, 1983, 1650, 1372, 1168, 1073, 970, 5051, 3121, 2210,
+1713, 1376, 1141, 985, 872, 789, 4730, 2886, 2054, 1580, 1203, 1087,
+881, 778, 681, 4435, 2639, 1859, 1387, 1126, 990, 808, 709, 614,
+4167, 2449, 1742, 1359, 1057, 883, 742, 673, 620, 3910, 2325, 1629,
+1237, 999, 823, 677, 580, 527, 3683, 2150, 1509, 1155, 904, 771,
+624, 552, 505, 3458, 2041, 1433, 1062, 866, 719, 649, 530, 486,
+3408, 2068, 1468, 1163, 891, 784, 676, 596, 536, 3428, 2118, 1574,
+1184, 1008, 853, 768, 716, 645, 3320, 2038, 1520, 1125, 911, 824,
+739, 664, 591, 3230, 2035, 1382, 1045, 915, 775, 656, 579, 538,
+5033, 3083, 2193, 1738, 1550, 1423, 1324, 1247, 1182,
+];
@@ -0,0 +1,232 @@
+use crate::grid::Grid;
+use crate::preflop::heads_up_equity;
+use crate::starting_hand::StartingHand;
+
+/// Rounds of fictitious play. Each round both players best-respond to the
+/// other's average strategy so far, which converges on the equilibrium.
+const ITERATIONS: usize = 1000;
+
+const SMALL_BLIND: f64 = 0.5;
+const BIG_BLIND: f64 = 1.0;
+
+/// Heads-up push/fold equilibrium at one effective stack: the small blind
+/// shoves or folds, and the big blind calls or folds against a shove.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushFold {
+    /// Effective stack in big blinds
+    pub stack: f64,
+    /// How often the small blind shoves each hand, indexed by
+    /// `StartingHand::index`
+    pub shove: Vec<f64>,
+    /// How often the big blind calls a shove with each hand
+    pub call: Vec<f64>,
+}
+
+impl PushFold {
+    /// Solves the spot for an effective stack of `stack` big blinds, using the
+    /// precomputed preflop equities and card removal between the two hands.
+    pub fn solve(stack: f64) -> Self {
+        assert!(
+            stack >= BIG_BLIND,
+            "The effective stack has to cover the big blind"
+        );
+        let matchups = Matchups::new();
+
+        let mut shove = vec![1.0; StartingHand::COUNT];
+        let mut call = vec![1.0; StartingHand::COUNT];
+        for round in 1..=ITERATIONS {
+            let step = 1.0 / (round + 1) as f64;
+
+            for (hand, frequency) in call.iter_mut().enumerate() {
+                let calls = matchups.call_value(hand, &shove, stack) > -BIG_BLIND;
+                *frequency += step * (if calls { 1.0 } else { 0.0 } - *frequency);
+            }
+            for (hand, frequency) in shove.iter_mut().enumerate() {
+                let shoves = matchups.shove_value(hand, &call, stack) > -SMALL_BLIND;
+                *frequency += step * (if shoves { 1.0 } else { 0.0 } - *frequency);
+            }
+        }
+
+        PushFold { stack, shove, call }
+    }
+
+    /// Whether the small blind shoves `hand` most of the time
+    pub fn shoves(&self, hand: StartingHand) -> bool {
+        self.shove[hand.index()] >= 0.5
+    }
+
+    /// Whether the big blind calls a shove with `hand` most of the time
+    pub fn calls(&self, hand: StartingHand) -> bool {
+        self.call[hand.index()] >= 0.5
+    }
+
+    /// Share of all combos the small blind shoves
+    pub fn shove_share(&self) -> f64 {
+        share(&self.shove)
+    }
+
+    /// Share of all combos the big blind calls with
+    pub fn call_share(&self) -> f64 {
+        share(&self.call)
+    }
+
+    /// Shove frequencies as a grid, e.g. for `Grid::to_ansi`
+    pub fn shove_grid(&self) -> Grid {
+        Grid::new(self.shove.clone())
+    }
+
+    /// Call frequencies as a grid
+    pub fn call_grid(&self) -> Grid {
+        Grid::new(self.call.clone())
+    }
+}
+
+/// Prints both ranges as 13x13 charts, with each hand in the range written
+/// out and the rest left as dots.
+impl core::fmt::Display for PushFold {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let in_range = |played: bool| if played { 1.0 } else { 0.0 };
+
+        writeln!(
+            f,
+            "Small blind shoves {:.1}% at {}bb",
+            self.shove_share() * 100.0,
+            self.stack
+        )?;
+        write!(f, "{}", Grid::from_fn(|hand| in_range(self.shoves(hand))))?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Big blind calls {:.1}% at {}bb",
+            self.call_share() * 100.0,
+            self.stack
+        )?;
+        write!(f, "{}", Grid::from_fn(|hand| in_range(self.calls(hand))))
+    }
+}
+
+fn share(frequencies: &[f64]) -> f64 {
+    StartingHand::all()
+        .map(|hand| frequencies[hand.index()] * hand.combo_count() as f64)
+        .sum::<f64>()
+        / 1326.0
+}
+
+/// Equity and combo count of every class against every other, from the
+/// point of view of the first
+struct Matchups {
+    equity: Vec<f64>,
+    /// Combos of the second class left once one combo of the first is dealt
+    combos: Vec<f64>,
+}
+
+impl Matchups {
+    fn new() -> Self {
+        let mut equity = Vec::with_capacity(StartingHand::COUNT * StartingHand::COUNT);
+        let mut combos = Vec::with_capacity(equity.capacity());
+
+        for hero in StartingHand::all() {
+            // Every combo of a class blocks the others the same way, so the
+            // first one stands in for all of them
+            let dealt = hero.combos()[0];
+            for villain in StartingHand::all() {
+                equity.push(heads_up_equity(hero, villain));
+                let live = villain
+                    .combos()
+                    .iter()
+                    .filter(|cards| !cards.iter().any(|card| dealt.contains(card)))
+                    .count();
+                combos.push(live as f64);
+            }
+        }
+
+        Matchups { equity, combos }
+    }
+
+    fn at(&self, hero: usize, villain: usize) -> (f64, f64) {
+        let i = hero * StartingHand::COUNT + villain;
+        (self.equity[i], self.combos[i])
+    }
+
+    /// Small blind's expected result from shoving `hand`, in big blinds won
+    fn shove_value(&self, hand: usize, call: &[f64], stack: f64) -> f64 {
+        let (mut value, mut total) = (0.0, 0.0);
+        for (villain, calls) in call.iter().enumerate() {
+            let (equity, combos) = self.at(hand, villain);
+            let called = stack * (2.0 * equity - 1.0);
+            value += combos * (calls * called + (1.0 - calls) * BIG_BLIND);
+            total += combos;
+        }
+        value / total
+    }
+
+    /// Big blind's expected result from calling a shove with `hand`
+    fn call_value(&self, hand: usize, shove: &[f64], stack: f64) -> f64 {
+        let (mut value, mut total) = (0.0, 0.0);
+        for (villain, shoves) in shove.iter().enumerate() {
+            let (equity, combos) = self.at(hand, villain);
+            value += combos * shoves * stack * (2.0 * equity - 1.0);
+            total += combos * shoves;
+        }
+        if total > 0.0 {
+            value / total
+        } else {
+            // Nothing to call, so calling costs nothing either
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(notation: &str) -> StartingHand {
+        notation.parse().unwrap()
+    }
+
+    #[test]
+    fn solves_a_short_stacked_sit_and_go_spot() {
+        let solution = PushFold::solve(10.0);
+
+        for premium in &["AA", "KK", "AKo", "A2o", "K9o", "76s"] {
+            assert!(solution.shoves(hand(premium)), "{} should shove", premium);
+        }
+        assert!(!solution.shoves(hand("72o")));
+        for caller in &["AA", "A9o", "KJo", "55"] {
+            assert!(solution.calls(hand(caller)), "{} should call", caller);
+        }
+        assert!(!solution.calls(hand("T7o")));
+
+        // Published charts shove a little under 60% and call a little under
+        // 40% at 10bb
+        assert!(solution.shove_share() > 0.5 && solution.shove_share() < 0.65);
+        assert!(solution.call_share() > 0.3 && solution.call_share() < 0.45);
+        assert!(solution.call_share() < solution.shove_share());
+    }
+
+    #[test]
+    fn ranges_widen_as_stacks_get_shorter() {
+        let deep = PushFold::solve(20.0);
+        let short = PushFold::solve(4.0);
+
+        assert!(short.shove_share() > deep.shove_share());
+        assert!(short.call_share() > deep.call_share());
+        for hand in StartingHand::all() {
+            if deep.shoves(hand) {
+                assert!(short.shoves(hand), "{} should still shove at 4bb", hand);
+            }
+        }
+    }
+
+    #[test]
+    fn prints_both_charts() {
+        let chart = PushFold::solve(8.0).to_string();
+        let lines: Vec<&str> = chart.lines().collect();
+
+        assert_eq!(lines.len(), 2 * 14 + 1);
+        assert!(lines[0].starts_with("Small blind shoves"));
+        assert!(lines[1].starts_with("AA   AKs  AQs"));
+        assert!(lines[15].starts_with("Big blind calls"));
+    }
+}
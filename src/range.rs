@@ -0,0 +1,553 @@
+use crate::card::{Card, Rank};
+use crate::card_set::CardSet;
+use crate::starting_hand::{Shape, StartingHand};
+
+/// A specific pair of hole cards, such as `AhKh`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Combo([Card; 2]);
+
+impl Combo {
+    /// Number of distinct two card combos in a deck
+    pub const COUNT: usize = 1326;
+
+    pub fn new(first: Card, second: Card) -> Self {
+        assert_ne!(first, second, "A combo needs two different cards");
+
+        if first.index() > second.index() {
+            Combo([first, second])
+        } else {
+            Combo([second, first])
+        }
+    }
+
+    /// The two cards, higher `Card::index` first
+    pub fn cards(&self) -> [Card; 2] {
+        self.0
+    }
+
+    pub fn card_set(&self) -> CardSet {
+        CardSet::from(&self.0[..])
+    }
+
+    pub fn starting_hand(&self) -> StartingHand {
+        StartingHand::of(self.0)
+    }
+
+    /// Dense position of the combo in `0..Combo::COUNT`
+    pub fn index(&self) -> usize {
+        let high = self.0[0].index();
+        let low = self.0[1].index();
+        high * (high - 1) / 2 + low
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        assert!(index < Self::COUNT, "Combo index out of range");
+
+        let mut high = 1;
+        while (high + 1) * high / 2 <= index {
+            high += 1;
+        }
+        let low = index - high * (high - 1) / 2;
+
+        Combo([Card::from_index(high), Card::from_index(low)])
+    }
+}
+
+impl From<[Card; 2]> for Combo {
+    fn from(cards: [Card; 2]) -> Self {
+        Combo::new(cards[0], cards[1])
+    }
+}
+
+impl core::fmt::Display for Combo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}{}", self.0[0], self.0[1])
+    }
+}
+
+/// A weighted set of hole card combos.
+///
+/// Every combo carries a weight between 0 and 1, the fraction of the time the
+/// combo is played, and combos with a weight of 0 are not part of the range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    weights: Vec<f64>,
+}
+
+impl Range {
+    pub fn new() -> Self {
+        Range {
+            weights: vec![0.0; Combo::COUNT],
+        }
+    }
+
+    /// Every possible hand, each at full weight
+    pub fn full() -> Self {
+        Range {
+            weights: vec![1.0; Combo::COUNT],
+        }
+    }
+
+    pub fn weight(&self, combo: Combo) -> f64 {
+        self.weights[combo.index()]
+    }
+
+    pub fn set_weight(&mut self, combo: Combo, weight: f64) {
+        self.weights[combo.index()] = weight;
+    }
+
+    /// Sets the weight of every combo of a starting hand
+    pub fn add_hand(&mut self, hand: StartingHand, weight: f64) {
+        for cards in hand.combos() {
+            self.set_weight(cards.into(), weight);
+        }
+    }
+
+    /// The combos in the range along with their weights
+    pub fn combos(&self) -> impl Iterator<Item = (Combo, f64)> + '_ {
+        self.weights
+            .iter()
+            .enumerate()
+            .filter(|(_, weight)| **weight > 0.0)
+            .map(|(index, weight)| (Combo::from_index(index), *weight))
+    }
+
+    /// Number of combos with a non-zero weight
+    pub fn len(&self) -> usize {
+        self.weights.iter().filter(|weight| **weight > 0.0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of the weights, i.e. the number of combos the range is worth
+    pub fn total_weight(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Removes every combo that shares a card with `known`, such as our own
+    /// hole cards or the board.
+    pub fn remove_blocked(&mut self, known: CardSet) {
+        for (index, weight) in self.weights.iter_mut().enumerate() {
+            if !Combo::from_index(index).card_set().is_disjoint(known) {
+                *weight = 0.0;
+            }
+        }
+    }
+
+    /// Copy of the range with the combos blocked by `known` removed
+    pub fn without(&self, known: CardSet) -> Range {
+        let mut range = self.clone();
+        range.remove_blocked(known);
+        range
+    }
+
+    /// Writes the range in the shortest standard notation: whole starting
+    /// hands collapse into `TT+`, `A5s-A2s` or `AK` style runs, and hands
+    /// that are only partly in the range are listed combo by combo.
+    pub fn to_notation(&self) -> String {
+        let mut tokens = Vec::new();
+        let mut partial = Vec::new();
+
+        let class_weights: Vec<Option<f64>> = StartingHand::all()
+            .map(|hand| {
+                let weights: Vec<f64> = hand
+                    .combos()
+                    .into_iter()
+                    .map(|cards| self.weight(cards.into()))
+                    .collect();
+
+                if weights.iter().all(|w| *w == weights[0]) {
+                    Some(weights[0])
+                } else {
+                    partial.extend(
+                        hand.combos()
+                            .into_iter()
+                            .map(Combo::from)
+                            .filter(|combo| self.weight(*combo) > 0.0),
+                    );
+                    None
+                }
+            })
+            .collect();
+        let weight_of = |first: Rank, second: Rank, shape: Shape| {
+            let hand = StartingHand::new(first, second, shape).expect("Valid starting hand");
+            class_weights[hand.index()].filter(|weight| *weight > 0.0)
+        };
+
+        let pairs: Vec<Option<f64>> = Rank::ALL
+            .iter()
+            .map(|&rank| weight_of(rank, rank, Shape::Pair))
+            .collect();
+        for (start, end, weight) in runs(&pairs) {
+            let token = if start == end {
+                format!("{0}{0}", Rank::ALL[start].to_char())
+            } else if start == 0 {
+                format!("{0}{0}+", Rank::ALL[end].to_char())
+            } else {
+                format!(
+                    "{0}{0}-{1}{1}",
+                    Rank::ALL[start].to_char(),
+                    Rank::ALL[end].to_char()
+                )
+            };
+            tokens.push(with_weight(token, weight));
+        }
+
+        for (high_position, &high) in Rank::ALL.iter().enumerate() {
+            let kickers = &Rank::ALL[high_position + 1..];
+            let suited: Vec<Option<f64>> = kickers
+                .iter()
+                .map(|&low| weight_of(high, low, Shape::Suited))
+                .collect();
+            let offsuit: Vec<Option<f64>> = kickers
+                .iter()
+                .map(|&low| weight_of(high, low, Shape::Offsuit))
+                .collect();
+
+            let shapes: &[(&[Option<f64>], &str)] = if suited == offsuit {
+                &[(&suited, "")]
+            } else {
+                &[(&suited, "s"), (&offsuit, "o")]
+            };
+
+            for (weights, suffix) in shapes {
+                for (start, end, weight) in runs(weights) {
+                    let high = high.to_char();
+                    let token = if start == end {
+                        format!("{}{}{}", high, kickers[start].to_char(), suffix)
+                    } else if start == 0 {
+                        format!("{}{}{}+", high, kickers[end].to_char(), suffix)
+                    } else {
+                        format!(
+                            "{0}{1}{3}-{0}{2}{3}",
+                            high,
+                            kickers[start].to_char(),
+                            kickers[end].to_char(),
+                            suffix
+                        )
+                    };
+                    tokens.push(with_weight(token, weight));
+                }
+            }
+        }
+
+        partial.sort_by_key(|combo| core::cmp::Reverse(combo.index()));
+        for combo in partial {
+            tokens.push(with_weight(combo.to_string(), self.weight(combo)));
+        }
+
+        tokens.join(", ")
+    }
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Range::new()
+    }
+}
+
+impl core::fmt::Display for Range {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.to_notation())
+    }
+}
+
+impl core::str::FromStr for Range {
+    type Err = ParseRangeError;
+
+    /// Parses comma separated range notation such as
+    /// `TT+, AKs, A2s-A5s, KQo, 72o:0.5, AhKh`. A `:weight` suffix sets the
+    /// weight of the hands in that part, and later parts override earlier
+    /// ones.
+    fn from_str(s: &str) -> Result<Range, ParseRangeError> {
+        let mut range = Range::new();
+
+        for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (token, weight) = match part.find(':') {
+                Some(at) => {
+                    let weight = part[at + 1..]
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|weight| (0.0..=1.0).contains(weight))
+                        .ok_or_else(|| ParseRangeError::InvalidWeight(part.to_string()))?;
+                    (part[..at].trim(), weight)
+                }
+                None => (part, 1.0),
+            };
+
+            for combo in
+                expand(token).ok_or_else(|| ParseRangeError::InvalidToken(part.to_string()))?
+            {
+                range.set_weight(combo, weight);
+            }
+        }
+
+        Ok(range)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeError {
+    /// The hand part of the token could not be understood
+    InvalidToken(String),
+    /// The `:weight` suffix was not a number between 0 and 1
+    InvalidWeight(String),
+}
+
+impl core::fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            ParseRangeError::InvalidToken(token) => write!(f, "invalid range token `{}`", token),
+            ParseRangeError::InvalidWeight(token) => {
+                write!(f, "weight in `{}` must be between 0 and 1", token)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseRangeError {}
+
+/// Hand pattern such as `AK`, `T9s` or `77`, before any `+` or `-` range.
+struct Pattern {
+    high: Rank,
+    low: Rank,
+    suffix: Option<Shape>,
+}
+
+impl Pattern {
+    fn parse(s: &str) -> Option<Pattern> {
+        let chars: Vec<char> = s.chars().collect();
+        let suffix = match chars.len() {
+            2 => None,
+            3 if chars[2] == 's' => Some(Shape::Suited),
+            3 if chars[2] == 'o' => Some(Shape::Offsuit),
+            _ => return None,
+        };
+        let first = Rank::from_char(chars[0])?;
+        let second = Rank::from_char(chars[1])?;
+
+        if first == second && suffix.is_some() {
+            return None;
+        }
+        let (high, low) = if first.order_encoding() >= second.order_encoding() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        Some(Pattern { high, low, suffix })
+    }
+
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// The starting hands of this pattern with the kicker (or pair) `rank`
+    fn hands(&self, rank: Rank) -> Vec<StartingHand> {
+        if self.is_pair() {
+            return StartingHand::new(rank, rank, Shape::Pair)
+                .into_iter()
+                .collect();
+        }
+
+        let shapes = match self.suffix {
+            Some(shape) => vec![shape],
+            None => vec![Shape::Suited, Shape::Offsuit],
+        };
+        shapes
+            .into_iter()
+            .filter_map(|shape| StartingHand::new(self.high, rank, shape))
+            .collect()
+    }
+}
+
+/// Expands a single token of range notation into the combos it stands for
+fn expand(token: &str) -> Option<Vec<Combo>> {
+    if let (Some(first), Some(second)) = (token.get(..2), token.get(2..)) {
+        if let (Ok(first), Ok(second)) = (first.parse::<Card>(), second.parse::<Card>()) {
+            return if first == second {
+                None
+            } else {
+                Some(vec![Combo::new(first, second)])
+            };
+        }
+    }
+
+    let (pattern, from, to) = if let Some(base) = token.strip_suffix('+') {
+        let pattern = Pattern::parse(base)?;
+        let top = if pattern.is_pair() {
+            Rank::Ace.order_encoding()
+        } else {
+            pattern.high.order_encoding() - 1
+        };
+        let from = pattern.low.order_encoding();
+        (pattern, from, top)
+    } else if let Some(dash) = token.find('-') {
+        let pattern = Pattern::parse(&token[..dash])?;
+        let other = Pattern::parse(&token[dash + 1..])?;
+
+        let same_shape = pattern.suffix == other.suffix && pattern.is_pair() == other.is_pair();
+        if !same_shape || (!pattern.is_pair() && pattern.high != other.high) {
+            return None;
+        }
+
+        let a = pattern.low.order_encoding();
+        let b = other.low.order_encoding();
+        (pattern, a.min(b), a.max(b))
+    } else {
+        let pattern = Pattern::parse(token)?;
+        let order = pattern.low.order_encoding();
+        (pattern, order, order)
+    };
+
+    let mut combos = Vec::new();
+    for order in from..=to {
+        for hand in pattern.hands(Rank::from_order(order)) {
+            combos.extend(hand.combos().into_iter().map(Combo::from));
+        }
+    }
+    Some(combos)
+}
+
+/// Splits a run of per-rank weights into maximal stretches of equal weight,
+/// returned as `(first index, last index, weight)`.
+fn runs(weights: &[Option<f64>]) -> Vec<(usize, usize, f64)> {
+    let mut runs: Vec<(usize, usize, f64)> = Vec::new();
+
+    for (index, weight) in weights.iter().enumerate() {
+        let weight = match weight {
+            Some(weight) => *weight,
+            None => continue,
+        };
+        match runs.last_mut() {
+            Some(run) if run.1 + 1 == index && run.2 == weight => run.1 = index,
+            _ => runs.push((index, index, weight)),
+        }
+    }
+    runs
+}
+
+fn with_weight(token: String, weight: f64) -> String {
+    if weight == 1.0 {
+        token
+    } else {
+        format!("{}:{}", token, weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::Suit;
+
+    fn range(notation: &str) -> Range {
+        notation.parse().unwrap()
+    }
+
+    #[test]
+    fn indexes_every_combo() {
+        for index in 0..Combo::COUNT {
+            assert_eq!(Combo::from_index(index).index(), index);
+        }
+
+        let ace = Card::new(Rank::Ace, Suit::Hearts);
+        let king = Card::new(Rank::King, Suit::Hearts);
+        assert_eq!(Combo::new(king, ace), Combo::new(ace, king));
+        assert_eq!(Combo::new(king, ace).to_string(), "AhKh");
+    }
+
+    #[test]
+    fn expands_standard_notation() {
+        let range = range("TT+, AKs, A2s-A5s, KQo, 72o:0.5");
+
+        assert_eq!(range.len(), 30 + 4 + 16 + 12 + 12);
+        assert_eq!(range.total_weight(), 30.0 + 4.0 + 16.0 + 12.0 + 6.0);
+
+        let seven_deuce = Combo::new(
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        );
+        assert_eq!(range.weight(seven_deuce), 0.5);
+    }
+
+    #[test]
+    fn expands_plus_and_dash_ranges() {
+        assert_eq!(range("ATs+"), range("ATs, AJs, AQs, AKs"));
+        assert_eq!(range("77-99"), range("99-77"));
+        assert_eq!(range("77-99"), range("77, 88, 99"));
+        assert_eq!(range("K9+"), range("K9s+, K9o+"));
+        assert_eq!(range("22+").len(), 78);
+        assert_eq!(range("AhKh, AsKs").len(), 2);
+        assert_eq!(range("").len(), 0);
+    }
+
+    #[test]
+    fn later_parts_override_earlier_weights() {
+        let range = range("AK, AKo:0.25");
+
+        for (combo, weight) in range.combos() {
+            match combo.starting_hand().shape() {
+                Shape::Suited => assert_eq!(weight, 1.0),
+                _ => assert_eq!(weight, 0.25),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bad_notation() {
+        assert_eq!(
+            "AKx".parse::<Range>(),
+            Err(ParseRangeError::InvalidToken("AKx".to_string()))
+        );
+        assert_eq!(
+            "AKs:2".parse::<Range>(),
+            Err(ParseRangeError::InvalidWeight("AKs:2".to_string()))
+        );
+        assert!("AKs-QJs".parse::<Range>().is_err());
+        assert!("AA-KQs".parse::<Range>().is_err());
+        assert!("AhAh".parse::<Range>().is_err());
+        assert!("AAs".parse::<Range>().is_err());
+    }
+
+    #[test]
+    fn removes_blocked_combos() {
+        let mut range = range("AA, AKs");
+        let known = CardSet::from(&[Card::new(Rank::Ace, Suit::Spades)][..]);
+
+        assert_eq!(range.without(known).len(), 3 + 3);
+        range.remove_blocked(known);
+        assert_eq!(range.len(), 3 + 3);
+    }
+
+    #[test]
+    fn writes_the_shortest_notation() {
+        let notation = |s: &str| range(s).to_notation();
+
+        assert_eq!(
+            notation("TT+, AKs, A2s-A5s, KQo, 72o:0.5"),
+            "TT+, AKs, A5s-A2s, KQo, 72o:0.5"
+        );
+        assert_eq!(notation("AKs, AKo"), "AK");
+        assert_eq!(notation("22+"), "22+");
+        assert_eq!(notation("KK-QQ, 55"), "KK-QQ, 55");
+        assert_eq!(notation("KTs+, K9s:0.5"), "KTs+, K9s:0.5");
+        assert_eq!(notation("AhKh"), "AhKh");
+        assert_eq!(notation(""), "");
+    }
+
+    #[test]
+    fn notation_round_trips() {
+        for notation in &[
+            "TT+, AKs, A2s-A5s, KQo, 72o:0.5",
+            "22+, A2+, K2s+, Q9o+, T8s:0.75, 65s, AhKh:0.5, QcJd",
+            "QQ-99, AQs-ATs:0.3, KQ",
+        ] {
+            let parsed = range(notation);
+            assert_eq!(range(&parsed.to_notation()), parsed);
+        }
+    }
+}
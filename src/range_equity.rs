@@ -0,0 +1,417 @@
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::combinatorics::choose;
+use crate::equity::{self, EquityError};
+use crate::hand_rank::HandRank;
+use crate::monte_carlo::{Batches, Budget, Simulation};
+use crate::range::{Combo, Range};
+use crate::rng::Rng;
+use crate::strength::Strength;
+
+/// Enumerate every runout when that costs at most this many hand evaluations,
+/// otherwise fall back to sampling runouts.
+const EXACT_EVALUATIONS: u64 = 2_000_000;
+
+/// Number of sampled runouts between checks of the simulation budget. Each
+/// runout scores whole ranges, so batches are smaller than for single hands.
+const BATCH: u64 = 100;
+
+/// Number of equal width buckets in `RangeEquity::histogram`
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ComboEquity {
+    pub combo: Combo,
+    /// Weight of the combo in the hero's range
+    pub weight: f64,
+    /// Equity of the combo against the whole villain range
+    pub equity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeEquity {
+    /// Equity of the hero's range as a whole. The villain's is `1 - equity`.
+    pub equity: f64,
+    /// Every combo of the hero's range that can face at least one villain
+    /// combo, in `Combo::index` order
+    pub combos: Vec<ComboEquity>,
+    /// Share of the hero's range, by weight, whose equity falls into each
+    /// tenth from `[0%, 10%)` up to `[90%, 100%]`
+    pub histogram: [f64; HISTOGRAM_BUCKETS],
+    /// Number of runouts that were dealt
+    pub runouts: u64,
+    /// Whether every runout was enumerated rather than sampled
+    pub exact: bool,
+}
+
+impl RangeEquity {
+    /// Share of the hero's range, by weight, with at least `equity` against
+    /// the villain range
+    pub fn fraction_above(&self, equity: f64) -> f64 {
+        let total: f64 = self.combos.iter().map(|c| c.weight).sum();
+        let above: f64 = self
+            .combos
+            .iter()
+            .filter(|c| c.equity >= equity)
+            .map(|c| c.weight)
+            .sum();
+        above / total
+    }
+}
+
+/// Computes the equity of the `hero` range against the `villain` range.
+///
+/// Every runout is enumerated when that is cheap enough, e.g. on the turn and
+/// river or with narrow ranges on the flop. Otherwise runouts are sampled from
+/// `rng` for as long as `simulation` allows. Combos that share a card with the
+/// board, the dead cards or the runout are left out, and so are pairs of hero
+/// and villain combos that share a card.
+pub fn range_vs_range<R: Rng>(
+    hero: &Range,
+    villain: &Range,
+    board: &[Card],
+    dead: &[Card],
+    simulation: &Simulation,
+    rng: &mut R,
+) -> Result<RangeEquity, EquityError> {
+    let deck = equity::validate_board(&[], board, dead)?;
+    if simulation.budget == Budget::Trials(0) {
+        return Err(EquityError::NoTrials);
+    }
+
+    let known = CardSet::from(board).union(CardSet::from(dead));
+    let hero: Vec<(Combo, f64)> = hero.without(known).combos().collect();
+    let villain = villain.without(known);
+    if hero.is_empty() || villain.is_empty() {
+        return Err(EquityError::EmptyRange);
+    }
+
+    let mut tally = RangeTally::new(&hero, &villain, board);
+    let to_deal = 5 - board.len();
+
+    let runouts = choose(deck.len() as u64, to_deal as u64);
+    let evaluations = runouts * (hero.len() + villain.len()) as u64;
+    let exact = evaluations <= EXACT_EVALUATIONS;
+
+    if exact {
+        for runout in deck.combinations(to_deal) {
+            tally.deal(runout);
+        }
+    } else {
+        let batches = Batches::new(simulation, BATCH);
+        loop {
+            let batch = batches.next_batch(tally.runouts, tally.std_error());
+            if batch == 0 {
+                break;
+            }
+
+            for _ in 0..batch {
+                let mut remaining = deck;
+                let runout = (0..to_deal)
+                    .map(|_| {
+                        remaining
+                            .draw(rng)
+                            .expect("Deck was checked to be large enough")
+                    })
+                    .collect();
+                tally.deal(runout);
+            }
+        }
+    }
+
+    tally.finish(exact)
+}
+
+/// Accumulates how every hero combo fares against the villain range.
+struct RangeTally<'a> {
+    hero: &'a [(Combo, f64)],
+    villain: &'a Range,
+    board: &'a [Card],
+    /// Weighted share of the pot won by each hero combo
+    shares: Vec<f64>,
+    /// Weight of the villain combos each hero combo has faced
+    faced: Vec<f64>,
+    /// Per runout equity of the whole hero range, for the standard error
+    runout_equity: (f64, f64),
+    runouts: u64,
+    /// Strength of every villain combo on the current runout, by combo index
+    villain_strengths: Vec<Option<Strength>>,
+}
+
+impl<'a> RangeTally<'a> {
+    fn new(hero: &'a [(Combo, f64)], villain: &'a Range, board: &'a [Card]) -> Self {
+        RangeTally {
+            hero,
+            villain,
+            board,
+            shares: vec![0.0; hero.len()],
+            faced: vec![0.0; hero.len()],
+            runout_equity: (0.0, 0.0),
+            runouts: 0,
+            villain_strengths: vec![None; Combo::COUNT],
+        }
+    }
+
+    fn strength(&self, combo: Combo, runout: CardSet) -> Strength {
+        let mut cards = [combo.cards()[0]; 7];
+        cards[..2].copy_from_slice(&combo.cards());
+        cards[2..2 + self.board.len()].copy_from_slice(self.board);
+        for (slot, card) in cards[2 + self.board.len()..].iter_mut().zip(runout) {
+            *slot = card;
+        }
+        HandRank::best_of(&cards).strength()
+    }
+
+    /// Scores every hero combo against every villain combo on one runout.
+    ///
+    /// Villain combos are sorted by strength so that a hero combo's wins and
+    /// ties come from prefix sums, then the handful of villain combos sharing
+    /// a card with the hero combo get taken back out.
+    fn deal(&mut self, runout: CardSet) {
+        let mut villains: Vec<(Strength, f64)> = Vec::new();
+        for strength in self.villain_strengths.iter_mut() {
+            *strength = None;
+        }
+        for (combo, weight) in self.villain.combos() {
+            if combo.card_set().is_disjoint(runout) {
+                let strength = self.strength(combo, runout);
+                self.villain_strengths[combo.index()] = Some(strength);
+                villains.push((strength, weight));
+            }
+        }
+        villains.sort_by_key(|villain| villain.0);
+
+        let mut below = Vec::with_capacity(villains.len() + 1);
+        below.push(0.0);
+        for (_, weight) in &villains {
+            below.push(below.last().unwrap() + weight);
+        }
+
+        let (mut runout_shares, mut runout_faced) = (0.0, 0.0);
+        for (i, &(combo, hero_weight)) in self.hero.iter().enumerate() {
+            if !combo.card_set().is_disjoint(runout) {
+                continue;
+            }
+            let strength = self.strength(combo, runout);
+
+            let worse = villains.partition_point(|v| v.0 < strength);
+            let not_better = villains.partition_point(|v| v.0 <= strength);
+            let mut wins = below[worse];
+            let mut ties = below[not_better] - below[worse];
+            let mut faced = below[villains.len()];
+
+            // Take out the villain combos that hold one of our cards. Those
+            // are the combos pairing either card with any other card, and the
+            // hero combo itself must only be taken out once.
+            let [first, second] = combo.cards();
+            let others = CardSet::full().difference(combo.card_set());
+            let blocked = others
+                .iter()
+                .flat_map(|card| [Combo::new(first, card), Combo::new(second, card)])
+                .chain(Some(combo));
+            for blocked in blocked {
+                if let Some(villain) = self.villain_strengths[blocked.index()] {
+                    let weight = self.villain.weight(blocked);
+                    faced -= weight;
+                    if villain < strength {
+                        wins -= weight;
+                    } else if villain == strength {
+                        ties -= weight;
+                    }
+                }
+            }
+
+            let share = wins + ties / 2.0;
+            self.shares[i] += share;
+            self.faced[i] += faced;
+            runout_shares += hero_weight * share;
+            runout_faced += hero_weight * faced;
+        }
+
+        if runout_faced > 0.0 {
+            let equity = runout_shares / runout_faced;
+            self.runout_equity.0 += equity;
+            self.runout_equity.1 += equity * equity;
+        }
+        self.runouts += 1;
+    }
+
+    fn std_error(&self) -> f64 {
+        if self.runouts < 2 {
+            return f64::INFINITY;
+        }
+
+        let n = self.runouts as f64;
+        let mean = self.runout_equity.0 / n;
+        let variance = (self.runout_equity.1 / n - mean * mean).max(0.0) * n / (n - 1.0);
+        (variance / n).sqrt()
+    }
+
+    fn finish(self, exact: bool) -> Result<RangeEquity, EquityError> {
+        let (mut shares, mut faced) = (0.0, 0.0);
+        let mut combos = Vec::new();
+        for (&(combo, weight), (combo_shares, combo_faced)) in
+            self.hero.iter().zip(self.shares.iter().zip(&self.faced))
+        {
+            if *combo_faced <= 0.0 {
+                continue;
+            }
+
+            // Each combo counts in proportion to how often it runs into the
+            // villain range, which is how often it actually gets to showdown
+            shares += weight * combo_shares;
+            faced += weight * combo_faced;
+            combos.push(ComboEquity {
+                combo,
+                weight,
+                equity: combo_shares / combo_faced,
+            });
+        }
+        if combos.is_empty() {
+            return Err(EquityError::EmptyRange);
+        }
+        let equity = shares / faced;
+
+        let mut histogram = [0.0; HISTOGRAM_BUCKETS];
+        for combo in &combos {
+            // Nudge the equity so that rounding noise from the prefix sums
+            // cannot drop a combo sitting on a bucket edge into the one below
+            let scaled = combo.equity * HISTOGRAM_BUCKETS as f64 + 1e-9;
+            histogram[(scaled as usize).min(HISTOGRAM_BUCKETS - 1)] += combo.weight;
+        }
+        let weight: f64 = histogram.iter().sum();
+        for bucket in histogram.iter_mut() {
+            *bucket /= weight;
+        }
+
+        Ok(RangeEquity {
+            equity,
+            combos,
+            histogram,
+            runouts: self.runouts,
+            exact,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::equity::exact_equity;
+    use crate::rng::SplitMix64;
+
+    fn cards(notation: &str) -> Vec<Card> {
+        (0..notation.len())
+            .step_by(2)
+            .map(|i| notation[i..i + 2].parse().unwrap())
+            .collect()
+    }
+
+    fn range(notation: &str) -> Range {
+        notation.parse().unwrap()
+    }
+
+    fn equity(hero: &str, villain: &str, board: &str) -> RangeEquity {
+        range_vs_range(
+            &range(hero),
+            &range(villain),
+            &cards(board),
+            &[],
+            &Simulation::trials(200),
+            &mut SplitMix64::new(11),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_hand_versus_hand_equity() {
+        let result = equity("AhKh", "QsQd", "2h7h9c3s");
+        let exact = exact_equity(
+            &[
+                [cards("Ah")[0], cards("Kh")[0]],
+                [cards("Qs")[0], cards("Qd")[0]],
+            ],
+            &cards("2h7h9c3s"),
+            &[],
+        )
+        .unwrap();
+
+        assert!(result.exact);
+        assert_eq!(result.runouts, 48);
+        assert!((result.equity - exact.players[0].equity).abs() < 1e-12);
+        assert_eq!(result.combos.len(), 1);
+    }
+
+    #[test]
+    fn respects_card_removal_between_ranges() {
+        // Holding the ace of hearts leaves villain with three combos of aces
+        let result = equity("AhKh", "AA", "2c7d9s3s4c");
+
+        assert_eq!(result.runouts, 1);
+        assert_eq!(result.equity, 0.0);
+        assert_eq!(result.combos[0].equity, 0.0);
+
+        // Every combo in KK and QQ blocks none of villain's aces on this board
+        let result = equity("KK, QQ", "AA", "2c7d9s3s4c");
+        assert_eq!(result.combos.len(), 12);
+        assert_eq!(result.histogram[0], 1.0);
+    }
+
+    #[test]
+    fn weights_combos_and_splits_ties() {
+        // Both ranges play the board
+        let result = equity("22", "33", "AcKdQhJsTc");
+        assert_eq!(result.equity, 0.5);
+        assert_eq!(result.histogram[5], 1.0);
+
+        let result = equity("AKs, 22:0.5", "QQ", "Ac7d8h3s4c");
+        let aces = result.combos.iter().filter(|c| c.equity > 0.999).count();
+        assert_eq!(aces, 3);
+        assert_eq!(result.fraction_above(0.8), 0.5);
+    }
+
+    #[test]
+    fn samples_runouts_when_enumeration_is_too_slow() {
+        let result = range_vs_range(
+            &range("AA, KK"),
+            &range("22+, AK"),
+            &[],
+            &[],
+            &Simulation::trials(300),
+            &mut SplitMix64::new(5),
+        )
+        .unwrap();
+
+        assert!(!result.exact);
+        assert_eq!(result.runouts, 300);
+        assert!(result.equity > 0.7 && result.equity < 0.9);
+        assert!(result.fraction_above(0.5) > 0.99);
+    }
+
+    #[test]
+    fn rejects_empty_ranges() {
+        let result = range_vs_range(
+            &range("AhAd"),
+            &range("KK"),
+            &cards("Ah2c3d"),
+            &[],
+            &Simulation::trials(10),
+            &mut SplitMix64::new(1),
+        );
+        assert_eq!(result, Err(EquityError::EmptyRange));
+    }
+
+    #[test]
+    fn rejects_an_empty_trial_budget() {
+        let result = range_vs_range(
+            &range("AA"),
+            &range("KK"),
+            &[],
+            &[],
+            &Simulation::trials(0),
+            &mut SplitMix64::new(1),
+        );
+        assert_eq!(result, Err(EquityError::NoTrials));
+    }
+}
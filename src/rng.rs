@@ -0,0 +1,71 @@
+/// Source of randomness for the simulations in this crate.
+///
+/// Anything that can produce uniformly distributed `u64`s can drive a
+/// simulation, so callers are free to plug in their own generator. Results are
+/// reproducible whenever the generator is.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform value in `0..bound`, using the multiply-shift reduction which
+    /// is fast and close enough to unbiased for bounds as small as a deck.
+    fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Uniform value in `[0, 1)`
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// The SplitMix64 generator: tiny, fast, and seeded from a single `u64`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub const fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_reproducible_from_the_seed() {
+        let mut a = SplitMix64::new(42);
+        let mut b = SplitMix64::new(42);
+        let mut c = SplitMix64::new(43);
+
+        for _ in 0..100 {
+            let value = a.next_u64();
+            assert_eq!(value, b.next_u64());
+            assert_ne!(value, c.next_u64());
+        }
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let mut rng = SplitMix64::new(7);
+        let mut seen = [false; 52];
+
+        for _ in 0..10_000 {
+            seen[rng.below(52) as usize] = true;
+
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+}
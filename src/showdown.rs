@@ -0,0 +1,293 @@
+use crate::card::Rank;
+use crate::hand::Hand;
+
+/// Indices of the players that finished level with each other.
+pub type Tier = Vec<usize>;
+
+/// Groups players into tiers ordered from the best hand to the worst.
+///
+/// Each tier holds the indices (into `hands`) of every player that holds an
+/// equal hand, so the first tier is the set of players splitting the pot and
+/// later tiers decide side pots.
+pub fn showdown(hands: &[Hand]) -> Vec<Tier> {
+    tiers(hands)
+}
+
+/// Same as `showdown` for hands of `N` cards, such as Hold'em players' seven,
+/// which play their best five.
+pub fn showdown_of<const N: usize>(hands: &[Hand<N>]) -> Vec<Tier> {
+    tiers(hands)
+}
+
+/// Resolves a hi/lo split showdown.
+///
+/// `high` and `low` hold each player's best high hand and best low candidate
+/// respectively, indexed the same way. The low half is played as ace-to-five
+/// eight-or-better, so only players whose low hand qualifies take part in it.
+pub fn showdown_hi_lo(high: &[Hand], low: &[Hand]) -> HiLo {
+    assert_eq!(
+        high.len(),
+        low.len(),
+        "Every player needs both a high and a low hand"
+    );
+
+    let qualifying: Vec<(usize, LowRank)> = low
+        .iter()
+        .enumerate()
+        .filter_map(|(player, hand)| LowRank::eight_or_better(hand).map(|rank| (player, rank)))
+        .collect();
+
+    let low = if qualifying.is_empty() {
+        None
+    } else {
+        let ranks: Vec<LowRank> = qualifying.iter().map(|(_, rank)| *rank).collect();
+        Some(
+            tiers(&ranks)
+                .into_iter()
+                .map(|tier| tier.into_iter().map(|i| qualifying[i].0).collect())
+                .collect(),
+        )
+    };
+
+    HiLo {
+        high: tiers(high),
+        low,
+    }
+}
+
+/// Outcome of a hi/lo showdown. `low` is `None` when nobody qualified, in
+/// which case the high hand scoops the whole pot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HiLo {
+    pub high: Vec<Tier>,
+    pub low: Option<Vec<Tier>>,
+}
+
+/// Value of an ace-to-five low hand. Like `Hand`, a greater `LowRank` is the
+/// better hand, so the wheel (5-4-3-2-A) is the greatest of them all.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LowRank(u32);
+
+impl LowRank {
+    /// Evaluates a hand as an eight-or-better low. Straights and flushes do
+    /// not count against a low, but it needs five distinct ranks of eight or
+    /// lower (aces playing low) to qualify.
+    pub fn eight_or_better(hand: &Hand) -> Option<Self> {
+        let mut values = [0; 5];
+        for (value, card) in values.iter_mut().zip(hand.cards().iter()) {
+            *value = Self::low_value(card.rank());
+        }
+        values.sort_unstable_by(|a, b| b.cmp(a));
+
+        let distinct = values.windows(2).all(|pair| pair[0] != pair[1]);
+        if !distinct || values[0] > 8 {
+            return None;
+        }
+
+        // Pack the ranks highest first so that comparing the packed integers
+        // compares the hands card by card.
+        Some(LowRank(
+            values.iter().fold(0, |acc, value| acc << 4 | value),
+        ))
+    }
+
+    fn low_value(rank: Rank) -> u32 {
+        match rank {
+            Rank::Ace => 1,
+            _ => rank.order_encoding() + 2,
+        }
+    }
+}
+
+impl Ord for LowRank {
+    fn cmp(&self, other: &LowRank) -> core::cmp::Ordering {
+        // Smaller packed values are the better lows
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for LowRank {
+    fn partial_cmp(&self, other: &LowRank) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Groups anything ordered like a hand, such as `HandRank`s, into tiers the
+/// same way `showdown` does.
+pub fn tiers<T: Ord>(items: &[T]) -> Vec<Tier> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| items[b].cmp(&items[a]).then(a.cmp(&b)));
+
+    let mut tiers: Vec<Tier> = Vec::new();
+    for player in order {
+        match tiers.last_mut() {
+            Some(tier) if items[tier[0]] == items[player] => tier.push(player),
+            _ => tiers.push(vec![player]),
+        }
+    }
+    tiers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::{Card, Suit};
+
+    fn hand(cards: [(Rank, Suit); 5]) -> Hand {
+        let mut packed = [Card::new(Rank::Two, Suit::Hearts); 5];
+        for (card, (rank, suit)) in packed.iter_mut().zip(cards.iter()) {
+            *card = Card::new(*rank, *suit);
+        }
+        Hand::new(packed)
+    }
+
+    #[test]
+    fn groups_players_into_tiers() {
+        let straight = hand([
+            (Rank::King, Suit::Spades),
+            (Rank::Queen, Suit::Clubs),
+            (Rank::Jack, Suit::Hearts),
+            (Rank::Ten, Suit::Hearts),
+            (Rank::Nine, Suit::Hearts),
+        ]);
+        let same_straight = hand([
+            (Rank::King, Suit::Hearts),
+            (Rank::Queen, Suit::Diamonds),
+            (Rank::Jack, Suit::Clubs),
+            (Rank::Ten, Suit::Spades),
+            (Rank::Nine, Suit::Spades),
+        ]);
+        let pair = hand([
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Four, Suit::Hearts),
+            (Rank::Five, Suit::Hearts),
+            (Rank::Six, Suit::Hearts),
+        ]);
+        let quads = hand([
+            (Rank::Two, Suit::Spades),
+            (Rank::Two, Suit::Clubs),
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Three, Suit::Hearts),
+        ]);
+
+        assert_eq!(
+            showdown(&[pair, straight, quads, same_straight]),
+            vec![vec![2], vec![1, 3], vec![0]]
+        );
+        assert_eq!(showdown(&[]), Vec::<Tier>::new());
+    }
+
+    #[test]
+    fn plays_the_best_five_of_bigger_hands() {
+        let board = hand([
+            (Rank::King, Suit::Spades),
+            (Rank::Queen, Suit::Clubs),
+            (Rank::Jack, Suit::Hearts),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Two, Suit::Clubs),
+        ]);
+        let ten_nine = [
+            Card::new(Rank::Ten, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+        ];
+        let other_ten_nine = [
+            Card::new(Rank::Ten, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Clubs),
+        ];
+        let deuce = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Spades),
+        ];
+        let hands: [Hand<7>; 3] = [
+            board.add_cards(&deuce),
+            board.add_cards(&ten_nine),
+            board.add_cards(&other_ten_nine),
+        ];
+
+        assert_eq!(showdown_of(&hands), vec![vec![1, 2], vec![0]]);
+    }
+
+    #[test]
+    fn ranks_eight_or_better_lows() {
+        let wheel = hand([
+            (Rank::Ace, Suit::Spades),
+            (Rank::Two, Suit::Spades),
+            (Rank::Three, Suit::Spades),
+            (Rank::Four, Suit::Spades),
+            (Rank::Five, Suit::Spades),
+        ]);
+        let six_four = hand([
+            (Rank::Six, Suit::Hearts),
+            (Rank::Four, Suit::Clubs),
+            (Rank::Three, Suit::Hearts),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Ace, Suit::Hearts),
+        ]);
+        let six_five = hand([
+            (Rank::Six, Suit::Clubs),
+            (Rank::Five, Suit::Clubs),
+            (Rank::Three, Suit::Diamonds),
+            (Rank::Two, Suit::Clubs),
+            (Rank::Ace, Suit::Diamonds),
+        ]);
+        let paired = hand([
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Three, Suit::Clubs),
+            (Rank::Four, Suit::Clubs),
+            (Rank::Five, Suit::Clubs),
+        ]);
+        let nine_high = hand([
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Two, Suit::Hearts),
+            (Rank::Three, Suit::Clubs),
+            (Rank::Four, Suit::Clubs),
+            (Rank::Five, Suit::Clubs),
+        ]);
+
+        let wheel = LowRank::eight_or_better(&wheel).unwrap();
+        let six_four = LowRank::eight_or_better(&six_four).unwrap();
+        let six_five = LowRank::eight_or_better(&six_five).unwrap();
+
+        assert!(wheel > six_four);
+        assert!(six_four > six_five);
+        assert_eq!(LowRank::eight_or_better(&paired), None);
+        assert_eq!(LowRank::eight_or_better(&nine_high), None);
+    }
+
+    #[test]
+    fn splits_hi_lo_pots() {
+        let wheel = hand([
+            (Rank::Ace, Suit::Spades),
+            (Rank::Two, Suit::Spades),
+            (Rank::Three, Suit::Hearts),
+            (Rank::Four, Suit::Spades),
+            (Rank::Five, Suit::Spades),
+        ]);
+        let flush = hand([
+            (Rank::King, Suit::Hearts),
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Seven, Suit::Hearts),
+            (Rank::Four, Suit::Hearts),
+            (Rank::Two, Suit::Hearts),
+        ]);
+        let other_wheel = hand([
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Two, Suit::Clubs),
+            (Rank::Three, Suit::Diamonds),
+            (Rank::Four, Suit::Clubs),
+            (Rank::Five, Suit::Clubs),
+        ]);
+
+        let result = showdown_hi_lo(&[flush, wheel, other_wheel], &[flush, wheel, other_wheel]);
+        assert_eq!(result.high, vec![vec![0], vec![1, 2]]);
+        assert_eq!(result.low, Some(vec![vec![1, 2]]));
+
+        let result = showdown_hi_lo(&[flush], &[flush]);
+        assert_eq!(result.high, vec![vec![0]]);
+        assert_eq!(result.low, None);
+    }
+}
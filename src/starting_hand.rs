@@ -0,0 +1,224 @@
+use crate::card::{Card, Rank, Suit};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Pair,
+    Suited,
+    Offsuit,
+}
+
+/// One of the 169 strategically distinct Hold'em starting hands, such as
+/// `AKs`, `T9o` or `77`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StartingHand {
+    high: Rank,
+    low: Rank,
+    shape: Shape,
+}
+
+impl StartingHand {
+    pub const COUNT: usize = 169;
+
+    /// Builds a starting hand from two ranks in either order. Returns `None`
+    /// for combinations that cannot exist, like a suited pair.
+    pub fn new(first: Rank, second: Rank, shape: Shape) -> Option<Self> {
+        let (high, low) = if first.order_encoding() >= second.order_encoding() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        match (high == low, shape) {
+            (true, Shape::Pair) | (false, Shape::Suited) | (false, Shape::Offsuit) => {
+                Some(StartingHand { high, low, shape })
+            }
+            _ => None,
+        }
+    }
+
+    /// The class a pair of hole cards belongs to
+    pub fn of(cards: [Card; 2]) -> Self {
+        let shape = if cards[0].rank() == cards[1].rank() {
+            Shape::Pair
+        } else if cards[0].suit() == cards[1].suit() {
+            Shape::Suited
+        } else {
+            Shape::Offsuit
+        };
+
+        StartingHand::new(cards[0].rank(), cards[1].rank(), shape)
+            .expect("Two distinct cards always make a starting hand")
+    }
+
+    pub fn high(&self) -> Rank {
+        self.high
+    }
+
+    pub fn low(&self) -> Rank {
+        self.low
+    }
+
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Row and column of the hand in the standard 13x13 grid, counting from
+    /// the top left ace corner. Pairs sit on the diagonal, suited hands above
+    /// it and offsuit hands below it.
+    pub fn grid_position(&self) -> (usize, usize) {
+        let high = 12 - self.high.order_encoding() as usize;
+        let low = 12 - self.low.order_encoding() as usize;
+
+        match self.shape {
+            Shape::Pair | Shape::Suited => (high, low),
+            Shape::Offsuit => (low, high),
+        }
+    }
+
+    /// Position in `StartingHand::all()`, row by row through the grid
+    pub fn index(&self) -> usize {
+        let (row, column) = self.grid_position();
+        row * 13 + column
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        assert!(index < Self::COUNT, "Starting hand index out of range");
+
+        let (row, column) = (index / 13, index % 13);
+        let shape = match row.cmp(&column) {
+            core::cmp::Ordering::Equal => Shape::Pair,
+            core::cmp::Ordering::Less => Shape::Suited,
+            core::cmp::Ordering::Greater => Shape::Offsuit,
+        };
+
+        StartingHand::new(Rank::ALL[row], Rank::ALL[column], shape)
+            .expect("Every grid cell is a starting hand")
+    }
+
+    /// All 169 starting hands in grid order
+    pub fn all() -> impl Iterator<Item = StartingHand> {
+        (0..Self::COUNT).map(StartingHand::from_index)
+    }
+
+    /// Number of ways to deal the hand: 6 for pairs, 4 for suited hands and
+    /// 12 for offsuit hands.
+    pub fn combo_count(&self) -> usize {
+        match self.shape {
+            Shape::Pair => 6,
+            Shape::Suited => 4,
+            Shape::Offsuit => 12,
+        }
+    }
+
+    /// Every pair of hole cards in this class
+    pub fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::with_capacity(self.combo_count());
+
+        for (i, &first) in Suit::ALL.iter().enumerate() {
+            for (j, &second) in Suit::ALL.iter().enumerate() {
+                let wanted = match self.shape {
+                    Shape::Pair => i < j,
+                    Shape::Suited => i == j,
+                    Shape::Offsuit => i != j,
+                };
+                if wanted {
+                    combos.push([Card::new(self.high, first), Card::new(self.low, second)]);
+                }
+            }
+        }
+        combos
+    }
+}
+
+impl core::fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}{}", self.high.to_char(), self.low.to_char())?;
+        match self.shape {
+            Shape::Pair => Ok(()),
+            Shape::Suited => write!(f, "s"),
+            Shape::Offsuit => write!(f, "o"),
+        }
+    }
+}
+
+impl core::str::FromStr for StartingHand {
+    type Err = ParseStartingHandError;
+
+    /// Parses classes such as `AKs`, `T9o` or `77`
+    fn from_str(s: &str) -> Result<Self, ParseStartingHandError> {
+        let chars: Vec<char> = s.chars().collect();
+        let shape = match chars.len() {
+            2 => Shape::Pair,
+            3 if chars[2] == 's' => Shape::Suited,
+            3 if chars[2] == 'o' => Shape::Offsuit,
+            _ => return Err(ParseStartingHandError),
+        };
+
+        match (Rank::from_char(chars[0]), Rank::from_char(chars[1])) {
+            (Some(first), Some(second)) => {
+                StartingHand::new(first, second, shape).ok_or(ParseStartingHandError)
+            }
+            _ => Err(ParseStartingHandError),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseStartingHandError;
+
+impl core::fmt::Display for ParseStartingHandError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "expected a starting hand such as AKs, T9o or 77")
+    }
+}
+
+impl std::error::Error for ParseStartingHandError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covers_every_combo_exactly_once() {
+        let mut seen = crate::card_set::CardSet::empty();
+        let mut total = 0;
+
+        for (index, hand) in StartingHand::all().enumerate() {
+            assert_eq!(hand.index(), index);
+            assert_eq!(hand.combos().len(), hand.combo_count());
+
+            for combo in hand.combos() {
+                assert_eq!(StartingHand::of(combo), hand);
+                assert_eq!(StartingHand::of([combo[1], combo[0]]), hand);
+                seen.insert(combo[0]);
+                seen.insert(combo[1]);
+                total += 1;
+            }
+        }
+
+        assert_eq!(total, 1326);
+        assert_eq!(seen.len(), 52);
+    }
+
+    #[test]
+    fn lays_hands_out_on_the_grid() {
+        let aces: StartingHand = "AA".parse().unwrap();
+        let ak_suited: StartingHand = "AKs".parse().unwrap();
+        let ak_offsuit: StartingHand = "KAo".parse().unwrap();
+        let deuces: StartingHand = "22".parse().unwrap();
+
+        assert_eq!(aces.grid_position(), (0, 0));
+        assert_eq!(ak_suited.grid_position(), (0, 1));
+        assert_eq!(ak_offsuit.grid_position(), (1, 0));
+        assert_eq!(deuces.grid_position(), (12, 12));
+        assert_eq!(ak_offsuit.to_string(), "AKo");
+    }
+
+    #[test]
+    fn rejects_impossible_hands() {
+        assert!("AAs".parse::<StartingHand>().is_err());
+        assert!("AK".parse::<StartingHand>().is_err());
+        assert!("AKx".parse::<StartingHand>().is_err());
+        assert!("1Ks".parse::<StartingHand>().is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::hand_rank::NumericHandRank;
+
+/// Number of distinct 5-card hand values produced by the lookup tables
+const DISTINCT_VALUES: u16 = 7462;
+
+/// Strength of an evaluated hand where greater always means better.
+///
+/// The lookup tables hand out `NumericHandRank`s where 1 is a royal flush and
+/// 7462 is seven high, which is easy to get backwards when sorting. Every type
+/// that can be compared as a poker hand orders itself through `Strength`, so
+/// `Vec::sort`, `max` and the comparison operators all agree.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Strength(u16);
+
+impl Strength {
+    /// Seven high, the weakest possible hand
+    pub const MIN: Strength = Strength(1);
+
+    /// A royal flush, the strongest possible hand
+    pub const MAX: Strength = Strength(DISTINCT_VALUES);
+
+    /// Panics unless `numeric` is one of the 7462 hand values
+    pub const fn from_numeric(numeric: NumericHandRank) -> Self {
+        assert!(
+            numeric >= 1 && numeric <= DISTINCT_VALUES as NumericHandRank,
+            "Hand values run from 1 to 7462"
+        );
+        Strength(DISTINCT_VALUES + 1 - numeric as u16)
+    }
+
+    pub const fn numeric(&self) -> NumericHandRank {
+        (DISTINCT_VALUES + 1 - self.0) as NumericHandRank
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl From<NumericHandRank> for Strength {
+    fn from(numeric: NumericHandRank) -> Self {
+        Strength::from_numeric(numeric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::Card;
+    use crate::deck::Deck;
+    use crate::hand::Hand;
+    use crate::hand_rank::HandRank;
+    use crate::rng::SplitMix64;
+
+    fn random_hand(rng: &mut SplitMix64) -> Hand {
+        let mut deck = Deck::new();
+        let mut cards = [Card::from_index(0); 5];
+        for card in cards.iter_mut() {
+            *card = deck.draw(rng).unwrap();
+        }
+        Hand::new(cards)
+    }
+
+    #[test]
+    fn round_trips_every_numeric_rank() {
+        for numeric in 1..=7462 {
+            assert_eq!(Strength::from_numeric(numeric).numeric(), numeric);
+        }
+        assert_eq!(Strength::from_numeric(1), Strength::MAX);
+        assert_eq!(Strength::from_numeric(7462), Strength::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "Hand values run from 1 to 7462")]
+    fn rejects_values_outside_the_tables() {
+        Strength::from_numeric(7463);
+    }
+
+    #[test]
+    fn greater_strength_is_a_better_category() {
+        for numeric in 1..7462 {
+            let better = HandRank::from(numeric);
+            let worse = HandRank::from(numeric + 1);
+
+            assert!(better.strength() > worse.strength());
+            assert!(better > worse);
+            assert!(better.category() >= worse.category());
+        }
+    }
+
+    #[test]
+    fn hand_and_hand_rank_order_the_same_way() {
+        let mut rng = SplitMix64::new(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..2000 {
+            let a = random_hand(&mut rng);
+            let b = random_hand(&mut rng);
+
+            let ordering = a.cmp(&b);
+            assert_eq!(ordering, a.rank().cmp(&b.rank()));
+            assert_eq!(ordering, a.strength().cmp(&b.strength()));
+            assert_eq!(a.partial_cmp(&b), Some(ordering));
+            assert_eq!(b.cmp(&a), ordering.reverse());
+            assert_eq!(a == b, ordering == core::cmp::Ordering::Equal);
+            assert_eq!(a > b, a.rank().numeric() < b.rank().numeric());
+        }
+    }
+
+    #[test]
+    fn sorting_agrees_with_comparison_operators() {
+        let mut rng = SplitMix64::new(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..200 {
+            let mut hands: Vec<Hand> = (0..8).map(|_| random_hand(&mut rng)).collect();
+            hands.sort();
+
+            for pair in hands.windows(2) {
+                assert!(pair[0] <= pair[1]);
+                assert!(pair[0].rank() <= pair[1].rank());
+            }
+
+            let best = hands.iter().max().unwrap();
+            assert!(hands.iter().all(|hand| best >= hand));
+            assert_eq!(best, hands.last().unwrap());
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use crate::board::{self, BoardError};
+use crate::card::{Card, Rank};
+
+/// Five-rank windows a straight can fill: the wheel plus one for every higher
+/// starting rank.
+const STRAIGHT_WINDOWS: usize = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Pairing {
+    Unpaired,
+    Paired,
+    TwoPair,
+    Trips,
+    FullHouse,
+    Quads,
+}
+
+/// How the suits on the board are spread out
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SuitPattern {
+    /// Every card is a different suit
+    Rainbow,
+    /// Only two suits, e.g. `Kh7h3c` or `Kh7h3c2c`
+    TwoTone,
+    /// Every card is the same suit
+    Monotone,
+    /// Three or more suits with at least one repeated, as on most rivers
+    Mixed,
+}
+
+/// Class of the highest card on the board
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HighCard {
+    /// Two to five
+    Low,
+    /// Six to nine
+    Middle,
+    /// Ten to king
+    Broadway,
+    Ace,
+}
+
+/// Structural summary of a flop, turn or river board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Texture {
+    /// Number of cards on the board
+    pub cards: usize,
+    pub pairing: Pairing,
+    pub suits: SuitPattern,
+    /// Most cards of any one suit
+    pub flush_cards: u8,
+    pub high_rank: Rank,
+    pub high_card: HighCard,
+    pub distinct_ranks: u8,
+    /// Ranks missing between the lowest and the highest board card, with
+    /// the ace playing low when that leaves fewer
+    pub gaps: u8,
+    /// Most board ranks that fall into any five-rank straight window
+    pub connectedness: u8,
+    /// Number of different straights a player can hold with two cards
+    pub straights: u8,
+    /// Straight windows two cards short, i.e. that still need a card to come
+    pub straight_draws: u8,
+    /// Rough score from 0 (dry) to 100 (wet) of how many draws and made
+    /// hands the board allows
+    pub wetness: u8,
+}
+
+impl Texture {
+    pub fn of(board: &[Card]) -> Result<Self, BoardError> {
+        board::validate(board, 3..=5, &[])?;
+
+        let mut rank_counts = [0_u8; 13];
+        let mut suit_counts = [0_u8; 4];
+        for &card in board {
+            rank_counts[card.rank().order_encoding() as usize] += 1;
+            let suit_bits = (card.as_int() >> 12) & 0xF;
+            suit_counts[suit_bits.trailing_zeros() as usize] += 1;
+        }
+
+        let to_come = board.len() < 5;
+        let ranks = RankCounts(rank_counts);
+        let flush_cards = *suit_counts.iter().max().unwrap();
+        let suits_present = suit_counts.iter().filter(|count| **count > 0).count();
+
+        let suits = if suits_present == 1 {
+            SuitPattern::Monotone
+        } else if suits_present == 2 {
+            SuitPattern::TwoTone
+        } else if flush_cards == 1 {
+            SuitPattern::Rainbow
+        } else {
+            SuitPattern::Mixed
+        };
+
+        let windows = ranks.window_counts();
+        let straights = windows.iter().filter(|count| **count >= 3).count() as u8;
+        let straight_draws = if to_come {
+            windows.iter().filter(|count| **count == 2).count() as u8
+        } else {
+            0
+        };
+
+        let high_order = ranks.highest();
+        let high_rank = Rank::from_order(high_order);
+        let high_card = match high_rank {
+            Rank::Ace => HighCard::Ace,
+            Rank::King | Rank::Queen | Rank::Jack | Rank::Ten => HighCard::Broadway,
+            Rank::Nine | Rank::Eight | Rank::Seven | Rank::Six => HighCard::Middle,
+            _ => HighCard::Low,
+        };
+
+        let distinct_ranks = ranks.distinct();
+        let pairing = ranks.pairing();
+
+        let flush_score = match flush_cards {
+            1 => 0,
+            2 if to_come => 15,
+            2 => 0,
+            3 => 25,
+            _ => 35,
+        };
+        let wetness = flush_score
+            + (straights * 10).min(30)
+            + (straight_draws * 3).min(15)
+            + if pairing == Pairing::Unpaired { 10 } else { 0 };
+
+        Ok(Texture {
+            cards: board.len(),
+            pairing,
+            suits,
+            flush_cards,
+            high_rank,
+            high_card,
+            distinct_ranks,
+            gaps: ranks.gaps(),
+            connectedness: *windows.iter().max().unwrap(),
+            straights,
+            straight_draws,
+            wetness,
+        })
+    }
+
+    pub fn is_paired(&self) -> bool {
+        self.pairing != Pairing::Unpaired
+    }
+
+    /// Whether somebody can already hold a flush
+    pub fn flush_possible(&self) -> bool {
+        self.flush_cards >= 3
+    }
+
+    /// Whether somebody can already hold a straight
+    pub fn straight_possible(&self) -> bool {
+        self.straights > 0
+    }
+
+    /// What changed when the board went from `self` to `next`, usually one
+    /// street later.
+    pub fn change_to(&self, next: &Texture) -> TextureChange {
+        TextureChange {
+            paired: next.pairing > self.pairing,
+            flush_completed: !self.flush_possible() && next.flush_possible(),
+            straight_completed: next.straights > self.straights,
+            overcard: next.high_rank.order_encoding() > self.high_rank.order_encoding(),
+            wetness: i16::from(next.wetness) - i16::from(self.wetness),
+        }
+    }
+}
+
+/// How the last card of a turn or river board changed the texture.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextureChange {
+    /// The board paired up further, e.g. from unpaired to paired or paired
+    /// to trips
+    pub paired: bool,
+    /// A flush became possible
+    pub flush_completed: bool,
+    /// At least one new straight became possible
+    pub straight_completed: bool,
+    /// The new card is higher than everything before it
+    pub overcard: bool,
+    /// Change in the wetness score
+    pub wetness: i16,
+}
+
+impl TextureChange {
+    /// Compares a turn or river `board` with the street before it. Flops
+    /// have nothing to compare with and give `None`.
+    pub fn of(board: &[Card]) -> Result<Option<Self>, BoardError> {
+        let after = Texture::of(board)?;
+        if board.len() == 3 {
+            return Ok(None);
+        }
+        let before = Texture::of(&board[..board.len() - 1])?;
+        Ok(Some(before.change_to(&after)))
+    }
+}
+
+/// Number of board cards of each rank, indexed by order encoding
+struct RankCounts([u8; 13]);
+
+impl RankCounts {
+    fn distinct(&self) -> u8 {
+        self.0.iter().filter(|count| **count > 0).count() as u8
+    }
+
+    fn highest(&self) -> u32 {
+        self.0.iter().rposition(|count| *count > 0).unwrap() as u32
+    }
+
+    fn lowest(&self) -> u32 {
+        self.0.iter().position(|count| *count > 0).unwrap() as u32
+    }
+
+    fn gaps(&self) -> u8 {
+        let span = self.highest() - self.lowest() + 1;
+        // An ace played below the deuce spans up to the highest other rank
+        let ace_low = match self.0[..12].iter().rposition(|count| *count > 0) {
+            Some(highest) if self.0[12] > 0 => highest as u32 + 2,
+            _ => span,
+        };
+        span.min(ace_low) as u8 - self.distinct()
+    }
+
+    fn pairing(&self) -> Pairing {
+        let mut groups = [0_u8; 5];
+        for &count in self.0.iter() {
+            groups[count as usize] += 1;
+        }
+
+        match (groups[4], groups[3], groups[2]) {
+            (1, _, _) => Pairing::Quads,
+            (_, 1, 0) => Pairing::Trips,
+            (_, 1, _) => Pairing::FullHouse,
+            (_, _, 2) => Pairing::TwoPair,
+            (_, _, 1) => Pairing::Paired,
+            _ => Pairing::Unpaired,
+        }
+    }
+
+    /// Distinct board ranks in each straight window, wheel first
+    fn window_counts(&self) -> [u8; STRAIGHT_WINDOWS] {
+        let mut mask = 0_u32;
+        for (order, count) in self.0.iter().enumerate() {
+            if *count > 0 {
+                mask |= 1 << order;
+            }
+        }
+        // Shift everything up one so the ace can also play below the deuce
+        let mask = (mask << 1) | (mask >> 12);
+
+        let mut windows = [0; STRAIGHT_WINDOWS];
+        for (low, count) in windows.iter_mut().enumerate() {
+            *count = ((mask >> low) & 0x1F).count_ones() as u8;
+        }
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card::parse_cards;
+
+    fn texture(board: &str) -> Texture {
+        Texture::of(&parse_cards(board).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn classifies_a_dry_flop() {
+        let dry = texture("Kc7h2d");
+        assert_eq!(dry.pairing, Pairing::Unpaired);
+        assert_eq!(dry.suits, SuitPattern::Rainbow);
+        assert_eq!(dry.high_card, HighCard::Broadway);
+        assert_eq!(dry.high_rank, Rank::King);
+        assert_eq!(dry.gaps, 9);
+        assert_eq!(dry.connectedness, 1);
+        assert_eq!(dry.straights, 0);
+        assert_eq!(dry.straight_draws, 0);
+        assert!(!dry.flush_possible());
+        assert_eq!(dry.wetness, 10);
+    }
+
+    #[test]
+    fn classifies_a_wet_flop() {
+        let wet = texture("JhTh9c");
+        assert_eq!(wet.suits, SuitPattern::TwoTone);
+        assert_eq!(wet.flush_cards, 2);
+        assert_eq!(wet.gaps, 0);
+        assert_eq!(wet.connectedness, 3);
+        // 7-J, 8-Q and 9-K
+        assert_eq!(wet.straights, 3);
+        assert!(wet.straight_possible());
+        assert!(wet.wetness > 50);
+
+        let monotone = texture("JhTh9h");
+        assert_eq!(monotone.suits, SuitPattern::Monotone);
+        assert!(monotone.flush_possible());
+        assert!(monotone.wetness > wet.wetness);
+    }
+
+    #[test]
+    fn lets_the_ace_play_low() {
+        let wheel = texture("As2c3d");
+        assert_eq!(wheel.high_card, HighCard::Ace);
+        assert_eq!(wheel.straights, 1);
+        assert_eq!(wheel.gaps, 0);
+        assert_eq!(texture("Ac5d9h").gaps, 6);
+        assert_eq!(texture("AcKd9h").gaps, 3);
+        // Ace-to-three is also two short of 2-6
+        assert_eq!(wheel.straight_draws, 1);
+    }
+
+    #[test]
+    fn counts_pairs_on_the_board() {
+        assert_eq!(texture("KcKh2d").pairing, Pairing::Paired);
+        assert_eq!(texture("KcKh2d2s").pairing, Pairing::TwoPair);
+        assert_eq!(texture("KcKhKd").pairing, Pairing::Trips);
+        assert_eq!(texture("KcKhKd2s2c").pairing, Pairing::FullHouse);
+        assert_eq!(texture("KcKhKdKs").pairing, Pairing::Quads);
+        assert_eq!(texture("KcKh2d5s").suits, SuitPattern::Rainbow);
+        assert_eq!(texture("KcKh2d5s9c").suits, SuitPattern::Mixed);
+    }
+
+    #[test]
+    fn reports_what_the_next_street_changed() {
+        let board = parse_cards("Kh7h3c2h").unwrap();
+        let change = TextureChange::of(&board).unwrap().unwrap();
+        assert!(change.flush_completed);
+        assert!(!change.paired);
+        assert!(!change.overcard);
+        assert!(change.wetness > 0);
+
+        let board = parse_cards("Kh7h3cAs7c").unwrap();
+        let change = TextureChange::of(&board).unwrap().unwrap();
+        assert!(change.paired);
+        assert!(!change.flush_completed);
+
+        let board = parse_cards("Kh7h3cAs").unwrap();
+        assert!(TextureChange::of(&board).unwrap().unwrap().overcard);
+        assert_eq!(TextureChange::of(&board[..3]), Ok(None));
+    }
+
+    #[test]
+    fn rejects_bad_boards() {
+        let board = parse_cards("Kh7h").unwrap();
+        assert_eq!(
+            Texture::of(&board),
+            Err(BoardError::BoardSize {
+                cards: 2,
+                min: 3,
+                max: 5
+            })
+        );
+        let board = parse_cards("Kh7hKh").unwrap();
+        assert_eq!(
+            Texture::of(&board),
+            Err(BoardError::DuplicateCard(board[0]))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Runs the built `poker-calc` binary the way a script would.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn poker_calc(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_poker-calc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn runs_a_single_command() {
+    let output = poker_calc(&["eval", "AhKhQhJhTh"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "Straight flush (rank 1 of 7462, 1 is best)\n"
+    );
+
+    let output = poker_calc(&["eval", "AhKh"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "poker-calc: a hand needs 5 to 7 cards, got 2\n"
+    );
+
+    let output = poker_calc(&["--json", "eval", "AhKh"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "{\"error\":\"a hand needs 5 to 7 cards, got 2\"}\n"
+    );
+}
+
+#[test]
+fn runs_every_line_of_a_batch() {
+    let output = poker_calc(&[], "eval AhKhQhJhTh\n\ncompare AhKhQhJhTh 2c2d2h7s7d\n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output).lines().count(), 4);
+
+    // A bad line fails the batch but the lines after it still run
+    let output = poker_calc(&["--json"], "eval AhKh\neval AhKhQhJhTh\n");
+    assert_eq!(output.status.code(), Some(1));
+    let lines: Vec<&str> = stdout(&output).lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "{\"error\":\"a hand needs 5 to 7 cards, got 2\"}");
+    assert!(lines[1].starts_with("{\"cards\":[\"Ah\""));
+
+    let output = poker_calc(&[], "eval AhKh\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "error: a hand needs 5 to 7 cards, got 2\n");
+}